@@ -0,0 +1,2 @@
+-- Coordinate index for viewport and radius queries on public events
+CREATE INDEX IF NOT EXISTS idx_events_status_lat_lng ON events(status, latitude, longitude);
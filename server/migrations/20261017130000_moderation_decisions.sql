@@ -0,0 +1,13 @@
+-- History of approve/reject decisions with reason codes and moderator notes
+CREATE TABLE IF NOT EXISTS moderation_decisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    decision TEXT NOT NULL CHECK(decision IN ('approved', 'rejected')),
+    reason TEXT CHECK(reason IN ('duplicate', 'spam', 'insufficient_info', 'outside_scope')),
+    notes TEXT,
+    actor_id INTEGER REFERENCES admins(id) ON DELETE SET NULL,
+    actor TEXT NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_moderation_decisions_event ON moderation_decisions(event_id);
@@ -0,0 +1,16 @@
+-- Secret edit token handed to the submitter on creation (SHA-256 hash only)
+ALTER TABLE events ADD COLUMN edit_token_hash TEXT;
+
+-- Submitter edits to approved events wait here for review
+CREATE TABLE IF NOT EXISTS event_revisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    changes_json TEXT NOT NULL,
+    status TEXT DEFAULT 'pending' CHECK(status IN ('pending', 'approved', 'rejected', 'superseded')),
+    reviewer TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    reviewed_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_revisions_event ON event_revisions(event_id);
+CREATE INDEX IF NOT EXISTS idx_event_revisions_status ON event_revisions(status);
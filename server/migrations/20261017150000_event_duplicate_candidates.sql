@@ -0,0 +1,10 @@
+-- Likely duplicates flagged when an event is submitted
+CREATE TABLE IF NOT EXISTS event_duplicate_candidates (
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    candidate_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    score REAL NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (event_id, candidate_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_duplicate_candidates_candidate ON event_duplicate_candidates(candidate_id);
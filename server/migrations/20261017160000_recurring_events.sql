@@ -0,0 +1,21 @@
+-- Multi-day events and RFC 5545 recurrence rules
+ALTER TABLE events ADD COLUMN end_date DATETIME;
+ALTER TABLE events ADD COLUMN rrule TEXT;
+-- End of the last occurrence of a bounded series (NULL while open-ended); unused for one-off events
+ALTER TABLE events ADD COLUMN recurrence_end DATETIME;
+
+-- Cancelled (EXDATE) or modified occurrences of recurring events
+CREATE TABLE IF NOT EXISTS event_occurrence_overrides (
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    occurrence_start DATETIME NOT NULL,
+    cancelled BOOLEAN NOT NULL DEFAULT 0,
+    start_date DATETIME,
+    end_date DATETIME,
+    title TEXT,
+    description TEXT,
+    location_name TEXT,
+    latitude REAL,
+    longitude REAL,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (event_id, occurrence_start)
+);
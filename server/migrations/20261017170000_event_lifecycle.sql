@@ -0,0 +1,19 @@
+-- Whether an event is still going ahead, separate from moderation status
+ALTER TABLE events ADD COLUMN lifecycle TEXT NOT NULL DEFAULT 'scheduled'
+    CHECK(lifecycle IN ('scheduled', 'cancelled', 'postponed', 'completed'));
+ALTER TABLE events ADD COLUMN postponed_to DATETIME;
+
+-- History of lifecycle transitions
+CREATE TABLE IF NOT EXISTS event_lifecycle_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    from_state TEXT NOT NULL,
+    to_state TEXT NOT NULL,
+    postponed_to DATETIME,
+    note TEXT,
+    actor_id INTEGER REFERENCES admins(id) ON DELETE SET NULL,
+    actor TEXT NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_lifecycle_changes_event ON event_lifecycle_changes(event_id);
@@ -0,0 +1,3 @@
+-- IANA timezone of each event, derived from its coordinates at startup
+-- for existing rows (see db::backfill_timezones)
+ALTER TABLE events ADD COLUMN timezone TEXT;
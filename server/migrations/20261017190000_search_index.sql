@@ -0,0 +1,61 @@
+-- Full-text indexes over events and organizers. Both are external-content
+-- tables kept in sync by the triggers below; remove_diacritics 2 folds
+-- e.g. "Kraków" and "Krakow" to the same token.
+CREATE VIRTUAL TABLE IF NOT EXISTS events_fts USING fts5(
+    title,
+    description,
+    location_name,
+    country,
+    organizer,
+    content = 'events',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS events_fts_insert AFTER INSERT ON events BEGIN
+    INSERT INTO events_fts (rowid, title, description, location_name, country, organizer)
+    VALUES (new.id, new.title, new.description, new.location_name, new.country, new.organizer);
+END;
+
+CREATE TRIGGER IF NOT EXISTS events_fts_delete AFTER DELETE ON events BEGIN
+    INSERT INTO events_fts (events_fts, rowid, title, description, location_name, country, organizer)
+    VALUES ('delete', old.id, old.title, old.description, old.location_name, old.country, old.organizer);
+END;
+
+CREATE TRIGGER IF NOT EXISTS events_fts_update
+AFTER UPDATE OF title, description, location_name, country, organizer ON events BEGIN
+    INSERT INTO events_fts (events_fts, rowid, title, description, location_name, country, organizer)
+    VALUES ('delete', old.id, old.title, old.description, old.location_name, old.country, old.organizer);
+    INSERT INTO events_fts (rowid, title, description, location_name, country, organizer)
+    VALUES (new.id, new.title, new.description, new.location_name, new.country, new.organizer);
+END;
+
+CREATE VIRTUAL TABLE IF NOT EXISTS organizers_fts USING fts5(
+    name,
+    description,
+    content = 'organizers',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_insert AFTER INSERT ON organizers BEGIN
+    INSERT INTO organizers_fts (rowid, name, description)
+    VALUES (new.id, new.name, new.description);
+END;
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_delete AFTER DELETE ON organizers BEGIN
+    INSERT INTO organizers_fts (organizers_fts, rowid, name, description)
+    VALUES ('delete', old.id, old.name, old.description);
+END;
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_update
+AFTER UPDATE OF name, description ON organizers BEGIN
+    INSERT INTO organizers_fts (organizers_fts, rowid, name, description)
+    VALUES ('delete', old.id, old.name, old.description);
+    INSERT INTO organizers_fts (rowid, name, description)
+    VALUES (new.id, new.name, new.description);
+END;
+
+-- Index rows that existed before this migration
+INSERT INTO events_fts (events_fts) VALUES ('rebuild');
+INSERT INTO organizers_fts (organizers_fts) VALUES ('rebuild');
@@ -0,0 +1,20 @@
+-- Alternative spellings that link free-text organizer names to an organizer
+CREATE TABLE IF NOT EXISTS organizer_aliases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    organizer_id INTEGER NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    alias TEXT NOT NULL,
+    -- dedup::normalize(alias); one organizer per spelling
+    normalized TEXT NOT NULL UNIQUE,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_organizer_aliases_organizer ON organizer_aliases(organizer_id);
+
+-- Possible organizers for events whose name matched more than one, for moderators to pick from
+CREATE TABLE IF NOT EXISTS event_organizer_suggestions (
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    organizer_id INTEGER NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    score REAL NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (event_id, organizer_id)
+);
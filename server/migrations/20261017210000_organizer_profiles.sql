@@ -0,0 +1,9 @@
+-- Public profile details for organizers
+ALTER TABLE organizers ADD COLUMN logo_url TEXT;
+ALTER TABLE organizers ADD COLUMN city TEXT;
+ALTER TABLE organizers ADD COLUMN country TEXT;
+ALTER TABLE organizers ADD COLUMN instagram_url TEXT;
+ALTER TABLE organizers ADD COLUMN facebook_url TEXT;
+ALTER TABLE organizers ADD COLUMN youtube_url TEXT;
+ALTER TABLE organizers ADD COLUMN soundcloud_url TEXT;
+ALTER TABLE organizers ADD COLUMN contact_email TEXT;
@@ -0,0 +1,31 @@
+-- Self-service logins for organizer crews (passwords are argon2 PHC strings)
+CREATE TABLE IF NOT EXISTS organizer_accounts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    username TEXT NOT NULL UNIQUE,
+    password_hash TEXT NOT NULL,
+    -- Verified accounts publish their events without moderation
+    verified BOOLEAN NOT NULL DEFAULT 0,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    last_login_at DATETIME
+);
+
+-- Organizers an account manages
+CREATE TABLE IF NOT EXISTS organizer_account_organizers (
+    account_id INTEGER NOT NULL REFERENCES organizer_accounts(id) ON DELETE CASCADE,
+    organizer_id INTEGER NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    PRIMARY KEY (account_id, organizer_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_organizer_account_organizers_organizer ON organizer_account_organizers(organizer_id);
+
+-- Login sessions (only the SHA-256 of the bearer token is stored)
+CREATE TABLE IF NOT EXISTS organizer_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id INTEGER NOT NULL REFERENCES organizer_accounts(id) ON DELETE CASCADE,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    expires_at DATETIME NOT NULL,
+    revoked_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_organizer_sessions_account ON organizer_sessions(account_id);
@@ -0,0 +1,3 @@
+-- Admin roles: viewer < moderator < editor < owner.
+-- Existing admins keep full access.
+ALTER TABLE admins ADD COLUMN role TEXT NOT NULL DEFAULT 'owner';
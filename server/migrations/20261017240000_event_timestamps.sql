@@ -0,0 +1,21 @@
+-- When an event was approved and last changed, for feed entry dates and
+-- conditional GETs. Both are written by the application.
+ALTER TABLE events ADD COLUMN approved_at DATETIME;
+ALTER TABLE events ADD COLUMN updated_at DATETIME;
+
+-- Approval time from the moderation log where there is one, else the submission time
+UPDATE events SET approved_at = COALESCE(
+    (SELECT MAX(d.created_at) FROM moderation_decisions d
+     WHERE d.event_id = events.id AND d.decision = 'approved'),
+    created_at
+)
+WHERE status = 'approved';
+
+UPDATE events SET updated_at = COALESCE(approved_at, created_at);
+
+-- Timestamps written with CURRENT_TIMESTAMP use a space; match the RFC 3339 form the API writes
+UPDATE events SET
+    approved_at = strftime('%Y-%m-%dT%H:%M:%S+00:00', approved_at),
+    updated_at = strftime('%Y-%m-%dT%H:%M:%S+00:00', updated_at);
+
+CREATE INDEX IF NOT EXISTS idx_events_approved_at ON events(approved_at);
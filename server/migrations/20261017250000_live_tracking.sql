@@ -0,0 +1,36 @@
+-- Live position sharing from the sound bike during a ride. The device posting
+-- fixes authenticates with a per-session token (only its SHA-256 is stored).
+CREATE TABLE IF NOT EXISTS live_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    token_hash TEXT NOT NULL UNIQUE,
+    -- Username of the admin or organizer account that started it
+    started_by TEXT NOT NULL,
+    started_at DATETIME NOT NULL,
+    last_fix_at DATETIME,
+    ended_at DATETIME,
+    -- 'stopped' or 'inactive'
+    end_reason TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_live_sessions_event ON live_sessions(event_id);
+
+-- At most one session per event is live at a time
+CREATE UNIQUE INDEX IF NOT EXISTS idx_live_sessions_active ON live_sessions(event_id) WHERE ended_at IS NULL;
+
+-- The recorded track, kept for replay after the ride
+CREATE TABLE IF NOT EXISTS live_positions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id INTEGER NOT NULL REFERENCES live_sessions(id) ON DELETE CASCADE,
+    latitude REAL NOT NULL,
+    longitude REAL NOT NULL,
+    -- Degrees clockwise from north; from the device or derived from the previous fix
+    heading REAL,
+    -- Metres per second and metres, as reported by the device
+    speed REAL,
+    accuracy REAL,
+    recorded_at DATETIME NOT NULL,
+    received_at DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_live_positions_session ON live_positions(session_id, recorded_at);
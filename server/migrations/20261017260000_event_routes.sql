@@ -0,0 +1,13 @@
+-- Planned route of a ride, simplified from an uploaded GPX or GeoJSON track
+CREATE TABLE IF NOT EXISTS event_routes (
+    event_id INTEGER PRIMARY KEY REFERENCES events(id) ON DELETE CASCADE,
+    -- [longitude, latitude] pairs, as in GeoJSON
+    coordinates_json TEXT NOT NULL,
+    -- Length of the uploaded track, measured before simplification
+    distance_km REAL NOT NULL,
+    -- Points in the uploaded track
+    source_points INTEGER NOT NULL,
+    -- Username of the admin or organizer account that uploaded it
+    uploaded_by TEXT NOT NULL,
+    updated_at DATETIME NOT NULL
+);
@@ -0,0 +1,108 @@
+-- remove_diacritics only strips combining marks, so letters such as "ł",
+-- "ø" or "ß" stay distinct and "Wroclaw" never matched "Wrocław". Queries are
+-- folded with deunicode, so the indexes get an extra `folded` column holding
+-- the names with those letters spelled the same way. The FTS tables read from
+-- views that compute it, and the triggers index the view rows.
+DROP TRIGGER IF EXISTS events_fts_insert;
+DROP TRIGGER IF EXISTS events_fts_delete;
+DROP TRIGGER IF EXISTS events_fts_update;
+DROP TABLE IF EXISTS events_fts;
+
+DROP TRIGGER IF EXISTS organizers_fts_insert;
+DROP TRIGGER IF EXISTS organizers_fts_delete;
+DROP TRIGGER IF EXISTS organizers_fts_update;
+DROP TABLE IF EXISTS organizers_fts;
+
+CREATE VIEW IF NOT EXISTS events_search AS
+SELECT id, title, description, location_name, country, organizer,
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(
+        title || ' ' || location_name || ' ' || organizer,
+        'ł', 'l'), 'Ł', 'L'), 'ø', 'o'), 'Ø', 'O'), 'đ', 'd'), 'Đ', 'D'),
+        'ħ', 'h'), 'Ħ', 'H'), 'ı', 'i'), 'ß', 'ss'), 'æ', 'ae'), 'Æ', 'AE'),
+        'œ', 'oe'), 'Œ', 'OE'), 'þ', 'th'), 'Þ', 'TH'), 'ð', 'd'), 'Ð', 'D')
+    AS folded
+FROM events;
+
+CREATE VIRTUAL TABLE IF NOT EXISTS events_fts USING fts5(
+    title,
+    description,
+    location_name,
+    country,
+    organizer,
+    folded,
+    content = 'events_search',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS events_fts_insert AFTER INSERT ON events BEGIN
+    INSERT INTO events_fts (rowid, title, description, location_name, country, organizer, folded)
+    SELECT id, title, description, location_name, country, organizer, folded
+    FROM events_search WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS events_fts_delete BEFORE DELETE ON events BEGIN
+    INSERT INTO events_fts (events_fts, rowid, title, description, location_name, country, organizer, folded)
+    SELECT 'delete', id, title, description, location_name, country, organizer, folded
+    FROM events_search WHERE id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS events_fts_update_before
+BEFORE UPDATE OF title, description, location_name, country, organizer ON events BEGIN
+    INSERT INTO events_fts (events_fts, rowid, title, description, location_name, country, organizer, folded)
+    SELECT 'delete', id, title, description, location_name, country, organizer, folded
+    FROM events_search WHERE id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS events_fts_update_after
+AFTER UPDATE OF title, description, location_name, country, organizer ON events BEGIN
+    INSERT INTO events_fts (rowid, title, description, location_name, country, organizer, folded)
+    SELECT id, title, description, location_name, country, organizer, folded
+    FROM events_search WHERE id = new.id;
+END;
+
+CREATE VIEW IF NOT EXISTS organizers_search AS
+SELECT id, name, description,
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(
+        name,
+        'ł', 'l'), 'Ł', 'L'), 'ø', 'o'), 'Ø', 'O'), 'đ', 'd'), 'Đ', 'D'),
+        'ħ', 'h'), 'Ħ', 'H'), 'ı', 'i'), 'ß', 'ss'), 'æ', 'ae'), 'Æ', 'AE'),
+        'œ', 'oe'), 'Œ', 'OE'), 'þ', 'th'), 'Þ', 'TH'), 'ð', 'd'), 'Ð', 'D')
+    AS folded
+FROM organizers;
+
+CREATE VIRTUAL TABLE IF NOT EXISTS organizers_fts USING fts5(
+    name,
+    description,
+    folded,
+    content = 'organizers_search',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_insert AFTER INSERT ON organizers BEGIN
+    INSERT INTO organizers_fts (rowid, name, description, folded)
+    SELECT id, name, description, folded FROM organizers_search WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_delete BEFORE DELETE ON organizers BEGIN
+    INSERT INTO organizers_fts (organizers_fts, rowid, name, description, folded)
+    SELECT 'delete', id, name, description, folded FROM organizers_search WHERE id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_update_before
+BEFORE UPDATE OF name, description ON organizers BEGIN
+    INSERT INTO organizers_fts (organizers_fts, rowid, name, description, folded)
+    SELECT 'delete', id, name, description, folded FROM organizers_search WHERE id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS organizers_fts_update_after
+AFTER UPDATE OF name, description ON organizers BEGIN
+    INSERT INTO organizers_fts (rowid, name, description, folded)
+    SELECT id, name, description, folded FROM organizers_search WHERE id = new.id;
+END;
+
+INSERT INTO events_fts (events_fts) VALUES ('rebuild');
+INSERT INTO organizers_fts (organizers_fts) VALUES ('rebuild');
@@ -0,0 +1,5 @@
+-- Merging a duplicate event moves its history to the surviving event. Moved
+-- rows keep the duplicate's id here, so the survivor's latest decision is
+-- still one made on the survivor itself.
+ALTER TABLE moderation_decisions ADD COLUMN merged_from INTEGER;
+ALTER TABLE event_lifecycle_changes ADD COLUMN merged_from INTEGER;
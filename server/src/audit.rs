@@ -0,0 +1,116 @@
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use sqlx::{Executor, Sqlite};
+
+use crate::auth::AdminIdentity;
+
+/// Entity types recorded in `audit_log.entity_type`
+pub const ENTITY_EVENT: &str = "event";
+pub const ENTITY_SUGGESTION: &str = "suggestion";
+pub const ENTITY_REVISION: &str = "revision";
+pub const ENTITY_ORGANIZER: &str = "organizer";
+pub const ENTITY_ORGANIZER_ACCOUNT: &str = "organizer_account";
+pub const ENTITY_ADMIN: &str = "admin";
+
+/// A single admin action to be written to the audit log
+pub struct AuditRecord<'a> {
+    pub actor: &'a AdminIdentity,
+    /// Dotted action name, e.g. `event.approve`
+    pub action: &'a str,
+    pub entity_type: &'a str,
+    pub entity_id: i64,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub reason: Option<String>,
+}
+
+/// Snapshot any serializable model for the audit log
+pub fn snapshot<T: Serialize>(value: &T) -> Option<Value> {
+    serde_json::to_value(value).ok()
+}
+
+/// Write an audit record. Pass a transaction to make it atomic with the change.
+pub async fn record<'e, E>(executor: E, record: AuditRecord<'_>) -> Result<(), sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let diff = diff(record.before.as_ref(), record.after.as_ref());
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_log (actor_id, actor, action, entity_type, entity_id, before_json, after_json, diff_json, reason, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(record.actor.id)
+    .bind(&record.actor.username)
+    .bind(record.action)
+    .bind(record.entity_type)
+    .bind(record.entity_id)
+    .bind(record.before.map(|v| v.to_string()))
+    .bind(record.after.map(|v| v.to_string()))
+    .bind(diff.to_string())
+    .bind(record.reason)
+    .bind(Utc::now())
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Field-level diff of two JSON objects: `{ field: { "before": .., "after": .. } }`.
+/// A missing side (creation or deletion) is treated as an empty object.
+pub fn diff(before: Option<&Value>, after: Option<&Value>) -> Value {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for (key, old) in before {
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            changes.insert(key.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+    for (key, new) in after {
+        if !before.contains_key(key) && !new.is_null() {
+            changes.insert(key.clone(), json!({ "before": Value::Null, "after": new }));
+        }
+    }
+    Value::Object(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_reports_changed_fields_only() {
+        let before = json!({ "title": "Old", "status": "pending", "country": "Poland" });
+        let after = json!({ "title": "New", "status": "pending", "country": null });
+
+        let changes = diff(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            json!({
+                "title": { "before": "Old", "after": "New" },
+                "country": { "before": "Poland", "after": null },
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_for_deletion_and_creation() {
+        let snapshot = json!({ "title": "Ride", "video_url": null });
+
+        let deleted = diff(Some(&snapshot), None);
+        assert_eq!(deleted["title"], json!({ "before": "Ride", "after": null }));
+
+        let created = diff(None, Some(&snapshot));
+        assert_eq!(
+            created,
+            json!({ "title": { "before": null, "after": "Ride" } })
+        );
+    }
+}
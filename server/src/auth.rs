@@ -0,0 +1,497 @@
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap},
+};
+use chrono::{DateTime, Duration, Utc};
+use rand::{rngs::OsRng, RngCore};
+use schemars::JsonSchema;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::error::ApiError;
+use crate::models::{OrganizerAccount, Role};
+use crate::AppState;
+
+/// How long a login session stays valid
+pub const SESSION_TTL_HOURS: i64 = 12;
+
+/// Minimum length for admin passwords
+pub const MIN_PASSWORD_LENGTH: usize = 12;
+
+/// Username recorded for actions taken with the `ADMIN_API_KEY` break-glass key
+pub const BREAK_GLASS_USERNAME: &str = "break-glass";
+
+/// The admin behind a request
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AdminIdentity {
+    /// `None` when authenticated with the break-glass key
+    pub id: Option<i64>,
+    pub username: String,
+    pub role: Role,
+}
+
+/// An organizer account behind a request
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OrganizerIdentity {
+    pub id: i64,
+    pub username: String,
+    pub verified: bool,
+    /// Organizers this account manages
+    pub organizer_ids: Vec<i64>,
+}
+
+impl OrganizerIdentity {
+    /// Identity for the audit log. `audit_log.actor_id` holds admin ids,
+    /// so organizer accounts are recorded by prefixed username only.
+    pub fn audit_actor(&self) -> AdminIdentity {
+        AdminIdentity {
+            id: None,
+            username: format!("organizer:{}", self.username),
+            role: Role::Viewer,
+        }
+    }
+}
+
+/// Whoever is behind an authenticated request
+#[derive(Debug, Clone)]
+pub enum Principal {
+    Admin(AdminIdentity),
+    Organizer(OrganizerIdentity),
+}
+
+/// Something a request wants to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Anything under `/api/admin`
+    Administer,
+    /// Create and edit the events of an organizer (`None` for unlinked events)
+    ManageEvents(Option<i64>),
+    /// Publish an organizer's events without moderation
+    Publish(Option<i64>),
+}
+
+impl Principal {
+    pub fn allows(&self, permission: Permission) -> bool {
+        match (self, permission) {
+            (Principal::Admin(_), Permission::Administer) => true,
+            (Principal::Admin(admin), Permission::ManageEvents(_)) => admin.role >= Role::Editor,
+            (Principal::Admin(admin), Permission::Publish(_)) => admin.role >= Role::Moderator,
+            (Principal::Organizer(_), Permission::Administer) => false,
+            (Principal::Organizer(account), Permission::ManageEvents(organizer_id)) => {
+                organizer_id.is_some_and(|id| account.organizer_ids.contains(&id))
+            }
+            (Principal::Organizer(account), Permission::Publish(organizer_id)) => {
+                account.verified
+                    && organizer_id.is_some_and(|id| account.organizer_ids.contains(&id))
+            }
+        }
+    }
+
+    /// `403 Forbidden` unless the permission is granted
+    pub fn require(&self, permission: Permission) -> Result<(), ApiError> {
+        if self.allows(permission) {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden)
+        }
+    }
+
+    /// Identity for the audit log
+    pub fn audit_actor(&self) -> AdminIdentity {
+        match self {
+            Principal::Admin(admin) => admin.clone(),
+            Principal::Organizer(account) => account.audit_actor(),
+        }
+    }
+}
+
+/// Break-glass admin key from the environment (optional)
+fn get_admin_api_key() -> Option<String> {
+    std::env::var("ADMIN_API_KEY")
+        .ok()
+        .filter(|key| !key.is_empty())
+}
+
+/// Reject passwords shorter than [`MIN_PASSWORD_LENGTH`]
+pub fn check_password(password: &str) -> Result<(), ApiError> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(ApiError::field(
+            "password",
+            "length",
+            format!("must be at least {} characters", MIN_PASSWORD_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+/// Hash a password as an argon2id PHC string
+pub fn hash_password(password: &str) -> anyhow::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!("failed to hash password: {}", e))?;
+    Ok(hash.to_string())
+}
+
+/// Check a password against a stored PHC string
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// Random 256-bit bearer token, hex encoded
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Tokens are stored hashed so a database leak does not leak sessions
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Extract `Authorization: Bearer <token>`
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+}
+
+/// Resolve who is behind a request: an admin or organizer session token,
+/// or the `X-Admin-Key` break-glass key
+pub async fn authenticate(db: &SqlitePool, headers: &HeaderMap) -> Result<Principal, ApiError> {
+    if let Some(token) = bearer_token(headers) {
+        let token_hash = hash_token(token);
+        let now = Utc::now();
+
+        let admin: Option<(i64, String, String)> = sqlx::query_as(
+            r#"
+            SELECT a.id, a.username, a.role
+            FROM admin_sessions s
+            JOIN admins a ON a.id = s.admin_id
+            WHERE s.token_hash = ? AND s.revoked_at IS NULL AND s.expires_at > ?
+            "#,
+        )
+        .bind(&token_hash)
+        .bind(now)
+        .fetch_optional(db)
+        .await?;
+        if let Some((id, username, role)) = admin {
+            return Ok(Principal::Admin(AdminIdentity {
+                id: Some(id),
+                username,
+                role: role.into(),
+            }));
+        }
+
+        let account: Option<(i64, String, bool)> = sqlx::query_as(
+            r#"
+            SELECT a.id, a.username, a.verified
+            FROM organizer_sessions s
+            JOIN organizer_accounts a ON a.id = s.account_id
+            WHERE s.token_hash = ? AND s.revoked_at IS NULL AND s.expires_at > ?
+            "#,
+        )
+        .bind(&token_hash)
+        .bind(now)
+        .fetch_optional(db)
+        .await?;
+        let (id, username, verified) = account.ok_or(ApiError::Unauthorized)?;
+
+        let organizer_ids = account_organizer_ids(db, id).await?;
+        return Ok(Principal::Organizer(OrganizerIdentity {
+            id,
+            username,
+            verified,
+            organizer_ids,
+        }));
+    }
+
+    let key_matches = match (get_admin_api_key(), headers.get("x-admin-key")) {
+        (Some(expected), Some(given)) => given.to_str().map(|k| k == expected).unwrap_or(false),
+        _ => false,
+    };
+    if key_matches {
+        return Ok(Principal::Admin(AdminIdentity {
+            id: None,
+            username: BREAK_GLASS_USERNAME.to_string(),
+            role: Role::Owner,
+        }));
+    }
+
+    Err(ApiError::Unauthorized)
+}
+
+/// Like `authenticate`, but `None` for requests without any credentials
+pub async fn authenticate_optional(
+    db: &SqlitePool,
+    headers: &HeaderMap,
+) -> Result<Option<Principal>, ApiError> {
+    if !headers.contains_key(header::AUTHORIZATION) && !headers.contains_key("x-admin-key") {
+        return Ok(None);
+    }
+    authenticate(db, headers).await.map(Some)
+}
+
+/// Authenticate a request and check it may perform `permission`
+pub async fn authorize(
+    db: &SqlitePool,
+    headers: &HeaderMap,
+    permission: Permission,
+) -> Result<Principal, ApiError> {
+    let principal = authenticate(db, headers).await?;
+    principal.require(permission)?;
+    Ok(principal)
+}
+
+/// Resolve the admin behind a request; organizer accounts get `403`
+pub async fn authenticate_admin(
+    db: &SqlitePool,
+    headers: &HeaderMap,
+) -> Result<AdminIdentity, ApiError> {
+    match authorize(db, headers, Permission::Administer).await? {
+        Principal::Admin(admin) => Ok(admin),
+        Principal::Organizer(_) => Err(ApiError::Forbidden),
+    }
+}
+
+/// Minimum admin role for an endpoint, as a type for `RequireRole`
+pub trait MinimumRole {
+    const ROLE: Role;
+}
+
+pub struct Viewer;
+pub struct Moderator;
+pub struct Editor;
+pub struct Owner;
+
+impl MinimumRole for Viewer {
+    const ROLE: Role = Role::Viewer;
+}
+impl MinimumRole for Moderator {
+    const ROLE: Role = Role::Moderator;
+}
+impl MinimumRole for Editor {
+    const ROLE: Role = Role::Editor;
+}
+impl MinimumRole for Owner {
+    const ROLE: Role = Role::Owner;
+}
+
+/// Extractor for admin endpoints: the admin behind the request, who must hold
+/// at least role `R`. Rejects with `401` without valid admin credentials and
+/// `403` for organizer accounts or a lower role.
+pub struct RequireRole<R> {
+    pub admin: AdminIdentity,
+    role: PhantomData<R>,
+}
+
+#[async_trait]
+impl<R: MinimumRole> FromRequestParts<Arc<AppState>> for RequireRole<R> {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let admin = authenticate_admin(&state.db, &parts.headers).await?;
+        if admin.role < R::ROLE {
+            return Err(ApiError::Forbidden);
+        }
+        Ok(RequireRole {
+            admin,
+            role: PhantomData,
+        })
+    }
+}
+
+/// Organizer ids managed by an organizer account
+pub async fn account_organizer_ids(
+    db: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT organizer_id FROM organizer_account_organizers WHERE account_id = ? ORDER BY organizer_id",
+    )
+    .bind(account_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Create a session for an admin and return the plaintext token
+pub async fn create_session(
+    db: &SqlitePool,
+    admin_id: i64,
+) -> anyhow::Result<(String, DateTime<Utc>)> {
+    let token = generate_token();
+    let expires_at = Utc::now() + Duration::hours(SESSION_TTL_HOURS);
+
+    sqlx::query("INSERT INTO admin_sessions (admin_id, token_hash, expires_at) VALUES (?, ?, ?)")
+        .bind(admin_id)
+        .bind(hash_token(&token))
+        .bind(expires_at)
+        .execute(db)
+        .await?;
+
+    Ok((token, expires_at))
+}
+
+/// Create a session for an organizer account and return the plaintext token
+pub async fn create_organizer_session(
+    db: &SqlitePool,
+    account_id: i64,
+) -> anyhow::Result<(String, DateTime<Utc>)> {
+    let token = generate_token();
+    let expires_at = Utc::now() + Duration::hours(SESSION_TTL_HOURS);
+
+    sqlx::query(
+        "INSERT INTO organizer_sessions (account_id, token_hash, expires_at) VALUES (?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(hash_token(&token))
+    .bind(expires_at)
+    .execute(db)
+    .await?;
+
+    Ok((token, expires_at))
+}
+
+/// Load an organizer account with the organizers it manages
+pub async fn fetch_organizer_account(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<Option<OrganizerAccount>, sqlx::Error> {
+    let account: Option<OrganizerAccount> = sqlx::query_as(
+        "SELECT id, username, verified, created_at, last_login_at FROM organizer_accounts WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let Some(mut account) = account else {
+        return Ok(None);
+    };
+    account.organizer_ids = sqlx::query_scalar(
+        "SELECT organizer_id FROM organizer_account_organizers WHERE account_id = ? ORDER BY organizer_id",
+    )
+    .bind(id)
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(Some(account))
+}
+
+/// Create an admin account, returning its id
+pub async fn create_admin(
+    db: &SqlitePool,
+    username: &str,
+    password: &str,
+    role: Role,
+) -> anyhow::Result<i64> {
+    let hash = hash_password(password)?;
+    let result = sqlx::query("INSERT INTO admins (username, password_hash, role) VALUES (?, ?, ?)")
+        .bind(username)
+        .bind(hash)
+        .bind(role.to_string())
+        .execute(db)
+        .await?;
+    Ok(result.last_insert_rowid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_hash_roundtrip() {
+        let hash = hash_password("correct horse battery staple").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password("correct horse battery staple", &hash));
+        assert!(!verify_password("wrong password", &hash));
+        assert!(!verify_password("anything", "not-a-phc-string"));
+    }
+
+    #[test]
+    fn test_tokens_are_random_and_hashed() {
+        let a = generate_token();
+        let b = generate_token();
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, b);
+        assert_eq!(hash_token(&a), hash_token(&a));
+        assert_ne!(hash_token(&a), a);
+    }
+
+    fn organizer(verified: bool) -> Principal {
+        Principal::Organizer(OrganizerIdentity {
+            id: 1,
+            username: "nh".to_string(),
+            verified,
+            organizer_ids: vec![2, 5],
+        })
+    }
+
+    #[test]
+    fn test_permissions() {
+        let admin = |role| {
+            Principal::Admin(AdminIdentity {
+                id: Some(1),
+                username: "alice".to_string(),
+                role,
+            })
+        };
+        let owner = admin(Role::Owner);
+        assert!(owner.allows(Permission::Administer));
+        assert!(owner.allows(Permission::ManageEvents(None)));
+        assert!(owner.allows(Permission::Publish(Some(3))));
+        assert!(admin(Role::Viewer).allows(Permission::Administer));
+        assert!(!admin(Role::Viewer).allows(Permission::ManageEvents(Some(2))));
+        assert!(!admin(Role::Moderator).allows(Permission::ManageEvents(Some(2))));
+        assert!(admin(Role::Editor).allows(Permission::ManageEvents(Some(2))));
+        assert!(admin(Role::Moderator).allows(Permission::Publish(Some(2))));
+
+        let unverified = organizer(false);
+        assert!(!unverified.allows(Permission::Administer));
+        assert!(unverified.allows(Permission::ManageEvents(Some(2))));
+        assert!(unverified.allows(Permission::ManageEvents(Some(5))));
+        assert!(!unverified.allows(Permission::ManageEvents(Some(3))));
+        assert!(!unverified.allows(Permission::ManageEvents(None)));
+        assert!(!unverified.allows(Permission::Publish(Some(2))));
+        assert!(matches!(
+            unverified.require(Permission::Administer),
+            Err(ApiError::Forbidden)
+        ));
+
+        let verified = organizer(true);
+        assert!(verified.allows(Permission::Publish(Some(2))));
+        assert!(!verified.allows(Permission::Publish(Some(3))));
+        assert!(!verified.allows(Permission::Publish(None)));
+        assert_eq!(verified.audit_actor().username, "organizer:nh");
+        assert_eq!(verified.audit_actor().id, None);
+    }
+
+    #[test]
+    fn test_bearer_token_parsing() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(bearer_token(&headers), None);
+        headers.insert(header::AUTHORIZATION, "Bearer abc123".parse().unwrap());
+        assert_eq!(bearer_token(&headers), Some("abc123"));
+        headers.insert(header::AUTHORIZATION, "Basic abc123".parse().unwrap());
+        assert_eq!(bearer_token(&headers), None);
+    }
+}
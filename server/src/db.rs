@@ -1,4 +1,367 @@
-use sqlx::SqlitePool;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{Executor, QueryBuilder, Sqlite, SqlitePool};
+
+use crate::geo::BoundingBox;
+use crate::models::{
+    AuditLogQuery, Event, EventFilters, EventRoute, EventStatus, EventTime, OccurrenceOverride,
+    TimeFilter, UpdateEventRequest,
+};
+use crate::recurrence::Schedule;
+use crate::timezone;
+
+/// Column list matching the `Event` model
+pub const EVENT_COLUMNS: &str =
+    "id, title, description, organizer, organizer_id, location_name, country, event_link, \
+     latitude, longitude, event_date, end_date, rrule, image_url, video_url, status, lifecycle, postponed_to, timezone, created_at, \
+     approved_at, updated_at";
+
+/// `SET` clause stamping `approved_at` when an event moves into `approved`;
+/// binds the new status, then the time. Re-approving keeps the original time.
+pub const APPROVED_AT_SQL: &str =
+    "approved_at = CASE WHEN status != 'approved' AND ? = 'approved' THEN ? ELSE approved_at END";
+
+/// SQL expression for when an event is over: the end of a one-off event,
+/// or of the last occurrence of a series (NULL while the series is open-ended)
+pub const EVENT_END_SQL: &str =
+    "(CASE WHEN rrule IS NULL THEN COALESCE(end_date, event_date) ELSE recurrence_end END)";
+
+/// Load a single event by id, regardless of status
+pub async fn fetch_event<'e, E>(executor: E, id: i64) -> Result<Option<Event>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let event: Option<Event> = sqlx::query_as(&format!(
+        "SELECT {} FROM events WHERE id = ?",
+        EVENT_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(event.map(Event::with_parsed_status))
+}
+
+/// Load the planned route of an event, if it has one
+pub async fn fetch_event_route<'e, E>(
+    executor: E,
+    event_id: i64,
+) -> Result<Option<EventRoute>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let route: Option<EventRoute> = sqlx::query_as(
+        "SELECT event_id, coordinates_json, distance_km, source_points, updated_at FROM event_routes WHERE event_id = ?",
+    )
+    .bind(event_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(route.map(EventRoute::with_bbox))
+}
+
+/// Apply the non-`None` fields of an update. Empty strings clear nullable columns.
+/// `schedule` is the validated schedule the event ends up with (see `Schedule::updated`).
+/// With `only_if`, the event is left alone unless it still has that status.
+/// Returns whether the event was updated.
+pub async fn apply_event_update<'e, E>(
+    executor: E,
+    id: i64,
+    payload: &UpdateEventRequest,
+    schedule: &Schedule,
+    only_if: Option<EventStatus>,
+) -> Result<bool, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    // Build dynamic update query
+    let mut updates = Vec::new();
+    if payload.title.is_some() {
+        updates.push("title = ?");
+    }
+    if payload.description.is_some() {
+        updates.push("description = ?");
+    }
+    if payload.organizer.is_some() {
+        updates.push("organizer = ?");
+    }
+    if payload.location_name.is_some() {
+        updates.push("location_name = ?");
+    }
+    if payload.country.is_some() {
+        updates.push("country = ?");
+    }
+    if payload.latitude.is_some() {
+        updates.push("latitude = ?");
+    }
+    if payload.longitude.is_some() {
+        updates.push("longitude = ?");
+    }
+    if payload.event_date.is_some() {
+        updates.push("event_date = ?");
+    }
+    if payload.end_date.is_some() {
+        updates.push("end_date = ?");
+    }
+    // Moving an event can move it to another timezone
+    let timezone_changed =
+        payload.timezone.is_some() || payload.latitude.is_some() || payload.longitude.is_some();
+    if timezone_changed {
+        updates.push("timezone = ?");
+    }
+    let schedule_changed = payload.event_date.is_some()
+        || payload.end_date.is_some()
+        || payload.rrule.is_some()
+        || timezone_changed;
+    if schedule_changed {
+        updates.push("rrule = ?");
+        updates.push("recurrence_end = ?");
+    }
+    if payload.image_url.is_some() {
+        updates.push("image_url = ?");
+    }
+    if payload.video_url.is_some() {
+        updates.push("video_url = ?");
+    }
+    if payload.event_link.is_some() {
+        updates.push("event_link = ?");
+    }
+    if payload.status.is_some() {
+        updates.push(APPROVED_AT_SQL);
+        updates.push("status = ?");
+    }
+
+    if updates.is_empty() {
+        return Ok(true);
+    }
+    updates.push("updated_at = ?");
+
+    let mut query = format!("UPDATE events SET {} WHERE id = ?", updates.join(", "));
+    if only_if.is_some() {
+        query.push_str(" AND status = ?");
+    }
+    let mut q = sqlx::query(&query);
+
+    if let Some(ref v) = payload.title {
+        q = q.bind(v);
+    }
+    if let Some(ref v) = payload.description {
+        q = q.bind(if v.is_empty() {
+            None::<String>
+        } else {
+            Some(v.clone())
+        });
+    }
+    if let Some(ref v) = payload.organizer {
+        q = q.bind(v);
+    }
+    if let Some(ref v) = payload.location_name {
+        q = q.bind(v);
+    }
+    if let Some(ref v) = payload.country {
+        q = q.bind(if v.is_empty() {
+            None::<String>
+        } else {
+            Some(v.clone())
+        });
+    }
+    if let Some(v) = payload.latitude {
+        q = q.bind(v);
+    }
+    if let Some(v) = payload.longitude {
+        q = q.bind(v);
+    }
+    // Dates come from the schedule, where local times are already resolved
+    if payload.event_date.is_some() {
+        q = q.bind(schedule.start);
+    }
+    if payload.end_date.is_some() {
+        q = q.bind(schedule.end);
+    }
+    if timezone_changed {
+        q = q.bind(schedule.tz.name());
+    }
+    if schedule_changed {
+        q = q.bind(schedule.rrule()).bind(recurrence_end(schedule));
+    }
+    if let Some(ref v) = payload.image_url {
+        q = q.bind(if v.is_empty() {
+            None::<String>
+        } else {
+            Some(v.clone())
+        });
+    }
+    if let Some(ref v) = payload.video_url {
+        q = q.bind(if v.is_empty() {
+            None::<String>
+        } else {
+            Some(v.clone())
+        });
+    }
+    if let Some(ref v) = payload.event_link {
+        q = q.bind(if v.is_empty() {
+            None::<String>
+        } else {
+            Some(v.clone())
+        });
+    }
+    let now = Utc::now();
+    if let Some(ref v) = payload.status {
+        q = q.bind(v).bind(now).bind(v);
+    }
+
+    q = q.bind(now).bind(id);
+    if let Some(status) = only_if {
+        q = q.bind(status.to_string());
+    }
+    let result = q.execute(executor).await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Value of `events.recurrence_end` for a schedule
+pub fn recurrence_end(schedule: &Schedule) -> Option<DateTime<Utc>> {
+    schedule.rule.as_ref().and(schedule.series_end())
+}
+
+/// Load occurrence overrides for a set of events
+pub async fn fetch_occurrence_overrides<'e, E>(
+    executor: E,
+    event_ids: &[i64],
+) -> Result<Vec<OccurrenceOverride>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+        "SELECT event_id, occurrence_start, cancelled, start_date, end_date, title, description, \
+         location_name, latitude, longitude, updated_at \
+         FROM event_occurrence_overrides WHERE event_id IN (",
+    );
+    let mut ids = query.separated(", ");
+    for id in event_ids {
+        ids.push_bind(*id);
+    }
+    ids.push_unseparated(") ORDER BY event_id, occurrence_start");
+    query.build_query_as().fetch_all(executor).await
+}
+
+/// Append the public list filters as `AND ...` clauses.
+/// The builder must already contain a `WHERE` clause.
+pub fn push_event_filters(
+    qb: &mut QueryBuilder<'_, Sqlite>,
+    filters: &EventFilters,
+    now: DateTime<Utc>,
+) {
+    if let Some(ref country) = filters.country {
+        qb.push(" AND country = ").push_bind(country.clone());
+    }
+    if let Some(ref slug) = filters.organizer {
+        qb.push(" AND organizer_id IN (SELECT id FROM organizers WHERE slug = ")
+            .push_bind(slug.clone())
+            .push(")");
+    }
+    if let Some(from) = filters.from {
+        qb.push(" AND event_date >= ").push_bind(from);
+    }
+    if let Some(to) = filters.to {
+        qb.push(" AND event_date <= ").push_bind(to);
+    }
+    if let Some(lifecycle) = filters.lifecycle {
+        qb.push(" AND lifecycle = ")
+            .push_bind(lifecycle.to_string());
+    }
+    match filters.time {
+        TimeFilter::All => {}
+        // Multi-day events and unfinished series count as upcoming until they end
+        TimeFilter::Upcoming => {
+            qb.push(format!(" AND ({0} IS NULL OR {0} > ", EVENT_END_SQL))
+                .push_bind(now)
+                .push(")");
+        }
+        TimeFilter::Past => {
+            qb.push(format!(" AND {} <= ", EVENT_END_SQL))
+                .push_bind(now);
+        }
+    }
+}
+
+/// (title, description, organizer, organizer_id, location, country, lat, lng, local date, status, video_url, event_link)
+type SampleEvent<'a> = (
+    &'a str,
+    &'a str,
+    &'a str,
+    Option<i64>,
+    &'a str,
+    &'a str,
+    f64,
+    f64,
+    &'a str,
+    &'a str,
+    Option<&'a str>,
+    Option<&'a str>,
+);
+
+/// Append audit log filters as `AND ...` clauses
+pub fn push_audit_filters(qb: &mut QueryBuilder<'_, Sqlite>, params: &AuditLogQuery) {
+    if let Some(ref entity_type) = params.entity_type {
+        qb.push(" AND entity_type = ")
+            .push_bind(entity_type.clone());
+    }
+    if let Some(entity_id) = params.entity_id {
+        qb.push(" AND entity_id = ").push_bind(entity_id);
+    }
+    if let Some(ref actor) = params.actor {
+        qb.push(" AND actor = ").push_bind(actor.clone());
+    }
+    if let Some(ref action) = params.action {
+        qb.push(" AND action = ").push_bind(action.clone());
+    }
+    if let Some(from) = params.from {
+        qb.push(" AND created_at >= ").push_bind(from);
+    }
+    if let Some(to) = params.to {
+        qb.push(" AND created_at <= ").push_bind(to);
+    }
+}
+
+/// Append a coordinate range as `AND ...` clauses (served by `idx_events_status_lat_lng`)
+pub fn push_bbox_filter(qb: &mut QueryBuilder<'_, Sqlite>, bbox: &BoundingBox) {
+    qb.push(" AND latitude BETWEEN ")
+        .push_bind(bbox.min_lat)
+        .push(" AND ")
+        .push_bind(bbox.max_lat);
+    if bbox.crosses_antimeridian() {
+        qb.push(" AND (longitude >= ")
+            .push_bind(bbox.min_lng)
+            .push(" OR longitude <= ")
+            .push_bind(bbox.max_lng)
+            .push(")");
+    } else {
+        qb.push(" AND longitude BETWEEN ")
+            .push_bind(bbox.min_lng)
+            .push(" AND ")
+            .push_bind(bbox.max_lng);
+    }
+}
+
+/// Derive the timezone of events stored before it was tracked.
+/// Their UTC dates are kept as they are. Returns the number of events updated.
+pub async fn backfill_timezones(pool: &SqlitePool) -> Result<usize, sqlx::Error> {
+    let rows: Vec<(i64, f64, f64)> =
+        sqlx::query_as("SELECT id, latitude, longitude FROM events WHERE timezone IS NULL")
+            .fetch_all(pool)
+            .await?;
+
+    for (id, lat, lng) in &rows {
+        sqlx::query("UPDATE events SET timezone = ? WHERE id = ?")
+            .bind(timezone::resolve(*lat, *lng).name())
+            .bind(id)
+            .execute(pool)
+            .await?;
+    }
+    Ok(rows.len())
+}
 
 /// Add sample organizers and events for development/demo
 pub async fn seed_sample_data(pool: &SqlitePool) -> anyhow::Result<()> {
@@ -56,20 +419,7 @@ pub async fn seed_sample_data(pool: &SqlitePool) -> anyhow::Result<()> {
     let nh_id = nh_id.map(|x| x.0);
     let berlin_id = berlin_id.map(|x| x.0);
 
-    let sample_events: Vec<(
-        &str,
-        &str,
-        &str,
-        Option<i64>,
-        &str,
-        &str,
-        f64,
-        f64,
-        &str,
-        &str,
-        Option<&str>,
-        Option<&str>,
-    )> = vec![
+    let sample_events: Vec<SampleEvent> = vec![
         // FUTURE / UPCOMING EVENTS (Sample Data)
         (
             "London DNB On Bike - Spring Edition",
@@ -368,6 +718,7 @@ pub async fn seed_sample_data(pool: &SqlitePool) -> anyhow::Result<()> {
         ),
     ];
 
+    let now = Utc::now();
     for (
         title,
         desc,
@@ -383,10 +734,17 @@ pub async fn seed_sample_data(pool: &SqlitePool) -> anyhow::Result<()> {
         event_link,
     ) in &sample_events
     {
+        // Sample dates are local wall-clock times at the ride's location
+        let tz = timezone::resolve(*lat, *lng);
+        let local = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S")?;
+        let date = EventTime::Local(local)
+            .resolve(tz)
+            .map_err(|e| anyhow::anyhow!(e))?;
+
         sqlx::query(
             r#"
-            INSERT INTO events (title, description, organizer, organizer_id, location_name, country, latitude, longitude, event_date, status, video_url, event_link)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO events (title, description, organizer, organizer_id, location_name, country, latitude, longitude, event_date, timezone, status, video_url, event_link, approved_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(title)
@@ -398,9 +756,12 @@ pub async fn seed_sample_data(pool: &SqlitePool) -> anyhow::Result<()> {
         .bind(lat)
         .bind(lng)
         .bind(date)
+        .bind(tz.name())
         .bind(status)
         .bind(video_url)
         .bind(event_link)
+        .bind((*status == "approved").then_some(now))
+        .bind(now)
         .execute(pool)
         .await?;
     }
@@ -0,0 +1,206 @@
+use chrono::Duration;
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use std::collections::HashSet;
+
+use crate::db::{self, EVENT_COLUMNS};
+use crate::geo::{self, BoundingBox};
+use crate::models::Event;
+
+/// Events further apart than this are never considered duplicates
+pub const MAX_DISTANCE_KM: f64 = 25.0;
+
+/// Events further apart in time than this are never considered duplicates
+pub const MAX_DAYS_APART: i64 = 7;
+
+/// Events further apart in time than this get no credit for their dates, so
+/// weekly rides from the same spot aren't taken for reposts
+pub const DATE_CREDIT_DAYS: f64 = 2.0;
+
+/// Minimum score for a candidate to be flagged
+pub const DUPLICATE_THRESHOLD: f64 = 0.6;
+
+/// Lowercase, strip diacritics and punctuation, collapse whitespace
+pub fn normalize(text: &str) -> String {
+    deunicode::deunicode(text)
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Sørensen–Dice coefficient over character bigrams of the normalized titles
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    fn bigrams(s: &str) -> HashSet<(char, char)> {
+        let chars: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+        chars.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    let (a, b) = (normalize(a), normalize(b));
+    if a == b {
+        return 1.0;
+    }
+    let (a, b) = (bigrams(&a), bigrams(&b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    2.0 * a.intersection(&b).count() as f64 / (a.len() + b.len()) as f64
+}
+
+/// Score in [0, 1] of how likely two events describe the same ride;
+/// `None` if they are too far apart in space or time
+pub fn score(a: &Event, b: &Event) -> Option<f64> {
+    let distance_km = geo::haversine_km(a.latitude, a.longitude, b.latitude, b.longitude);
+    let days_apart = (a.event_date - b.event_date).num_minutes().abs() as f64 / (24.0 * 60.0);
+    if distance_km > MAX_DISTANCE_KM || days_apart > MAX_DAYS_APART as f64 {
+        return None;
+    }
+
+    let same_organizer = match (a.organizer_id, b.organizer_id) {
+        (Some(x), Some(y)) => x == y,
+        _ => normalize(&a.organizer) == normalize(&b.organizer),
+    };
+    let title_similarity = title_similarity(&a.title, &b.title);
+
+    // The date matters most: a recurring ride repeats the spot, title and organizer
+    // of last week's post, while a repost is on (nearly) the same day. Titles are
+    // often reworded between posts.
+    let date_score = (1.0 - days_apart / DATE_CREDIT_DAYS).max(0.0);
+    let distance_score = 1.0 - distance_km / MAX_DISTANCE_KM;
+    Some(
+        0.45 * date_score
+            + 0.2 * distance_score
+            + 0.25 * title_similarity
+            + if same_organizer { 0.1 } else { 0.0 },
+    )
+}
+
+/// Score an event against nearby non-rejected events and store likely duplicates.
+/// Returns the number of candidates flagged.
+pub async fn flag_duplicates(db: &SqlitePool, event: &Event) -> Result<usize, sqlx::Error> {
+    let bbox = BoundingBox::around(event.latitude, event.longitude, MAX_DISTANCE_KM);
+    let window = Duration::days(MAX_DAYS_APART);
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status != 'rejected' AND id != ",
+        EVENT_COLUMNS
+    ));
+    query.push_bind(event.id);
+    db::push_bbox_filter(&mut query, &bbox);
+    query
+        .push(" AND event_date BETWEEN ")
+        .push_bind(event.event_date - window)
+        .push(" AND ")
+        .push_bind(event.event_date + window);
+
+    let candidates: Vec<Event> = query.build_query_as().fetch_all(db).await?;
+
+    let mut flagged = 0;
+    for candidate in candidates {
+        let Some(score) = score(event, &candidate) else {
+            continue;
+        };
+        if score < DUPLICATE_THRESHOLD {
+            continue;
+        }
+        sqlx::query(
+            "INSERT OR REPLACE INTO event_duplicate_candidates (event_id, candidate_id, score) VALUES (?, ?, ?)",
+        )
+        .bind(event.id)
+        .bind(candidate.id)
+        .bind(score)
+        .execute(db)
+        .await?;
+        flagged += 1;
+    }
+
+    Ok(flagged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sample_event;
+    use chrono::{TimeZone, Utc};
+
+    fn event(id: i64, title: &str, organizer: &str, lat: f64, lng: f64, day: u32) -> Event {
+        Event {
+            id,
+            title: title.to_string(),
+            organizer: organizer.to_string(),
+            organizer_id: None,
+            latitude: lat,
+            longitude: lng,
+            event_date: Utc.with_ymd_and_hms(2026, 4, day, 15, 0, 0).unwrap(),
+            ..sample_event()
+        }
+    }
+
+    #[test]
+    fn test_normalize_strips_diacritics_and_punctuation() {
+        assert_eq!(
+            normalize("  Kraków: Bass na Kółkach! "),
+            "krakow bass na kolkach"
+        );
+        assert_eq!(
+            normalize("DnB On The Bike - LONDON"),
+            "dnb on the bike london"
+        );
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert_eq!(
+            title_similarity("Warsaw DNB Przejazd", "warsaw dnb przejazd!"),
+            1.0
+        );
+        assert!(title_similarity("Warsaw DNB Przejazd", "Warsaw DnB Przejazd 2026") > 0.8);
+        assert!(title_similarity("Warsaw DNB Przejazd", "Berlin Bass Fahrt") < 0.3);
+        assert_eq!(title_similarity("", "Anything"), 0.0);
+    }
+
+    #[test]
+    fn test_score_flags_same_ride_posted_twice() {
+        let original = event(1, "Warsaw DNB Przejazd", "NH Kolektyw", 52.2297, 21.0122, 5);
+        let repost = event(
+            2,
+            "Warszawa DnB przejazd",
+            "nh kolektyw",
+            52.2310,
+            21.0100,
+            5,
+        );
+        let result = score(&original, &repost).unwrap();
+        assert!(result >= DUPLICATE_THRESHOLD, "{}", result);
+
+        // Same city, different week and crew: not a duplicate
+        let other = event(3, "Bass Ride", "Someone Else", 52.25, 21.05, 11);
+        assert!(score(&original, &other).unwrap() < DUPLICATE_THRESHOLD);
+
+        // A day off is still likely the same ride
+        let next_day = event(5, "Warsaw DNB Przejazd", "NH Kolektyw", 52.2297, 21.0122, 6);
+        assert!(score(&original, &next_day).unwrap() >= DUPLICATE_THRESHOLD);
+
+        // Different city: never scored
+        let krakow = event(4, "Warsaw DNB Przejazd", "NH Kolektyw", 50.0647, 19.945, 5);
+        assert!(score(&original, &krakow).is_none());
+    }
+
+    #[test]
+    fn test_score_ignores_weekly_rides() {
+        // Same crew, title and spot a week later is the next ride, not a repost
+        let first = event(1, "Warsaw DNB Przejazd", "NH Kolektyw", 52.2297, 21.0122, 5);
+        let next_week = event(
+            2,
+            "Warsaw DNB Przejazd",
+            "NH Kolektyw",
+            52.2297,
+            21.0122,
+            12,
+        );
+        let result = score(&first, &next_week).unwrap();
+        assert!(result < DUPLICATE_THRESHOLD, "{}", result);
+    }
+}
@@ -0,0 +1,390 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use axum::{
+    async_trait,
+    body::{Body, Bytes},
+    extract::{
+        path::ErrorKind,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Request,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Serialize};
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// Error returned by handlers, rendered as a JSON [`ErrorBody`]
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("one or more fields are invalid")]
+    Validation(#[from] ValidationErrors),
+    #[error("{0}")]
+    BadRequest(Cow<'static, str>),
+    #[error("authentication required")]
+    Unauthorized,
+    #[error("you do not have permission to do this")]
+    Forbidden,
+    #[error("not found")]
+    NotFound,
+    #[error("{0}")]
+    Conflict(Cow<'static, str>),
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("internal error: {0}")]
+    Internal(#[from] anyhow::Error),
+}
+
+/// JSON body of every error response
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ErrorBody {
+    /// Stable, machine-readable error code
+    pub code: &'static str,
+    pub message: String,
+    /// Validator errors keyed by field name; only present for `validation_failed`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(default)]
+    pub fields: BTreeMap<String, Vec<FieldError>>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FieldError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn bad_request(message: impl Into<Cow<'static, str>>) -> Self {
+        ApiError::BadRequest(message.into())
+    }
+
+    pub fn conflict(message: impl Into<Cow<'static, str>>) -> Self {
+        ApiError::Conflict(message.into())
+    }
+
+    /// A validation failure on a single field, for checks the validator derive can't express
+    pub fn field(
+        field: &'static str,
+        code: &'static str,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            field,
+            ValidationError::new(code).with_message(message.into()),
+        );
+        ApiError::Validation(errors)
+    }
+
+    /// A failure on a field only known at runtime, such as a path parameter
+    /// or a JSON key that didn't deserialize
+    fn dynamic_field(field: String, code: &'static str, message: String) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.0.insert(
+            field.into(),
+            ValidationErrorsKind::Field(vec![
+                ValidationError::new(code).with_message(message.into())
+            ]),
+        );
+        ApiError::Validation(errors)
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::Validation(_) | ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden => StatusCode::FORBIDDEN,
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::Database(_) | ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Validation(_) => "validation_failed",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Unauthorized => "unauthorized",
+            ApiError::Forbidden => "forbidden",
+            ApiError::NotFound => "not_found",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::Database(_) | ApiError::Internal(_) => "internal_error",
+        }
+    }
+
+    pub fn body(&self) -> ErrorBody {
+        let (message, fields) = match self {
+            // Internal details stay in the logs
+            ApiError::Database(_) | ApiError::Internal(_) => {
+                ("internal server error".to_string(), BTreeMap::new())
+            }
+            ApiError::Validation(errors) => (self.to_string(), field_errors(errors)),
+            _ => (self.to_string(), BTreeMap::new()),
+        };
+        ErrorBody {
+            code: self.code(),
+            message,
+            fields,
+        }
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        // Bodies that parse but don't fit the type are reported on the failing field
+        if let JsonRejection::JsonDataError(error) = &rejection {
+            let mut source = std::error::Error::source(error);
+            while let Some(error) = source {
+                if let Some(error) =
+                    error.downcast_ref::<serde_path_to_error::Error<serde_json::Error>>()
+                {
+                    return json_field_error(error);
+                }
+                source = error.source();
+            }
+        }
+        ApiError::BadRequest(rejection.body_text().into())
+    }
+}
+
+/// Field error for a JSON body that doesn't match the request type. A missing
+/// field is reported by serde on its parent, so its name comes from the message.
+fn json_field_error(error: &serde_path_to_error::Error<serde_json::Error>) -> ApiError {
+    let message = error.inner().to_string();
+    // serde_json appends the position, which means nothing to API clients
+    let message = match message.rsplit_once(" at line ") {
+        Some((message, _)) => message.to_string(),
+        None => message,
+    };
+    let parent = error.path().to_string();
+    let missing = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'));
+    match missing {
+        Some(field) if parent == "." => {
+            ApiError::dynamic_field(field.to_string(), "required", "is required".to_string())
+        }
+        Some(field) => ApiError::dynamic_field(
+            format!("{}.{}", parent, field),
+            "required",
+            "is required".to_string(),
+        ),
+        None if parent == "." => ApiError::BadRequest(message.into()),
+        None => ApiError::dynamic_field(parent, "invalid_type", message),
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        match &rejection {
+            PathRejection::FailedToDeserializePathParams(error) => match error.kind() {
+                ErrorKind::ParseErrorAtKey {
+                    key, expected_type, ..
+                } => ApiError::dynamic_field(
+                    key.clone(),
+                    "invalid_type",
+                    format!("must be {}", describe_type(expected_type)),
+                ),
+                ErrorKind::ParseErrorAtIndex {
+                    index,
+                    expected_type,
+                    ..
+                } => ApiError::BadRequest(
+                    format!(
+                        "path parameter {} must be {}",
+                        index + 1,
+                        describe_type(expected_type)
+                    )
+                    .into(),
+                ),
+                ErrorKind::ParseError { expected_type, .. } => ApiError::BadRequest(
+                    format!("path parameter must be {}", describe_type(expected_type)).into(),
+                ),
+                _ => ApiError::BadRequest(rejection.body_text().into()),
+            },
+            // The route and the extractor disagree, which is a bug
+            _ => ApiError::Internal(anyhow::anyhow!(rejection.body_text())),
+        }
+    }
+}
+
+/// Name of a Rust type for path parameter errors
+fn describe_type(type_name: &str) -> &'static str {
+    match type_name {
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "usize" | "isize" => {
+            "an integer"
+        }
+        "f32" | "f64" => "a number",
+        "bool" => "true or false",
+        _ => "valid",
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text().into())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match &self {
+            ApiError::Database(e) => tracing::error!("Database error: {}", e),
+            ApiError::Internal(e) => tracing::error!("Internal error: {:#}", e),
+            _ => {}
+        }
+        (self.status(), Json(self.body())).into_response()
+    }
+}
+
+/// `Json` extractor whose rejections are rendered as [`ApiError`]
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(ApiError))]
+pub struct AppJson<T>(pub T);
+
+/// [`AppJson`] for an optional body: an empty body is `T::default()`, while
+/// anything else must be valid JSON
+pub struct AppJsonOrEmpty<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for AppJsonOrEmpty<T>
+where
+    T: DeserializeOwned + Default,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let (parts, body) = request.into_parts();
+        let bytes = Bytes::from_request(Request::from_parts(parts.clone(), body), state)
+            .await
+            .map_err(|rejection| ApiError::bad_request(rejection.body_text()))?;
+        if bytes.is_empty() {
+            return Ok(Self(T::default()));
+        }
+        let AppJson(value) =
+            AppJson::from_request(Request::from_parts(parts, Body::from(bytes)), state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// `Path` extractor whose rejections are rendered as [`ApiError`]
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Path), rejection(ApiError))]
+pub struct AppPath<T>(pub T);
+
+/// `Query` extractor whose rejections are rendered as [`ApiError`]
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(ApiError))]
+pub struct AppQuery<T>(pub T);
+
+fn field_errors(errors: &ValidationErrors) -> BTreeMap<String, Vec<FieldError>> {
+    errors
+        .field_errors()
+        .into_iter()
+        .map(|(field, errors)| {
+            let errors = errors
+                .iter()
+                .map(|e| FieldError {
+                    code: e.code.to_string(),
+                    message: describe(e),
+                })
+                .collect();
+            (field.to_string(), errors)
+        })
+        .collect()
+}
+
+/// Human-readable text for a validator error: its own message if it has
+/// one, otherwise one derived from the rule and its parameters
+fn describe(error: &ValidationError) -> String {
+    if let Some(message) = &error.message {
+        return message.to_string();
+    }
+    let param = |name: &str| error.params.get(name).map(|v| v.to_string());
+    match error.code.as_ref() {
+        "length" => match (param("min"), param("max"), param("equal")) {
+            (_, _, Some(n)) => format!("must be exactly {} characters", n),
+            (Some(min), Some(max), _) => format!("must be between {} and {} characters", min, max),
+            (Some(min), None, _) if min == "1" => "must not be empty".to_string(),
+            (Some(min), None, _) => format!("must be at least {} characters", min),
+            (None, Some(max), _) => format!("must be at most {} characters", max),
+            _ => "has an invalid length".to_string(),
+        },
+        "range" => match (param("min"), param("max")) {
+            (Some(min), Some(max)) => format!("must be between {} and {}", min, max),
+            (Some(min), None) => format!("must be at least {}", min),
+            (None, Some(max)) => format!("must be at most {}", max),
+            _ => "is out of range".to_string(),
+        },
+        "url" => "must be a valid URL".to_string(),
+        "email" => "must be a valid email address".to_string(),
+        "required" => "is required".to_string(),
+        code => format!("is invalid ({})", code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct Ride {
+        #[validate(length(min = 3, max = 200))]
+        title: String,
+        #[validate(range(min = -90.0, max = 90.0))]
+        latitude: f64,
+        #[validate(url)]
+        website: Option<String>,
+    }
+
+    #[test]
+    fn test_validation_errors_are_reported_per_field() {
+        let ride = Ride {
+            title: "DB".to_string(),
+            latitude: 123.0,
+            website: Some("not a url".to_string()),
+        };
+        let error = ApiError::from(ride.validate().unwrap_err());
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+
+        let body = serde_json::to_value(error.body()).unwrap();
+        assert_eq!(body["code"], "validation_failed");
+        assert_eq!(body["fields"]["title"][0]["code"], "length");
+        assert_eq!(
+            body["fields"]["title"][0]["message"],
+            "must be between 3 and 200 characters"
+        );
+        assert_eq!(
+            body["fields"]["latitude"][0]["message"],
+            "must be between -90.0 and 90.0"
+        );
+        assert_eq!(
+            body["fields"]["website"][0]["message"],
+            "must be a valid URL"
+        );
+    }
+
+    #[test]
+    fn test_internal_errors_are_not_leaked() {
+        let error = ApiError::from(sqlx::Error::RowNotFound);
+        let body = serde_json::to_value(error.body()).unwrap();
+        assert_eq!(error.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["code"], "internal_error");
+        assert_eq!(body["message"], "internal server error");
+        assert!(body.get("fields").is_none());
+    }
+
+    #[test]
+    fn test_custom_field_error() {
+        let body = ApiError::field("password", "length", "must be at least 12 characters").body();
+        assert_eq!(
+            body.fields["password"][0].message,
+            "must be at least 12 characters"
+        );
+    }
+}
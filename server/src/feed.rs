@@ -0,0 +1,292 @@
+use axum::http::{header, HeaderMap};
+use chrono::{DateTime, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::ics::UID_DOMAIN;
+use crate::models::{Event, EventLifecycle};
+use crate::share::{self, escape};
+
+/// Number of most recently approved events in a feed
+pub const FEED_LIMIT: i64 = 50;
+
+/// Year in the `tag:` URIs of feed entries; like ICS UIDs, must never change
+const TAG_YEAR: u16 = 2025;
+
+/// Feed flavour, picked by the URL's extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Rss,
+    Atom,
+}
+
+impl Format {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Format::Rss => "application/rss+xml; charset=utf-8",
+            Format::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+}
+
+/// Title and location of one feed
+pub struct Channel<'a> {
+    pub title: &'a str,
+    pub description: &'a str,
+    /// Absolute URL the feed is served from
+    pub self_url: &'a str,
+}
+
+/// Render an RSS 2.0 channel, newest approval first
+pub fn rss(channel: &Channel, events: &[Event]) -> String {
+    let site = share::site_url();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    out.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n");
+    out.push_str(&element("title", channel.title));
+    out.push_str(&element("link", &site));
+    out.push_str(&element("description", channel.description));
+    out.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        escape(channel.self_url)
+    ));
+    if let Some(updated) = last_modified(events) {
+        out.push_str(&element("lastBuildDate", &updated.to_rfc2822()));
+    }
+    for event in events {
+        out.push_str("<item>\n");
+        out.push_str(&element("title", &entry_title(event)));
+        out.push_str(&element("link", &share::event_page_url(event.id)));
+        out.push_str(&format!(
+            "<guid isPermaLink=\"false\">{}</guid>\n",
+            escape(&entry_id(event.id))
+        ));
+        if let Some(approved_at) = event.approved_at {
+            out.push_str(&element("pubDate", &approved_at.to_rfc2822()));
+        }
+        if let Some(ref country) = event.country {
+            out.push_str(&element("category", country));
+        }
+        // RSS descriptions are HTML, so keep the line breaks as <br/>
+        let html = escape(&summary(event)).replace('\n', "<br/>");
+        out.push_str(&element("description", &html));
+        out.push_str("</item>\n");
+    }
+    out.push_str("</channel>\n</rss>\n");
+    out
+}
+
+/// Render an Atom (RFC 4287) feed, newest approval first
+pub fn atom(channel: &Channel, events: &[Event]) -> String {
+    let site = share::site_url();
+    // A feed without entries still needs an <updated>; the epoch keeps it stable
+    let updated = last_modified(events).unwrap_or(DateTime::UNIX_EPOCH);
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    out.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    out.push_str(&element("id", channel.self_url));
+    out.push_str(&element("title", channel.title));
+    out.push_str(&element("subtitle", channel.description));
+    out.push_str(&element("updated", &atom_date(updated)));
+    out.push_str(&format!(
+        "<link href=\"{}\" rel=\"self\" type=\"application/atom+xml\"/>\n",
+        escape(channel.self_url)
+    ));
+    out.push_str(&format!("<link href=\"{}\"/>\n", escape(&site)));
+    for event in events {
+        out.push_str("<entry>\n");
+        out.push_str(&element("id", &entry_id(event.id)));
+        out.push_str(&element("title", &entry_title(event)));
+        out.push_str(&format!(
+            "<link href=\"{}\"/>\n",
+            escape(&share::event_page_url(event.id))
+        ));
+        if let Some(ref link) = event.event_link {
+            out.push_str(&format!(
+                "<link rel=\"related\" href=\"{}\"/>\n",
+                escape(link)
+            ));
+        }
+        if let Some(approved_at) = event.approved_at {
+            out.push_str(&element("published", &atom_date(approved_at)));
+        }
+        out.push_str(&element("updated", &atom_date(event.updated_at)));
+        out.push_str(&format!(
+            "<author><name>{}</name></author>\n",
+            escape(&event.organizer)
+        ));
+        if let Some(ref country) = event.country {
+            out.push_str(&format!("<category term=\"{}\"/>\n", escape(country)));
+        }
+        out.push_str(&format!(
+            "<summary type=\"text\">{}</summary>\n",
+            escape(&summary(event))
+        ));
+        out.push_str("</entry>\n");
+    }
+    out.push_str("</feed>\n");
+    out
+}
+
+/// Latest change to any entry; the feed's `Last-Modified`
+pub fn last_modified(events: &[Event]) -> Option<DateTime<Utc>> {
+    events.iter().map(|e| e.updated_at).max()
+}
+
+/// Strong ETag of a rendered feed
+pub fn etag(body: &str) -> String {
+    let digest = hex::encode(Sha256::digest(body.as_bytes()));
+    format!("\"{}\"", &digest[..32])
+}
+
+/// Whether a conditional GET can be answered with 304 Not Modified.
+/// `If-None-Match` wins over `If-Modified-Since` (RFC 9110 section 13.2.2).
+pub fn not_modified(headers: &HeaderMap, etag: &str, last_modified: Option<DateTime<Utc>>) -> bool {
+    if let Some(value) = headers.get(header::IF_NONE_MATCH) {
+        let Ok(value) = value.to_str() else {
+            return false;
+        };
+        // Weak comparison: W/"x" matches "x"
+        return value
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == etag);
+    }
+    let since = headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok());
+    match (since, last_modified) {
+        // HTTP dates have whole seconds
+        (Some(since), Some(modified)) => modified.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+/// `Last-Modified` header value (IMF-fixdate)
+pub fn http_date(dt: DateTime<Utc>) -> String {
+    dt.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Stable entry id, independent of title and dates
+fn entry_id(event_id: i64) -> String {
+    format!("tag:{},{}:event-{}", UID_DOMAIN, TAG_YEAR, event_id)
+}
+
+fn entry_title(event: &Event) -> String {
+    match event.lifecycle {
+        EventLifecycle::Cancelled => format!("Cancelled: {}", event.title),
+        EventLifecycle::Postponed => format!("Postponed: {}", event.title),
+        _ => event.title.clone(),
+    }
+}
+
+/// Plain-text entry body: when and where, who, and the description
+fn summary(event: &Event) -> String {
+    let tz = event.tz();
+    let mut lines = vec![
+        share::when_and_where(event),
+        format!("Organized by {}", event.organizer),
+    ];
+    match (event.lifecycle, event.postponed_to) {
+        (EventLifecycle::Cancelled, _) => lines.push("This ride has been cancelled.".to_string()),
+        (EventLifecycle::Postponed, Some(date)) => lines.push(format!(
+            "Postponed to {}.",
+            date.with_timezone(&tz).format("%a %-d %b %Y, %H:%M %Z")
+        )),
+        (EventLifecycle::Postponed, None) => {
+            lines.push("Postponed, new date to be announced.".to_string())
+        }
+        _ => {}
+    }
+    if let Some(ref description) = event.description {
+        lines.push(String::new());
+        lines.push(description.clone());
+    }
+    lines.join("\n")
+}
+
+fn atom_date(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn element(name: &str, text: &str) -> String {
+    format!("<{0}>{1}</{0}>\n", name, escape(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sample_event;
+    use axum::http::HeaderValue;
+    use chrono::TimeZone;
+
+    const CHANNEL: Channel = Channel {
+        title: "DNB RIDE - New rides",
+        description: "Recently approved rides",
+        self_url: "http://localhost:3001/api/events/feed.atom",
+    };
+
+    #[test]
+    fn test_rss_item() {
+        let rss = rss(&CHANNEL, &[sample_event()]);
+        assert!(rss.contains("<guid isPermaLink=\"false\">tag:dnbride.events,2025:event-42</guid>"));
+        assert!(rss.contains("<pubDate>Sat, 3 Jan 2026 09:30:00 +0000</pubDate>"));
+        assert!(rss.contains("<lastBuildDate>Sun, 4 Jan 2026 12:00:00 +0000</lastBuildDate>"));
+        assert!(rss.contains("<category>Poland</category>"));
+        // Text is escaped, then escaped again as the HTML of the description
+        assert!(rss.contains(
+            "Sun 5 Apr 2026, 17:00 CEST - Warszawa, Poland&lt;br/&gt;Organized by NH Kolektyw"
+        ));
+        assert!(rss.contains("Bring lights &amp;amp; &amp;lt;bass&amp;gt;"));
+    }
+
+    #[test]
+    fn test_atom_entry() {
+        let cancelled = Event {
+            lifecycle: EventLifecycle::Cancelled,
+            ..sample_event()
+        };
+        let atom = atom(&CHANNEL, &[cancelled]);
+        assert!(atom.contains("<updated>2026-01-04T12:00:00Z</updated>"));
+        assert!(atom.contains("<published>2026-01-03T09:30:00Z</published>"));
+        assert!(atom.contains("<title>Cancelled: Warsaw DNB Przejazd</title>"));
+        assert!(atom.contains("<author><name>NH Kolektyw</name></author>"));
+        assert!(atom.contains("<link rel=\"related\" href=\"https://facebook.com/nhkolektyw\"/>"));
+        assert!(atom.contains("This ride has been cancelled."));
+        assert!(atom.contains("Bring lights &amp; &lt;bass&gt;"));
+    }
+
+    #[test]
+    fn test_empty_feeds_are_well_formed() {
+        assert!(atom(&CHANNEL, &[]).contains("<updated>1970-01-01T00:00:00Z</updated>"));
+        assert!(!rss(&CHANNEL, &[]).contains("<item>"));
+    }
+
+    #[test]
+    fn test_not_modified() {
+        let modified = Utc.with_ymd_and_hms(2026, 1, 4, 12, 0, 0).unwrap();
+        let tag = "\"abc\"";
+        let conditional = |name: header::HeaderName, value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, HeaderValue::from_str(value).unwrap());
+            not_modified(&headers, tag, Some(modified))
+        };
+
+        assert!(!not_modified(&HeaderMap::new(), tag, Some(modified)));
+        assert!(conditional(header::IF_NONE_MATCH, "\"abc\""));
+        assert!(conditional(header::IF_NONE_MATCH, "\"x\", W/\"abc\""));
+        assert!(!conditional(header::IF_NONE_MATCH, "\"x\""));
+        assert!(conditional(header::IF_MODIFIED_SINCE, &http_date(modified)));
+        assert!(!conditional(
+            header::IF_MODIFIED_SINCE,
+            "Sun, 04 Jan 2026 11:59:59 GMT"
+        ));
+
+        // A stale ETag means changed, whatever the date says
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("\"old\""));
+        headers.insert(
+            header::IF_MODIFIED_SINCE,
+            HeaderValue::from_str(&http_date(modified)).unwrap(),
+        );
+        assert!(!not_modified(&headers, tag, Some(modified)));
+    }
+}
@@ -0,0 +1,226 @@
+/// Mean Earth radius in km (same value the UI uses)
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Great-circle distance between two coordinates in km (Haversine formula)
+pub fn haversine_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().atan2((1.0 - a).sqrt())
+}
+
+/// Initial compass bearing from the first coordinate towards the second, in
+/// degrees clockwise from north
+pub fn bearing_deg(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lng = (lng2 - lng1).to_radians();
+    let y = d_lng.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * d_lng.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+/// Length of a path of `[longitude, latitude]` points in km
+pub fn path_length_km(points: &[[f64; 2]]) -> f64 {
+    points
+        .windows(2)
+        .map(|pair| haversine_km(pair[0][1], pair[0][0], pair[1][1], pair[1][0]))
+        .sum()
+}
+
+/// Simplify a path of `[longitude, latitude]` points with the Douglas–Peucker
+/// algorithm: every dropped point lies within `tolerance_m` metres of the
+/// simplified path. The first and last points are always kept.
+pub fn simplify(points: &[[f64; 2]], tolerance_m: f64) -> Vec<[f64; 2]> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Iterative, so long tracks can't overflow the stack
+    let mut spans = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = spans.pop() {
+        let farthest = (first + 1..last)
+            .map(|i| (i, offset_m(points[i], points[first], points[last])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, offset)) = farthest {
+            if offset > tolerance_m {
+                keep[i] = true;
+                spans.push((first, i));
+                spans.push((i, last));
+            }
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
+/// Distance in metres from `point` to the segment `start`-`end`, on a flat
+/// projection around `start` (accurate over the lengths of a ride's segments)
+fn offset_m(point: [f64; 2], start: [f64; 2], end: [f64; 2]) -> f64 {
+    let metres_per_degree = EARTH_RADIUS_KM * 1000.0 * std::f64::consts::PI / 180.0;
+    let cos_lat = start[1].to_radians().cos();
+    let project = |p: [f64; 2]| {
+        (
+            (p[0] - start[0]) * cos_lat * metres_per_degree,
+            (p[1] - start[1]) * metres_per_degree,
+        )
+    };
+    let (px, py) = project(point);
+    let (ex, ey) = project(end);
+    let length_sq = ex * ex + ey * ey;
+    let t = if length_sq == 0.0 {
+        0.0
+    } else {
+        ((px * ex + py * ey) / length_sq).clamp(0.0, 1.0)
+    };
+    (px - t * ex).hypot(py - t * ey)
+}
+
+/// Lat/lng rectangle used to prefilter rows via the coordinate index.
+/// `min_lng > max_lng` means the box crosses the antimeridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_lng: f64,
+    pub max_lat: f64,
+    pub max_lng: f64,
+}
+
+impl BoundingBox {
+    /// Smallest box that contains every point within `radius_km` of the center
+    pub fn around(lat: f64, lng: f64, radius_km: f64) -> Self {
+        let d_lat = (radius_km / EARTH_RADIUS_KM).to_degrees();
+        let min_lat = lat - d_lat;
+        let max_lat = lat + d_lat;
+
+        // Circle reaches a pole: every longitude is in range
+        if min_lat <= -90.0 || max_lat >= 90.0 {
+            return Self {
+                min_lat: min_lat.max(-90.0),
+                min_lng: -180.0,
+                max_lat: max_lat.min(90.0),
+                max_lng: 180.0,
+            };
+        }
+
+        let d_lng = (radius_km / (EARTH_RADIUS_KM * lat.to_radians().cos())).to_degrees();
+        if d_lng >= 180.0 {
+            return Self {
+                min_lat,
+                min_lng: -180.0,
+                max_lat,
+                max_lng: 180.0,
+            };
+        }
+
+        Self {
+            min_lat,
+            min_lng: wrap_lng(lng - d_lng),
+            max_lat,
+            max_lng: wrap_lng(lng + d_lng),
+        }
+    }
+
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lng > self.max_lng
+    }
+}
+
+/// Normalize a longitude into [-180, 180]
+fn wrap_lng(lng: f64) -> f64 {
+    if lng < -180.0 {
+        lng + 360.0
+    } else if lng > 180.0 {
+        lng - 360.0
+    } else {
+        lng
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl BoundingBox {
+        fn contains(&self, lat: f64, lng: f64) -> bool {
+            let lat_ok = lat >= self.min_lat && lat <= self.max_lat;
+            let lng_ok = if self.crosses_antimeridian() {
+                lng >= self.min_lng || lng <= self.max_lng
+            } else {
+                lng >= self.min_lng && lng <= self.max_lng
+            };
+            lat_ok && lng_ok
+        }
+    }
+
+    #[test]
+    fn test_haversine_known_distances() {
+        // London -> Manchester is roughly 262 km
+        let d = haversine_km(51.5074, -0.1278, 53.4808, -2.2426);
+        assert!((d - 262.0).abs() < 2.0, "got {d}");
+
+        assert_eq!(haversine_km(52.2297, 21.0122, 52.2297, 21.0122), 0.0);
+    }
+
+    #[test]
+    fn test_bearing_compass_points() {
+        let close = |a: f64, b: f64| (a - b).abs() < 0.5;
+        assert!(close(bearing_deg(52.0, 21.0, 52.1, 21.0), 0.0));
+        assert!(close(bearing_deg(52.0, 21.0, 52.0, 21.1), 90.0));
+        assert!(close(bearing_deg(52.0, 21.0, 51.9, 21.0), 180.0));
+        assert!(close(bearing_deg(52.0, 21.0, 52.0, 20.9), 270.0));
+    }
+
+    #[test]
+    fn test_simplify_keeps_corners_within_tolerance() {
+        // An L through Warsaw: ~1.1 km east with 2 m of GPS jitter, then north
+        let mut path: Vec<[f64; 2]> = (0..=10)
+            .map(|i| {
+                let jitter = if i % 2 == 0 { 0.00002 } else { -0.00002 };
+                [21.0 + i as f64 * 0.0015, 52.2 + jitter]
+            })
+            .collect();
+        path.extend((1..=10).map(|i| [21.015, 52.2 + i as f64 * 0.001]));
+
+        let simple = simplify(&path, 10.0);
+        assert_eq!(simple, [path[0], path[10], path[20]]);
+        // The simplified path is a little shorter, never longer
+        assert!(path_length_km(&simple) <= path_length_km(&path));
+        assert!((path_length_km(&simple) - 2.14).abs() < 0.05);
+
+        // A tighter tolerance keeps the jitter, but not the points along the straight leg
+        assert_eq!(simplify(&path, 1.0).len(), 12);
+        assert_eq!(simplify(&path[..2], 10.0), &path[..2]);
+    }
+
+    #[test]
+    fn test_bounding_box_contains_circle() {
+        let bbox = BoundingBox::around(52.2297, 21.0122, 50.0);
+        assert!(!bbox.crosses_antimeridian());
+        assert!(bbox.contains(52.2297, 21.0122));
+        // Points 49 km away in each direction stay inside the box
+        assert!(bbox.contains(52.2297 + 0.44, 21.0122));
+        assert!(bbox.contains(52.2297, 21.0122 + 0.72));
+        assert!(!bbox.contains(50.0647, 19.9450)); // Krakow
+    }
+
+    #[test]
+    fn test_bounding_box_antimeridian_and_poles() {
+        let fiji = BoundingBox::around(-17.7, 179.9, 100.0);
+        assert!(fiji.crosses_antimeridian());
+        assert!(fiji.contains(-17.7, -179.8));
+        assert!(fiji.contains(-17.7, 179.5));
+        assert!(!fiji.contains(-17.7, 0.0));
+
+        let arctic = BoundingBox::around(89.5, 0.0, 100.0);
+        assert_eq!(arctic.max_lat, 90.0);
+        assert_eq!((arctic.min_lng, arctic.max_lng), (-180.0, 180.0));
+    }
+}
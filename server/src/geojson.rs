@@ -0,0 +1,137 @@
+use serde_json::{json, Value};
+
+use crate::models::Event;
+
+/// Media type registered for GeoJSON (RFC 7946 section 12)
+pub const CONTENT_TYPE: &str = "application/geo+json";
+
+/// Render events as an RFC 7946 FeatureCollection of points.
+/// Every `Event` field is kept in `properties`.
+pub fn feature_collection(events: &[Event]) -> Value {
+    let features: Vec<Value> = events.iter().map(feature).collect();
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+fn feature(event: &Event) -> Value {
+    json!({
+        "type": "Feature",
+        "id": event.id,
+        // GeoJSON positions are [longitude, latitude]
+        "geometry": {
+            "type": "Point",
+            "coordinates": [event.longitude, event.latitude],
+        },
+        "properties": event,
+    })
+}
+
+/// Points of an uploaded route: a LineString or MultiLineString geometry, a
+/// Feature holding one, or the first Feature in a collection that does.
+/// MultiLineString parts are joined in order.
+pub fn line_coordinates(value: &Value) -> Result<Vec<[f64; 2]>, String> {
+    match value["type"].as_str() {
+        Some("FeatureCollection") => value["features"]
+            .as_array()
+            .and_then(|features| {
+                features.iter().find(|feature| {
+                    matches!(
+                        feature["geometry"]["type"].as_str(),
+                        Some("LineString" | "MultiLineString")
+                    )
+                })
+            })
+            .ok_or_else(|| "the collection has no LineString feature".to_string())
+            .and_then(line_coordinates),
+        Some("Feature") => line_coordinates(&value["geometry"]),
+        Some("LineString") => positions(&value["coordinates"]),
+        Some("MultiLineString") => {
+            let parts = value["coordinates"]
+                .as_array()
+                .ok_or_else(|| "MultiLineString coordinates must be an array".to_string())?;
+            let mut points = Vec::new();
+            for part in parts {
+                points.extend(positions(part)?);
+            }
+            Ok(points)
+        }
+        Some(other) => Err(format!("expected a LineString, not a {}", other)),
+        None => Err("not a GeoJSON object".to_string()),
+    }
+}
+
+/// `[longitude, latitude]` of each position; altitudes are dropped
+fn positions(value: &Value) -> Result<Vec<[f64; 2]>, String> {
+    let positions = value
+        .as_array()
+        .ok_or_else(|| "LineString coordinates must be an array".to_string())?;
+    positions
+        .iter()
+        .map(|position| match position.as_array().map(Vec::as_slice) {
+            Some([lng, lat, ..]) => match (lng.as_f64(), lat.as_f64()) {
+                (Some(lng), Some(lat)) => Ok([lng, lat]),
+                _ => Err(format!("invalid position {}", position)),
+            },
+            _ => Err(format!("invalid position {}", position)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sample_event;
+
+    #[test]
+    fn test_feature_collection_shape() {
+        let event = Event {
+            id: 7,
+            title: "Krakow Bass na Kolkach".to_string(),
+            latitude: 50.0647,
+            longitude: 19.945,
+            ..sample_event()
+        };
+
+        let collection = feature_collection(&[event]);
+        assert_eq!(collection["type"], "FeatureCollection");
+        let feature = &collection["features"][0];
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["id"], 7);
+        assert_eq!(feature["geometry"]["type"], "Point");
+        assert_eq!(feature["geometry"]["coordinates"], json!([19.945, 50.0647]));
+        assert_eq!(feature["properties"]["title"], "Krakow Bass na Kolkach");
+        assert_eq!(feature["properties"]["status"], "approved");
+    }
+
+    #[test]
+    fn test_line_coordinates() {
+        let line =
+            json!({ "type": "LineString", "coordinates": [[21.0, 52.2, 110.0], [21.1, 52.3]] });
+        assert_eq!(
+            line_coordinates(&line).unwrap(),
+            [[21.0, 52.2], [21.1, 52.3]]
+        );
+
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [
+                { "type": "Feature", "geometry": { "type": "Point", "coordinates": [21.0, 52.2] } },
+                {
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "MultiLineString",
+                        "coordinates": [[[21.0, 52.2], [21.1, 52.3]], [[21.2, 52.4]]]
+                    }
+                }
+            ]
+        });
+        assert_eq!(line_coordinates(&collection).unwrap().len(), 3);
+
+        let point = json!({ "type": "Point", "coordinates": [21.0, 52.2] });
+        assert!(line_coordinates(&point).is_err());
+        let broken = json!({ "type": "LineString", "coordinates": [[21.0], [21.1, 52.3]] });
+        assert!(line_coordinates(&broken).is_err());
+    }
+}
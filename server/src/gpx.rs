@@ -0,0 +1,152 @@
+use chrono::SecondsFormat;
+
+use crate::models::{Event, EventRoute};
+use crate::share::{self, escape};
+
+/// Media type for GPX documents
+pub const CONTENT_TYPE: &str = "application/gpx+xml";
+
+/// Points of a GPX document as `[longitude, latitude]`: its track points, or
+/// its route points when it has no track. Segments and tracks are joined in
+/// document order; waypoints are ignored.
+pub fn parse(xml: &str) -> Result<Vec<[f64; 2]>, String> {
+    let mut is_gpx = false;
+    let mut track = Vec::new();
+    let mut route = Vec::new();
+
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        // Comments and character data may contain anything
+        if let Some(after) = rest.strip_prefix("!--") {
+            rest = after.split_once("-->").map_or("", |(_, after)| after);
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix("![CDATA[") {
+            rest = after.split_once("]]>").map_or("", |(_, after)| after);
+            continue;
+        }
+        let end = rest.find('>').ok_or("unterminated tag")?;
+        let tag = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        // Namespace prefixes, e.g. `gpx:trkpt`, don't matter
+        match name.rsplit(':').next() {
+            Some("gpx") => is_gpx = true,
+            Some("trkpt") => track.push(point(tag)?),
+            Some("rtept") => route.push(point(tag)?),
+            _ => {}
+        }
+    }
+
+    if !is_gpx {
+        return Err("not a GPX document".to_string());
+    }
+    Ok(if track.is_empty() { route } else { track })
+}
+
+fn point(tag: &str) -> Result<[f64; 2], String> {
+    let coordinate = |name: &str| {
+        attribute(tag, name)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .ok_or_else(|| format!("point without a valid {}: <{}>", name, tag))
+    };
+    Ok([coordinate("lon")?, coordinate("lat")?])
+}
+
+/// Value of attribute `name` in the text of a start tag
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(at) = rest.find(name) {
+        let preceded_by_space = rest[..at].ends_with(char::is_whitespace);
+        rest = &rest[at + name.len()..];
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        return value[1..].split(quote).next();
+    }
+    None
+}
+
+/// GPX 1.1 document with the planned route of a ride as a single track, the
+/// form route planners such as Komoot and Strava import
+pub fn document(event: &Event, route: &EventRoute) -> String {
+    let title = escape(&event.title);
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(
+        "<gpx version=\"1.1\" creator=\"DNB RIDE\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n",
+    );
+    out.push_str("<metadata>\n");
+    out.push_str(&format!("<name>{}</name>\n", title));
+    out.push_str(&format!(
+        "<link href=\"{}\"><text>DNB RIDE</text></link>\n",
+        escape(&share::event_page_url(event.id))
+    ));
+    out.push_str(&format!(
+        "<time>{}</time>\n",
+        route.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+    ));
+    out.push_str("</metadata>\n");
+    out.push_str("<trk>\n");
+    out.push_str(&format!("<name>{}</name>\n", title));
+    out.push_str(&format!(
+        "<desc>{}</desc>\n",
+        escape(&share::when_and_where(event))
+    ));
+    out.push_str("<trkseg>\n");
+    for [lng, lat] in route.coordinates.iter() {
+        out.push_str(&format!("<trkpt lat=\"{}\" lon=\"{}\"/>\n", lat, lng));
+    }
+    out.push_str("</trkseg>\n</trk>\n</gpx>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tracks_before_routes() {
+        let xml = r#"<?xml version="1.0"?>
+            <gpx version="1.1" creator="Komoot" xmlns="http://www.topografix.com/GPX/1/1">
+              <!-- <trkpt lat="0" lon="0"/> -->
+              <wpt lat="1" lon="1"><name>Start</name></wpt>
+              <rte><rtept lat="9" lon="9"/></rte>
+              <trk><name><![CDATA[Ride <1>]]></name>
+                <trkseg>
+                  <trkpt lat="52.2297" lon="21.0122"><ele>100</ele></trkpt>
+                  <trkpt lon='21.02' lat='52.23'/>
+                </trkseg>
+                <trkseg><trkpt  lat = "52.24"  lon = "21.03" /></trkseg>
+              </trk>
+            </gpx>"#;
+        assert_eq!(
+            parse(xml).unwrap(),
+            [[21.0122, 52.2297], [21.02, 52.23], [21.03, 52.24]]
+        );
+
+        let route_only = r#"<gpx:gpx xmlns:gpx="http://www.topografix.com/GPX/1/1">
+            <gpx:rte><gpx:rtept lat="52.1" lon="21.1"/></gpx:rte></gpx:gpx>"#;
+        assert_eq!(parse(route_only).unwrap(), [[21.1, 52.1]]);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_documents() {
+        assert!(parse("<kml><Placemark/></kml>").is_err());
+        assert!(
+            parse(r#"<gpx><trk><trkseg><trkpt lat="north" lon="1"/></trkseg></trk></gpx>"#)
+                .is_err()
+        );
+        assert!(parse(r#"<gpx><trk><trkseg><trkpt lat="1"/></trkseg></trk></gpx>"#).is_err());
+    }
+}
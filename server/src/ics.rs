@@ -0,0 +1,323 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+
+use crate::models::{Event, EventLifecycle, OccurrenceOverride};
+use crate::recurrence::{self, Schedule};
+
+/// Product identifier written to every calendar
+const PRODID: &str = "-//DNB RIDE//Events//EN";
+
+/// Domain part of event UIDs; must never change or subscribers get duplicates
+pub const UID_DOMAIN: &str = "dnbride.events";
+
+/// Rides without an end time block out this long in calendars
+const DEFAULT_EVENT_DURATION_HOURS: i64 = 3;
+
+/// Maximum line length in octets, excluding the CRLF (RFC 5545 section 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Render events as an RFC 5545 VCALENDAR document. Recurring events carry
+/// their RRULE in local time (TZID) so clients keep the wall-clock time across
+/// DST changes (IANA TZIDs, which clients resolve without a VTIMEZONE);
+/// `overrides` become EXDATEs or RECURRENCE-ID instances.
+pub fn calendar(name: &str, events: &[Event], overrides: &[OccurrenceOverride]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+    for event in events {
+        lines.extend(vevents(event, overrides));
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Stable UID for an event, independent of its content
+pub fn event_uid(event_id: i64) -> String {
+    format!("event-{}@{}", event_id, UID_DOMAIN)
+}
+
+/// The master VEVENT plus one VEVENT per modified occurrence
+fn vevents(event: &Event, overrides: &[OccurrenceOverride]) -> Vec<String> {
+    let schedule = Schedule::of(event);
+    let Some(rrule) = schedule.rrule() else {
+        return vevent(event, None, Vec::new());
+    };
+    let tz = Some(schedule.tz);
+    // Overrides left behind by an edited rule no longer match an occurrence
+    let overrides: Vec<&OccurrenceOverride> = overrides
+        .iter()
+        .filter(|o| o.event_id == event.id && schedule.is_occurrence(o.occurrence_start))
+        .collect();
+
+    let mut recurrence = vec![format!("RRULE:{}", rrule)];
+    let exdates: Vec<String> = overrides
+        .iter()
+        .filter(|o| o.cancelled)
+        .map(|o| format_time(&o.occurrence_start, tz))
+        .collect();
+    if !exdates.is_empty() {
+        recurrence.push(format!("EXDATE{}:{}", tzid_param(tz), exdates.join(",")));
+    }
+
+    let mut lines = vevent(event, tz, recurrence);
+    for ov in overrides.into_iter().filter(|o| !o.cancelled) {
+        let occurrence = recurrence::occurrence(event, ov.occurrence_start, Some(ov));
+        lines.extend(vevent(
+            &occurrence.event,
+            tz,
+            vec![format!(
+                "RECURRENCE-ID{}:{}",
+                tzid_param(tz),
+                format_time(&ov.occurrence_start, tz)
+            )],
+        ));
+    }
+    lines
+}
+
+/// A VEVENT with `extra` recurrence properties after DTEND; dates are
+/// local to `tz` if given, UTC otherwise
+fn vevent(event: &Event, tz: Option<Tz>, extra: Vec<String>) -> Vec<String> {
+    let end = event
+        .end_date
+        .unwrap_or(event.event_date + Duration::hours(DEFAULT_EVENT_DURATION_HOURS));
+    let location = match event.country {
+        Some(ref country) => format!("{}, {}", event.location_name, country),
+        None => event.location_name.clone(),
+    };
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", event_uid(event.id)),
+        format!("DTSTAMP:{}", format_utc(&event.created_at)),
+        format!(
+            "DTSTART{}:{}",
+            tzid_param(tz),
+            format_time(&event.event_date, tz)
+        ),
+        format!("DTEND{}:{}", tzid_param(tz), format_time(&end, tz)),
+    ];
+    lines.extend(extra);
+    lines.extend([
+        format!("SUMMARY:{}", escape_text(&event.title)),
+        format!("LOCATION:{}", escape_text(&location)),
+        format!("GEO:{:.6};{:.6}", event.latitude, event.longitude),
+        format!("STATUS:{}", status(event.lifecycle)),
+    ]);
+    // Calendar apps rarely surface STATUS:TENTATIVE, so explain postponements in the text
+    let postponement =
+        (event.lifecycle == EventLifecycle::Postponed).then(|| match event.postponed_to {
+            Some(date) => format!(
+                "Postponed to {}",
+                date.with_timezone(&event.tz()).format("%Y-%m-%d %H:%M %Z")
+            ),
+            None => "Postponed, new date to be announced".to_string(),
+        });
+    let description = match (postponement, event.description.as_deref()) {
+        (Some(note), Some(description)) => Some(format!("{}\n\n{}", note, description)),
+        (Some(note), None) => Some(note),
+        (None, description) => description.map(str::to_string),
+    };
+    if let Some(description) = description {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+    }
+    if let Some(ref link) = event.event_link {
+        // URL is a URI value, not TEXT, so it is not escaped
+        lines.push(format!("URL:{}", link));
+    }
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+/// VEVENT STATUS for a lifecycle state
+fn status(lifecycle: EventLifecycle) -> &'static str {
+    match lifecycle {
+        EventLifecycle::Cancelled => "CANCELLED",
+        EventLifecycle::Postponed => "TENTATIVE",
+        EventLifecycle::Scheduled | EventLifecycle::Completed => "CONFIRMED",
+    }
+}
+
+/// UTC date-time in the basic format, e.g. 20260315T140000Z
+fn format_utc(dt: &DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Date-time as local time in `tz` (e.g. 20260315T150000), or UTC without one
+fn format_time(dt: &DateTime<Utc>, tz: Option<Tz>) -> String {
+    match tz {
+        Some(tz) => dt.with_timezone(&tz).format("%Y%m%dT%H%M%S").to_string(),
+        None => format_utc(dt),
+    }
+}
+
+/// `;TZID=...` parameter matching `format_time`
+fn tzid_param(tz: Option<Tz>) -> String {
+    tz.map(|tz| format!(";TZID={}", tz.name()))
+        .unwrap_or_default()
+}
+
+/// Escape a TEXT value (RFC 5545 section 3.3.11)
+pub fn escape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.replace("\r\n", "\n").chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' | '\r' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line at 75 octets without splitting UTF-8 sequences,
+/// terminating every physical line with CRLF
+pub fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space counts towards the continuation line
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{sample_event, EventLifecycle};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a,b;c\\d"), "a\\,b\\;c\\\\d");
+        assert_eq!(
+            escape_text("line1\r\nline2\nline3"),
+            "line1\\nline2\\nline3"
+        );
+    }
+
+    #[test]
+    fn test_fold_line_respects_octets_and_utf8() {
+        let line = format!("DESCRIPTION:{}", "ż".repeat(60));
+        let folded = fold_line(&line);
+        for physical in folded.split("\r\n").filter(|l| !l.is_empty()) {
+            assert!(
+                physical.len() <= MAX_LINE_OCTETS,
+                "{} octets",
+                physical.len()
+            );
+        }
+        // Unfolding restores the original line
+        assert_eq!(folded.trim_end_matches("\r\n").replace("\r\n ", ""), line);
+
+        assert_eq!(fold_line("SHORT:x"), "SHORT:x\r\n");
+    }
+
+    #[test]
+    fn test_calendar_contains_event_properties() {
+        let event = Event {
+            description: Some("Bring lights; helmets, water\nand bass".to_string()),
+            ..sample_event()
+        };
+        let ics = calendar("DNB RIDE", &[event], &[]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("UID:event-42@dnbride.events\r\n"));
+        assert!(ics.contains("DTSTART:20260405T150000Z\r\n"));
+        assert!(ics.contains("DTEND:20260405T180000Z\r\n"));
+        assert!(ics.contains("GEO:52.229700;21.012200\r\n"));
+        assert!(ics.contains("LOCATION:Warszawa\\, Poland\r\n"));
+        assert!(ics.contains("URL:https://facebook.com/nhkolektyw\r\n"));
+        assert!(ics.contains("DESCRIPTION:Bring lights\\; helmets\\, water\\nand bass\r\n"));
+        assert!(ics.contains("STATUS:CONFIRMED\r\n"));
+        assert!(!ics.contains("RRULE"));
+    }
+
+    #[test]
+    fn test_cancelled_and_postponed_status() {
+        let cancelled = Event {
+            lifecycle: EventLifecycle::Cancelled,
+            ..sample_event()
+        };
+        let ics = calendar("DNB RIDE", &[cancelled], &[]);
+        assert!(ics.contains("STATUS:CANCELLED\r\n"));
+
+        let postponed = Event {
+            lifecycle: EventLifecycle::Postponed,
+            postponed_to: Some(Utc.with_ymd_and_hms(2026, 4, 12, 15, 0, 0).unwrap()),
+            description: None,
+            ..sample_event()
+        };
+        let ics = calendar("DNB RIDE", &[postponed], &[]);
+        assert!(ics.contains("STATUS:TENTATIVE\r\n"));
+        assert!(ics.contains("DESCRIPTION:Postponed to 2026-04-12 17:00 CEST\r\n"));
+    }
+
+    #[test]
+    fn test_recurring_event_with_overrides() {
+        let event = Event {
+            end_date: Some(Utc.with_ymd_and_hms(2026, 4, 5, 17, 0, 0).unwrap()),
+            rrule: Some("FREQ=WEEKLY;COUNT=4".to_string()),
+            ..sample_event()
+        };
+        let overrides = vec![
+            OccurrenceOverride {
+                event_id: 42,
+                occurrence_start: Utc.with_ymd_and_hms(2026, 4, 12, 15, 0, 0).unwrap(),
+                cancelled: true,
+                start_date: None,
+                end_date: None,
+                title: None,
+                description: None,
+                location_name: None,
+                latitude: None,
+                longitude: None,
+                updated_at: Utc::now(),
+            },
+            OccurrenceOverride {
+                event_id: 42,
+                occurrence_start: Utc.with_ymd_and_hms(2026, 4, 19, 15, 0, 0).unwrap(),
+                cancelled: false,
+                start_date: Some(Utc.with_ymd_and_hms(2026, 4, 20, 15, 0, 0).unwrap()),
+                end_date: None,
+                title: Some("Rain date".to_string()),
+                description: None,
+                location_name: None,
+                latitude: None,
+                longitude: None,
+                updated_at: Utc::now(),
+            },
+        ];
+
+        let ics = calendar("DNB RIDE", &[event], &overrides);
+        // Series are written in local time (15:00 UTC is 17:00 in Warsaw)
+        assert!(ics.contains(
+            "DTSTART;TZID=Europe/Warsaw:20260405T170000\r\nDTEND;TZID=Europe/Warsaw:20260405T190000\r\nRRULE:FREQ=WEEKLY;COUNT=4\r\n"
+        ));
+        assert!(ics.contains("EXDATE;TZID=Europe/Warsaw:20260412T170000\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Europe/Warsaw:20260420T170000\r\n"));
+        assert!(ics.contains("RECURRENCE-ID;TZID=Europe/Warsaw:20260419T170000\r\n"));
+        assert!(ics.contains("SUMMARY:Rain date\r\n"));
+        assert_eq!(ics.matches("UID:event-42@dnbride.events").count(), 2);
+    }
+}
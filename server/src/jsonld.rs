@@ -0,0 +1,209 @@
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use serde_json::{json, Map, Value};
+
+use crate::models::{Event, EventLifecycle, Organizer};
+use crate::share;
+
+/// schema.org `Event` for a ride. `event` is the occurrence to describe (the
+/// next one for a recurring ride); `organizer` is its linked record, if any.
+pub fn event(event: &Event, organizer: Option<&Organizer>) -> Value {
+    let tz = event.tz();
+    let local = |dt: chrono::DateTime<chrono::Utc>| dt.with_timezone(&tz).fixed_offset();
+
+    let mut doc = Map::new();
+    doc.insert("@context".into(), json!("https://schema.org"));
+    doc.insert("@type".into(), json!("Event"));
+    doc.insert(
+        "@id".into(),
+        json!(format!("{}#event", share::event_page_url(event.id))),
+    );
+    doc.insert("name".into(), json!(event.title));
+    if let Some(ref description) = event.description {
+        doc.insert("description".into(), json!(description));
+    }
+    doc.insert("url".into(), json!(share::event_page_url(event.id)));
+    if let Some(ref link) = event.event_link {
+        doc.insert("sameAs".into(), json!(link));
+    }
+
+    // A postponement with a known date is a reschedule
+    let (status, start, previous) = match (event.lifecycle, event.postponed_to) {
+        (EventLifecycle::Cancelled, _) => ("EventCancelled", event.event_date, None),
+        (EventLifecycle::Postponed, Some(to)) => ("EventRescheduled", to, Some(event.event_date)),
+        (EventLifecycle::Postponed, None) => ("EventPostponed", event.event_date, None),
+        // schema.org has no "completed"; a past event simply stays scheduled
+        (EventLifecycle::Scheduled | EventLifecycle::Completed, _) => {
+            ("EventScheduled", event.event_date, None)
+        }
+    };
+    doc.insert("eventStatus".into(), json!(schema_url(status)));
+    doc.insert(
+        "eventAttendanceMode".into(),
+        json!(schema_url("OfflineEventAttendanceMode")),
+    );
+    doc.insert("startDate".into(), json!(date(local(start))));
+    if let Some(end) = event.end_date {
+        // Keep the duration when a reschedule moves the start
+        doc.insert(
+            "endDate".into(),
+            json!(date(local(end + (start - event.event_date)))),
+        );
+    }
+    if let Some(previous) = previous {
+        doc.insert("previousStartDate".into(), json!(date(local(previous))));
+    }
+
+    let mut address = json!({
+        "@type": "PostalAddress",
+        "addressLocality": event.location_name,
+    });
+    if let Some(ref country) = event.country {
+        address["addressCountry"] = json!(country);
+    }
+    doc.insert(
+        "location".into(),
+        json!({
+            "@type": "Place",
+            "name": event.location_name,
+            "address": address,
+            "geo": {
+                "@type": "GeoCoordinates",
+                "latitude": event.latitude,
+                "longitude": event.longitude,
+            },
+        }),
+    );
+
+    doc.insert(
+        "organizer".into(),
+        organization(&event.organizer, organizer),
+    );
+    if let Some(ref image) = event.image_url {
+        doc.insert("image".into(), json!([image]));
+    }
+    if let Some(ref video) = event.video_url {
+        doc.insert("recordedIn".into(), video_object(event, video));
+    }
+    Value::Object(doc)
+}
+
+fn organization(name: &str, organizer: Option<&Organizer>) -> Value {
+    let mut org = json!({ "@type": "Organization", "name": name });
+    let Some(organizer) = organizer else {
+        return org;
+    };
+    org["name"] = json!(organizer.name);
+    if let Some(ref website) = organizer.website {
+        org["url"] = json!(website);
+    }
+    if let Some(ref logo) = organizer.logo_url {
+        org["logo"] = json!(logo);
+    }
+    let profiles: Vec<&String> = [
+        &organizer.instagram_url,
+        &organizer.facebook_url,
+        &organizer.youtube_url,
+        &organizer.soundcloud_url,
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !profiles.is_empty() {
+        org["sameAs"] = json!(profiles);
+    }
+    org
+}
+
+fn video_object(event: &Event, url: &str) -> Value {
+    let mut video = json!({
+        "@type": "VideoObject",
+        "name": event.title,
+        "description": format!("Video of {}", event.title),
+        "contentUrl": url,
+    });
+    if let Some(id) = share::youtube_id(url) {
+        video["embedUrl"] = json!(format!("https://www.youtube.com/embed/{}", id));
+        video["thumbnailUrl"] = json!(share::youtube_thumbnail(id));
+    }
+    video
+}
+
+fn schema_url(name: &str) -> String {
+    format!("https://schema.org/{}", name)
+}
+
+/// ISO 8601 with the local offset, as search engines recommend for events
+fn date(dt: DateTime<FixedOffset>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sample_event;
+    use chrono::{TimeZone, Utc};
+
+    /// The sample ride with an end time and a recording
+    fn recorded_ride() -> Event {
+        Event {
+            end_date: Some(Utc.with_ymd_and_hms(2026, 4, 5, 18, 0, 0).unwrap()),
+            image_url: Some("https://example.com/ride.jpg".to_string()),
+            video_url: Some("https://www.youtube.com/watch?v=9k2CnY5rCzM".to_string()),
+            ..sample_event()
+        }
+    }
+
+    #[test]
+    fn test_event_document() {
+        let doc = event(&recorded_ride(), None);
+        assert_eq!(doc["@type"], "Event");
+        assert_eq!(doc["eventStatus"], "https://schema.org/EventScheduled");
+        assert_eq!(doc["startDate"], "2026-04-05T17:00:00+02:00");
+        assert_eq!(doc["endDate"], "2026-04-05T20:00:00+02:00");
+        assert_eq!(doc["location"]["geo"]["@type"], "GeoCoordinates");
+        assert_eq!(doc["location"]["geo"]["latitude"], 52.2297);
+        assert_eq!(doc["location"]["address"]["addressCountry"], "Poland");
+        assert_eq!(doc["organizer"]["@type"], "Organization");
+        assert_eq!(doc["organizer"]["name"], "NH Kolektyw");
+        assert_eq!(doc["recordedIn"]["@type"], "VideoObject");
+        assert_eq!(
+            doc["recordedIn"]["thumbnailUrl"],
+            "https://i.ytimg.com/vi/9k2CnY5rCzM/hqdefault.jpg"
+        );
+    }
+
+    #[test]
+    fn test_lifecycle_maps_to_event_status() {
+        let status = |lifecycle, postponed_to| {
+            let event = Event {
+                lifecycle,
+                postponed_to,
+                ..recorded_ride()
+            };
+            event_status(&super::event(&event, None))
+        };
+        assert_eq!(status(EventLifecycle::Cancelled, None), "EventCancelled");
+        assert_eq!(status(EventLifecycle::Postponed, None), "EventPostponed");
+        assert_eq!(status(EventLifecycle::Completed, None), "EventScheduled");
+
+        let to = Utc.with_ymd_and_hms(2026, 4, 12, 15, 0, 0).unwrap();
+        let rescheduled = Event {
+            lifecycle: EventLifecycle::Postponed,
+            postponed_to: Some(to),
+            ..recorded_ride()
+        };
+        let doc = super::event(&rescheduled, None);
+        assert_eq!(event_status(&doc), "EventRescheduled");
+        assert_eq!(doc["startDate"], "2026-04-12T17:00:00+02:00");
+        assert_eq!(doc["endDate"], "2026-04-12T20:00:00+02:00");
+        assert_eq!(doc["previousStartDate"], "2026-04-05T17:00:00+02:00");
+    }
+
+    fn event_status(doc: &Value) -> String {
+        doc["eventStatus"]
+            .as_str()
+            .unwrap()
+            .trim_start_matches("https://schema.org/")
+            .to_string()
+    }
+}
@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::auth;
+use crate::geo;
+use crate::models::{LiveFixRequest, LivePosition, LiveSession, LiveSnapshot};
+use crate::AppState;
+
+/// A session without fixes for this long ends by itself
+pub const IDLE_MINUTES: i64 = 15;
+
+/// Recent positions sent along with the current one
+pub const TRAIL_LEN: i64 = 100;
+
+/// How often the sweeper looks for idle sessions
+const SWEEP_INTERVAL_SECS: u64 = 60;
+
+/// Below this the bike is standing still and GPS jitter would make up a heading
+const MIN_HEADING_MOVE_KM: f64 = 0.005;
+
+/// Per-follower backlog; a follower further behind skips ahead
+const CHANNEL_CAPACITY: usize = 64;
+
+pub const SESSION_COLUMNS: &str = "id, event_id, started_at, last_fix_at, ended_at, end_reason";
+
+const POSITION_COLUMNS: &str =
+    "session_id, latitude, longitude, heading, speed, accuracy, recorded_at";
+
+/// What followers of an event are told
+#[derive(Debug)]
+pub enum LiveMessage {
+    Started(LiveSession),
+    Position(LivePosition),
+    Ended(LiveSession),
+}
+
+/// In-process hub behind the live position streams, with a channel per event
+/// that has followers
+#[derive(Default)]
+pub struct Tracker {
+    channels: Mutex<HashMap<i64, broadcast::Sender<Arc<LiveMessage>>>>,
+}
+
+impl Tracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tell the followers of event `event_id`, if any
+    pub fn publish(&self, event_id: i64, message: LiveMessage) {
+        let mut channels = self.channels.lock().unwrap();
+        if let Some(sender) = channels.get(&event_id) {
+            // Fails only when every follower has gone
+            if sender.send(Arc::new(message)).is_err() {
+                channels.remove(&event_id);
+            }
+        }
+    }
+
+    /// Follow event `event_id`. Subscribe before loading the snapshot so no
+    /// fix falls between the two.
+    pub fn subscribe(&self, event_id: i64) -> broadcast::Receiver<Arc<LiveMessage>> {
+        let mut channels = self.channels.lock().unwrap();
+        channels.retain(|_, sender| sender.receiver_count() > 0);
+        channels
+            .entry(event_id)
+            .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+            .subscribe()
+    }
+}
+
+/// SSE stream for a follower: the `snapshot` first (`null` before the first
+/// session), then `started`, `position` and `ended` as they happen
+pub fn stream(
+    receiver: broadcast::Receiver<Arc<LiveMessage>>,
+    snapshot: Option<LiveSnapshot>,
+) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+    let first = SseEvent::default()
+        .event("snapshot")
+        .json_data(&snapshot)
+        .ok();
+    // A follower that lagged behind just carries on from the newest position
+    let live = BroadcastStream::new(receiver).filter_map(|message| {
+        let message = message.ok()?;
+        let event = SseEvent::default();
+        match *message {
+            LiveMessage::Started(ref session) => event.event("started").json_data(session),
+            LiveMessage::Position(ref position) => event.event("position").json_data(position),
+            LiveMessage::Ended(ref session) => event.event("ended").json_data(session),
+        }
+        .ok()
+    });
+    let events = tokio_stream::iter(first).chain(live).map(Ok);
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+/// The running session of an event
+pub async fn active_session(
+    db: &SqlitePool,
+    event_id: i64,
+) -> Result<Option<LiveSession>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE event_id = ? AND ended_at IS NULL",
+        SESSION_COLUMNS
+    ))
+    .bind(event_id)
+    .fetch_optional(db)
+    .await
+}
+
+pub async fn fetch_session(
+    db: &SqlitePool,
+    session_id: i64,
+) -> Result<Option<LiveSession>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE id = ?",
+        SESSION_COLUMNS
+    ))
+    .bind(session_id)
+    .fetch_optional(db)
+    .await
+}
+
+/// The running session of an event, or else its latest one, with the trail
+pub async fn snapshot(db: &SqlitePool, event_id: i64) -> Result<Option<LiveSnapshot>, sqlx::Error> {
+    let session: Option<LiveSession> = sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE event_id = ? ORDER BY ended_at IS NULL DESC, id DESC LIMIT 1",
+        SESSION_COLUMNS
+    ))
+    .bind(event_id)
+    .fetch_optional(db)
+    .await?;
+    match session {
+        Some(session) => session_snapshot(db, session).await.map(Some),
+        None => Ok(None),
+    }
+}
+
+pub async fn session_snapshot(
+    db: &SqlitePool,
+    session: LiveSession,
+) -> Result<LiveSnapshot, sqlx::Error> {
+    let mut trail: Vec<LivePosition> = sqlx::query_as(&format!(
+        "SELECT {} FROM live_positions WHERE session_id = ? ORDER BY recorded_at DESC, id DESC LIMIT ?",
+        POSITION_COLUMNS
+    ))
+    .bind(session.id)
+    .bind(TRAIL_LEN)
+    .fetch_all(db)
+    .await?;
+    trail.reverse();
+    Ok(LiveSnapshot {
+        session,
+        position: trail.last().cloned(),
+        trail,
+    })
+}
+
+/// Every recorded position of a session, oldest first
+pub async fn track(db: &SqlitePool, session_id: i64) -> Result<Vec<LivePosition>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM live_positions WHERE session_id = ? ORDER BY recorded_at, id",
+        POSITION_COLUMNS
+    ))
+    .bind(session_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Length of a track in km
+pub fn distance_km(positions: &[LivePosition]) -> f64 {
+    positions
+        .windows(2)
+        .map(|pair| {
+            geo::haversine_km(
+                pair[0].latitude,
+                pair[0].longitude,
+                pair[1].latitude,
+                pair[1].longitude,
+            )
+        })
+        .sum()
+}
+
+/// Start a session for an event, returning it with the device token
+pub async fn start_session(
+    db: &SqlitePool,
+    event_id: i64,
+    started_by: &str,
+) -> Result<(LiveSession, String), sqlx::Error> {
+    let token = auth::generate_token();
+    let id = sqlx::query(
+        "INSERT INTO live_sessions (event_id, token_hash, started_by, started_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(event_id)
+    .bind(auth::hash_token(&token))
+    .bind(started_by)
+    .bind(Utc::now())
+    .execute(db)
+    .await?
+    .last_insert_rowid();
+    let session = fetch_session(db, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+    Ok((session, token))
+}
+
+/// The session whose device token this is
+pub async fn session_for_token(
+    db: &SqlitePool,
+    session_id: i64,
+    token: &str,
+) -> Result<Option<LiveSession>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE id = ? AND token_hash = ?",
+        SESSION_COLUMNS
+    ))
+    .bind(session_id)
+    .bind(auth::hash_token(token))
+    .fetch_optional(db)
+    .await
+}
+
+/// Store a fix. Without a heading from the device, it is taken from the
+/// previous fix once the bike has moved far enough.
+pub async fn record_fix(
+    db: &SqlitePool,
+    session: &LiveSession,
+    fix: &LiveFixRequest,
+    recorded_at: DateTime<Utc>,
+) -> Result<LivePosition, sqlx::Error> {
+    let heading = match fix.heading {
+        Some(heading) => Some(heading),
+        None => {
+            let previous: Option<LivePosition> = sqlx::query_as(&format!(
+                "SELECT {} FROM live_positions WHERE session_id = ? AND recorded_at <= ? ORDER BY recorded_at DESC, id DESC LIMIT 1",
+                POSITION_COLUMNS
+            ))
+            .bind(session.id)
+            .bind(recorded_at)
+            .fetch_optional(db)
+            .await?;
+            previous.and_then(|p| {
+                let moved = geo::haversine_km(p.latitude, p.longitude, fix.latitude, fix.longitude);
+                (moved >= MIN_HEADING_MOVE_KM)
+                    .then(|| geo::bearing_deg(p.latitude, p.longitude, fix.latitude, fix.longitude))
+            })
+        }
+    };
+
+    let now = Utc::now();
+    let mut tx = db.begin().await?;
+    sqlx::query(
+        r#"
+        INSERT INTO live_positions
+            (session_id, latitude, longitude, heading, speed, accuracy, recorded_at, received_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(session.id)
+    .bind(fix.latitude)
+    .bind(fix.longitude)
+    .bind(heading)
+    .bind(fix.speed)
+    .bind(fix.accuracy)
+    .bind(recorded_at)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("UPDATE live_sessions SET last_fix_at = ? WHERE id = ?")
+        .bind(now)
+        .bind(session.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(LivePosition {
+        session_id: session.id,
+        latitude: fix.latitude,
+        longitude: fix.longitude,
+        heading,
+        speed: fix.speed,
+        accuracy: fix.accuracy,
+        recorded_at,
+    })
+}
+
+/// End a running session; `None` if it had already ended
+pub async fn end_session(
+    db: &SqlitePool,
+    session_id: i64,
+    reason: &str,
+    at: DateTime<Utc>,
+) -> Result<Option<LiveSession>, sqlx::Error> {
+    let ended = sqlx::query(
+        "UPDATE live_sessions SET ended_at = ?, end_reason = ? WHERE id = ? AND ended_at IS NULL",
+    )
+    .bind(at)
+    .bind(reason)
+    .bind(session_id)
+    .execute(db)
+    .await?
+    .rows_affected();
+    if ended == 0 {
+        return Ok(None);
+    }
+    fetch_session(db, session_id).await
+}
+
+/// End the sessions that have gone quiet, as of their last activity, and
+/// tell their followers
+pub async fn end_idle_sessions(db: &SqlitePool, tracker: &Tracker) -> Result<usize, sqlx::Error> {
+    let active: Vec<LiveSession> = sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE ended_at IS NULL",
+        SESSION_COLUMNS
+    ))
+    .fetch_all(db)
+    .await?;
+
+    let cutoff = Utc::now() - Duration::minutes(IDLE_MINUTES);
+    let mut count = 0;
+    for session in active {
+        let last_activity = session.last_fix_at.unwrap_or(session.started_at);
+        if last_activity >= cutoff {
+            continue;
+        }
+        if let Some(ended) = end_session(db, session.id, "inactive", last_activity).await? {
+            tracing::info!("Live session {} ended after inactivity", ended.id);
+            tracker.publish(ended.event_id, LiveMessage::Ended(ended));
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Periodically end idle sessions, so followers hear of it even when nobody
+/// calls the API
+pub fn spawn_sweeper(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(SWEEP_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(e) = end_idle_sessions(&state.db, &state.live).await {
+                tracing::warn!("Could not end idle live sessions: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(latitude: f64, longitude: f64) -> LivePosition {
+        LivePosition {
+            session_id: 1,
+            latitude,
+            longitude,
+            heading: None,
+            speed: None,
+            accuracy: None,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_distance_along_track() {
+        assert_eq!(distance_km(&[]), 0.0);
+        assert_eq!(distance_km(&[position(52.0, 21.0)]), 0.0);
+        // Out and back along a meridian: 0.1 degree of latitude is ~11.1 km
+        let track = [
+            position(52.0, 21.0),
+            position(52.1, 21.0),
+            position(52.0, 21.0),
+        ];
+        assert!((distance_km(&track) - 22.24).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_publish_reaches_followers_only() {
+        let tracker = Tracker::new();
+        // Nobody follows yet: nothing is kept
+        tracker.publish(1, LiveMessage::Position(position(52.0, 21.0)));
+        assert!(tracker.channels.lock().unwrap().is_empty());
+
+        let mut follower = tracker.subscribe(1);
+        tracker.publish(2, LiveMessage::Position(position(50.0, 19.0)));
+        tracker.publish(1, LiveMessage::Position(position(52.1, 21.0)));
+        match *follower.try_recv().unwrap() {
+            LiveMessage::Position(ref p) => assert_eq!(p.latitude, 52.1),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert!(follower.try_recv().is_err());
+
+        // The channel goes once its last follower has
+        drop(follower);
+        tracker.publish(1, LiveMessage::Position(position(52.2, 21.0)));
+        assert!(tracker.channels.lock().unwrap().is_empty());
+    }
+}
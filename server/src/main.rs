@@ -1,8 +1,25 @@
+mod audit;
+mod auth;
 mod db;
+mod dedup;
+mod error;
+mod feed;
+mod geo;
+mod geojson;
+mod gpx;
+mod ics;
+mod jsonld;
+mod live;
 mod models;
+mod openapi;
+mod organizers;
+mod recurrence;
 mod routes;
+mod search;
+mod share;
+mod timezone;
+mod updates;
 
-use axum::{routing::get, Router};
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
 use tower_http::cors::CorsLayer;
@@ -10,6 +27,10 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 pub struct AppState {
     pub db: sqlx::SqlitePool,
+    /// Event changes for the live streams
+    pub updates: updates::Updates,
+    /// Followers of live ride tracking
+    pub live: live::Tracker,
 }
 
 #[tokio::main]
@@ -17,50 +38,46 @@ async fn main() -> anyhow::Result<()> {
     // Load .env file (if it exists)
     dotenvy::dotenv().ok();
 
-    // Validate required environment variables
-    if std::env::var("ADMIN_API_KEY").is_err() {
-        eprintln!("ERROR: ADMIN_API_KEY environment variable is required");
-        eprintln!("Please set it in your .env file or environment");
-        std::process::exit(1);
-    }
-
     // Initialize logging
     tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer())
         .with(tracing_subscriber::EnvFilter::new("info"))
         .init();
 
-    // Initialize database
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "sqlite:dnb_events.db?mode=rwc".to_string());
+    let pool = connect_database().await?;
 
-    let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
-        .await?;
+    // CLI: `dnb-ride-api create-admin <username>` bootstraps an admin account
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("create-admin") => return create_admin_command(&pool, args.get(2)).await,
+        // `dnb-ride-api link-organizers` links existing events to organizer records
+        Some("link-organizers") => return link_organizers_command(&pool).await,
+        _ => {}
+    }
 
-    // Run migrations
-    sqlx::migrate!().run(&pool).await?;
+    // The shared key is now only a break-glass fallback for admin logins
+    if std::env::var("ADMIN_API_KEY").map_or(true, |k| k.is_empty()) {
+        tracing::warn!("ADMIN_API_KEY is not set - break-glass admin access is disabled");
+    }
 
     db::seed_sample_data(&pool).await?;
+    let backfilled = db::backfill_timezones(&pool).await?;
+    if backfilled > 0 {
+        tracing::info!("Derived timezones for {} existing events", backfilled);
+    }
 
-    let state = Arc::new(AppState { db: pool });
-
-    // CORS - configurable via env var, defaults to localhost for dev
-    let allowed_origin =
-        std::env::var("ALLOWED_ORIGIN").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    let state = Arc::new(AppState {
+        db: pool,
+        updates: updates::Updates::new(),
+        live: live::Tracker::new(),
+    });
+    live::spawn_sweeper(state.clone());
 
+    // CORS - feeds, GeoJSON and the event stream are read from other sites
     let cors = CorsLayer::permissive();
 
     // Routes
-    let app = Router::new()
-        .route("/api/health", get(|| async { "OK" }))
-        .nest("/api/events", routes::events_router())
-        .nest("/api/organizers", routes::organizers_router())
-        .nest("/api/admin", routes::admin_router())
-        .nest("/api/suggestions", routes::suggestions_router())
-        .layer(cors)
-        .with_state(state);
+    let app = routes::api_router().layer(cors).with_state(state);
 
     // Port - configurable via env var, defaults to 3001 to avoid conflict with Next.js
     let port = std::env::var("PORT").unwrap_or_else(|_| "3001".to_string());
@@ -72,3 +89,67 @@ async fn main() -> anyhow::Result<()> {
     axum::serve(listener, app).await?;
     Ok(())
 }
+
+/// Connect to the database and run migrations
+async fn connect_database() -> anyhow::Result<sqlx::SqlitePool> {
+    let database_url = std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "sqlite:dnb_events.db?mode=rwc".to_string());
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect(&database_url)
+        .await?;
+
+    // Run migrations
+    sqlx::migrate!().run(&pool).await?;
+
+    Ok(pool)
+}
+
+/// Create an admin account. The password is read from `ADMIN_PASSWORD`
+/// or, if unset, from the first line of stdin.
+async fn create_admin_command(
+    pool: &sqlx::SqlitePool,
+    username: Option<&String>,
+) -> anyhow::Result<()> {
+    let username = username
+        .filter(|u| !u.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("usage: dnb-ride-api create-admin <username>"))?;
+
+    let password = match std::env::var("ADMIN_PASSWORD") {
+        Ok(password) => password,
+        Err(_) => {
+            eprintln!("Password for {} (read from stdin):", username);
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line)?;
+            line.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    // Same rules as accounts made through the API
+    if let Err(error) = auth::check_password(&password) {
+        let problems: Vec<String> = error
+            .body()
+            .fields
+            .into_values()
+            .flatten()
+            .map(|problem| problem.message)
+            .collect();
+        anyhow::bail!("password {}", problems.join(", "));
+    }
+
+    // Accounts made from the command line are owners; other roles are assigned via the API
+    let id = auth::create_admin(pool, username, &password, models::Role::Owner).await?;
+    println!("Created admin {} (id {})", username, id);
+    Ok(())
+}
+
+/// Match every unlinked event against the organizers and report the outcome
+async fn link_organizers_command(pool: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    let (summary, _) = organizers::backfill(&mut *pool.acquire().await?).await?;
+    println!(
+        "Linked {} events; {} ambiguous (left for moderators), {} unmatched",
+        summary.linked, summary.ambiguous, summary.unmatched
+    );
+    Ok(())
+}
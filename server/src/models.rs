@@ -1,22 +1,21 @@
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
-use validator::Validate;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use sqlx::{types::Json, FromRow};
+use validator::{Validate, ValidateUrl, ValidationError};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+use crate::timezone;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum EventStatus {
+    #[default]
     Pending,
     Approved,
     Rejected,
 }
 
-impl Default for EventStatus {
-    fn default() -> Self {
-        Self::Pending
-    }
-}
-
 impl From<String> for EventStatus {
     fn from(s: String) -> Self {
         match s.to_lowercase().as_str() {
@@ -27,18 +26,66 @@ impl From<String> for EventStatus {
     }
 }
 
-impl ToString for EventStatus {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Pending => "pending".to_string(),
-            Self::Approved => "approved".to_string(),
-            Self::Rejected => "rejected".to_string(),
+            Self::Pending => write!(f, "pending"),
+            Self::Approved => write!(f, "approved"),
+            Self::Rejected => write!(f, "rejected"),
         }
     }
 }
 
+/// Whether a ride is still going ahead, independent of moderation
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EventLifecycle {
+    #[default]
+    Scheduled,
+    Cancelled,
+    Postponed,
+    Completed,
+}
+
+impl From<String> for EventLifecycle {
+    fn from(s: String) -> Self {
+        match s.to_lowercase().as_str() {
+            "cancelled" => Self::Cancelled,
+            "postponed" => Self::Postponed,
+            "completed" => Self::Completed,
+            _ => Self::Scheduled,
+        }
+    }
+}
+
+impl std::fmt::Display for EventLifecycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scheduled => write!(f, "scheduled"),
+            Self::Cancelled => write!(f, "cancelled"),
+            Self::Postponed => write!(f, "postponed"),
+            Self::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl EventLifecycle {
+    /// Allowed admin transitions. Postponed rides may be postponed again to move
+    /// the new date; cancelled and completed rides can be reinstated if set by mistake.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use EventLifecycle::*;
+        matches!(
+            (self, next),
+            (Scheduled, Cancelled | Postponed | Completed)
+                | (Postponed, Scheduled | Cancelled | Postponed | Completed)
+                | (Cancelled, Scheduled)
+                | (Completed, Scheduled)
+        )
+    }
+}
+
 /// Event model representing a DNB On Bike ride
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, JsonSchema)]
 pub struct Event {
     pub id: i64,
     pub title: String,
@@ -51,6 +98,10 @@ pub struct Event {
     pub longitude: f64,
     #[sqlx(rename = "event_date")]
     pub event_date: DateTime<Utc>,
+    /// End of a multi-day or timed event (of the first occurrence for recurring events)
+    pub end_date: Option<DateTime<Utc>>,
+    /// RFC 5545 recurrence rule, e.g. `FREQ=WEEKLY;BYDAY=TH`
+    pub rrule: Option<String>,
     pub image_url: Option<String>,
     pub video_url: Option<String>,  // YouTube URL for past events
     pub event_link: Option<String>, // External link to event (FB, RA, etc.)
@@ -58,45 +109,274 @@ pub struct Event {
     pub status: EventStatus,
     #[sqlx(rename = "status")]
     pub status_str: String,
+    #[sqlx(skip)]
+    pub lifecycle: EventLifecycle,
+    #[sqlx(rename = "lifecycle")]
+    pub lifecycle_str: String,
+    /// New date of a postponed ride, if already known
+    pub postponed_to: Option<DateTime<Utc>>,
+    /// IANA timezone of the ride, derived from its coordinates unless given
+    pub timezone: Option<String>,
+    /// Start as local wall-clock time with its UTC offset
+    #[sqlx(skip)]
+    pub local_start: Option<DateTime<FixedOffset>>,
+    #[sqlx(skip)]
+    pub local_end: Option<DateTime<FixedOffset>>,
+    /// UTC offset at the start, e.g. `+02:00`
+    #[sqlx(skip)]
+    pub utc_offset: Option<String>,
     #[sqlx(rename = "created_at")]
     pub created_at: DateTime<Utc>,
+    /// When the event was approved (`None` until it first is)
+    pub approved_at: Option<DateTime<Utc>>,
+    /// Last change to the event's details, moderation status or lifecycle
+    pub updated_at: DateTime<Utc>,
 }
 
 impl Event {
-    /// Convert status_str to proper EventStatus after loading from DB
+    /// Convert status_str and lifecycle_str to their enums and fill in
+    /// the local times after loading from DB
     pub fn with_parsed_status(mut self) -> Self {
         self.status = EventStatus::from(self.status_str.clone());
+        self.lifecycle = EventLifecycle::from(self.lifecycle_str.clone());
+        self.localize();
         self
     }
+
+    /// Stored timezone, or one derived from the coordinates for rows without it
+    pub fn tz(&self) -> Tz {
+        self.timezone
+            .as_deref()
+            .and_then(timezone::parse)
+            .unwrap_or_else(|| timezone::resolve(self.latitude, self.longitude))
+    }
+
+    /// Recompute `local_start`, `local_end` and `utc_offset` from the UTC dates
+    pub fn localize(&mut self) {
+        let tz = self.tz();
+        let to_local = |dt: DateTime<Utc>| dt.with_timezone(&tz).fixed_offset();
+        let local_start = to_local(self.event_date);
+        self.utc_offset = Some(local_start.format("%:z").to_string());
+        self.local_start = Some(local_start);
+        self.local_end = self.end_date.map(to_local);
+        self.timezone = Some(tz.name().to_string());
+    }
+}
+
+/// An approved Warsaw ride for unit tests; override the fields a test is about
+#[cfg(test)]
+pub fn sample_event() -> Event {
+    Event {
+        id: 42,
+        title: "Warsaw DNB Przejazd".to_string(),
+        description: Some("Bring lights & <bass>".to_string()),
+        organizer: "NH Kolektyw".to_string(),
+        organizer_id: Some(2),
+        location_name: "Warszawa".to_string(),
+        country: Some("Poland".to_string()),
+        latitude: 52.2297,
+        longitude: 21.0122,
+        event_date: Utc.with_ymd_and_hms(2026, 4, 5, 15, 0, 0).unwrap(),
+        end_date: None,
+        rrule: None,
+        image_url: None,
+        video_url: None,
+        event_link: Some("https://facebook.com/nhkolektyw".to_string()),
+        status: EventStatus::Approved,
+        status_str: "approved".to_string(),
+        lifecycle: EventLifecycle::Scheduled,
+        lifecycle_str: "scheduled".to_string(),
+        postponed_to: None,
+        timezone: Some("Europe/Warsaw".to_string()),
+        local_start: None,
+        local_end: None,
+        utc_offset: None,
+        created_at: Utc.with_ymd_and_hms(2026, 1, 2, 10, 0, 0).unwrap(),
+        approved_at: Some(Utc.with_ymd_and_hms(2026, 1, 3, 9, 30, 0).unwrap()),
+        updated_at: Utc.with_ymd_and_hms(2026, 1, 4, 12, 0, 0).unwrap(),
+    }
+}
+
+/// A date-time in a request: an instant with an offset (`2026-04-05T15:00:00Z`),
+/// or a local wall-clock time (`2026-04-05T17:00:00`) read in the event's timezone
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum EventTime {
+    Utc(DateTime<Utc>),
+    Local(NaiveDateTime),
+}
+
+impl EventTime {
+    /// The UTC instant; local times skipped by a DST change are an error,
+    /// repeated ones take the earlier instant
+    pub fn resolve(self, tz: Tz) -> Result<DateTime<Utc>, String> {
+        match self {
+            Self::Utc(dt) => Ok(dt),
+            Self::Local(local) => tz
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok_or_else(|| format!("{} does not exist in {}", local, tz.name())),
+        }
+    }
 }
 
 /// Organizer model representing a DNB On Bike event organizer
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, JsonSchema)]
 pub struct Organizer {
     pub id: i64,
     pub name: String,
     pub slug: String,
     pub description: Option<String>,
     pub website: Option<String>,
+    pub logo_url: Option<String>,
+    /// Home city and country
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub instagram_url: Option<String>,
+    pub facebook_url: Option<String>,
+    pub youtube_url: Option<String>,
+    pub soundcloud_url: Option<String>,
+    pub contact_email: Option<String>,
     #[sqlx(rename = "created_at")]
     pub created_at: DateTime<Utc>,
 }
 
+/// Ride statistics of an organizer, over approved events that were not cancelled
+#[derive(Debug, Clone, Default, Serialize, FromRow, JsonSchema)]
+pub struct OrganizerStats {
+    pub total_rides: i64,
+    /// Rides that have not ended yet (recurring series count until their last occurrence)
+    pub upcoming_rides: i64,
+    #[sqlx(skip)]
+    pub countries_visited: Vec<String>,
+    pub first_ride: Option<DateTime<Utc>>,
+    pub last_ride: Option<DateTime<Utc>>,
+    pub rides_with_video: i64,
+}
+
+/// Response for GET /api/organizers/:slug
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OrganizerProfile {
+    #[serde(flatten)]
+    pub organizer: Organizer,
+    pub stats: OrganizerStats,
+}
+
 /// Response for organizers list
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct OrganizersResponse {
     pub organizers: Vec<Organizer>,
     pub total: usize,
 }
 
+/// Request body for POST /api/admin/organizers
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, JsonSchema)]
+pub struct CreateOrganizerRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    /// Generated from the name if absent
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    #[validate(url)]
+    pub website: Option<String>,
+    #[validate(url)]
+    pub logo_url: Option<String>,
+    #[validate(length(max = 100))]
+    pub city: Option<String>,
+    #[validate(length(max = 100))]
+    pub country: Option<String>,
+    #[validate(url)]
+    pub instagram_url: Option<String>,
+    #[validate(url)]
+    pub facebook_url: Option<String>,
+    #[validate(url)]
+    pub youtube_url: Option<String>,
+    #[validate(url)]
+    pub soundcloud_url: Option<String>,
+    #[validate(email)]
+    pub contact_email: Option<String>,
+}
+
+/// Request body for PUT /api/admin/organizers/:id (empty strings clear optional fields)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, JsonSchema)]
+pub struct UpdateOrganizerRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    #[validate(length(max = 100))]
+    pub city: Option<String>,
+    #[validate(length(max = 100))]
+    pub country: Option<String>,
+    pub instagram_url: Option<String>,
+    pub facebook_url: Option<String>,
+    pub youtube_url: Option<String>,
+    pub soundcloud_url: Option<String>,
+    pub contact_email: Option<String>,
+}
+
+/// Request body for POST /api/admin/organizers/:id/merge
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct MergeOrganizerRequest {
+    /// ID of the surviving organizer
+    pub into: i64,
+    pub reason: Option<String>,
+}
+
+/// Query parameters for DELETE /api/admin/organizers/:id
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct DeleteOrganizerQuery {
+    /// Free-text reason kept in the audit log
+    pub reason: Option<String>,
+}
+
+/// Alternative spelling of an organizer name used for auto-linking
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct OrganizerAlias {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub alias: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request body for POST /api/admin/organizers/:id/aliases
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema)]
+pub struct CreateAliasRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub alias: String,
+}
+
+/// Response for GET /api/admin/organizers/:id/aliases
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AliasesResponse {
+    pub aliases: Vec<OrganizerAlias>,
+    pub total: usize,
+}
+
+/// Result of POST /api/admin/organizers/backfill
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct OrganizerBackfillResponse {
+    pub linked: usize,
+    /// Events left unlinked with suggestions for moderators
+    pub ambiguous: usize,
+    pub unmatched: usize,
+}
+
 /// Request body for creating a new event
-#[derive(Debug, Deserialize, Validate, Clone)]
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
 pub struct CreateEventRequest {
     #[validate(length(min = 3))]
     pub title: String,
     pub description: Option<String>,
+    /// Filled in from the organizer record for organizer accounts
+    #[serde(default)]
     #[validate(length(min = 1))]
     pub organizer: String,
+    /// Organizer to submit for; requires an organizer account managing it
+    pub organizer_id: Option<i64>,
     #[validate(length(min = 1))]
     pub location_name: String,
     pub country: Option<String>,
@@ -104,47 +384,794 @@ pub struct CreateEventRequest {
     pub latitude: f64,
     #[validate(range(min = -180.0, max = 180.0))]
     pub longitude: f64,
-    pub event_date: DateTime<Utc>,
-    #[validate(url)]
+    pub event_date: EventTime,
+    pub end_date: Option<EventTime>,
+    /// IANA name; derived from the coordinates if absent
+    pub timezone: Option<String>,
+    pub rrule: Option<String>,
+    /// Occurrences of the recurrence rule to skip (RFC 5545 EXDATE)
+    #[serde(default)]
+    pub exdates: Vec<EventTime>,
+    #[schemars(url)]
+    #[validate(custom(function = "web_link"))]
     pub image_url: Option<String>,
-    #[validate(url)]
+    #[schemars(url)]
+    #[validate(custom(function = "web_link"))]
     pub video_url: Option<String>,
-    #[validate(url)]
+    #[schemars(url)]
+    #[validate(custom(function = "web_link"))]
     pub event_link: Option<String>,
 }
 
 /// Response for event list with metadata
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct EventsResponse {
     pub events: Vec<Event>,
+    /// Number of matching events before pagination
+    pub total: usize,
+    /// Opaque cursor for the next page, `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Time bucket for event list filtering
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFilter {
+    #[default]
+    All,
+    Upcoming,
+    Past,
+}
+
+/// Sort direction on `event_date`
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// Maximum page size for event list endpoints
+pub const MAX_PAGE_SIZE: i64 = 500;
+
+/// Query parameters for filtering and paginating event lists
+#[derive(Debug, Clone, Default, Deserialize, Validate, JsonSchema)]
+pub struct EventFilters {
+    pub country: Option<String>,
+    /// Organizer slug
+    pub organizer: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub time: TimeFilter,
+    pub lifecycle: Option<EventLifecycle>,
+    // schemars reads `validate` too, but only takes the constant by name
+    #[schemars(range(min = 1, max = "MAX_PAGE_SIZE"))]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+    /// Cursor returned as `next_cursor` by a previous page (takes precedence over `offset`)
+    pub cursor: Option<String>,
+    pub sort: Option<SortOrder>,
+}
+
+impl EventFilters {
+    /// Resolve the starting row from `cursor` or `offset`.
+    /// Returns `None` if the cursor is malformed.
+    pub fn start_offset(&self) -> Option<i64> {
+        match self.cursor {
+            Some(ref cursor) => cursor.parse::<i64>().ok().filter(|o| *o >= 0),
+            None => Some(self.offset.unwrap_or(0)),
+        }
+    }
+
+    /// Cursor pointing at the page after the current one, if any rows remain
+    pub fn next_cursor(&self, offset: i64, returned: usize, total: i64) -> Option<String> {
+        let next = offset + returned as i64;
+        (self.limit.is_some() && returned > 0 && next < total).then(|| next.to_string())
+    }
+}
+
+/// A single occurrence of an event. One-off events have exactly one;
+/// `event_date`/`end_date` and overridden fields reflect this occurrence.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct EventOccurrence {
+    #[serde(flatten)]
+    pub event: Event,
+    /// Start of the occurrence as generated by the rule (RFC 5545 RECURRENCE-ID)
+    pub occurrence_start: DateTime<Utc>,
+    /// This occurrence, or the whole event, is cancelled
+    pub cancelled: bool,
+    /// True if an admin changed this occurrence
+    pub overridden: bool,
+}
+
+/// Response for occurrence lists
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OccurrencesResponse {
+    pub events: Vec<EventOccurrence>,
+    /// Number of matching occurrences before pagination
+    pub total: usize,
+    pub next_cursor: Option<String>,
+}
+
+/// Query parameters for GET /api/events/:id/occurrences
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct OccurrenceWindow {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Cancelled or modified occurrence of a recurring event
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct OccurrenceOverride {
+    pub event_id: i64,
+    pub occurrence_start: DateTime<Utc>,
+    pub cancelled: bool,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub location_name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request body for cancelling or modifying one occurrence (replaces any previous override)
+#[derive(Debug, Clone, Default, Deserialize, Validate, JsonSchema)]
+pub struct OccurrenceOverrideRequest {
+    #[serde(default)]
+    pub cancelled: bool,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[validate(length(min = 3))]
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[validate(length(min = 1))]
+    pub location_name: Option<String>,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
+}
+
+/// Query parameters for GET /api/events/within (map viewport)
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema)]
+pub struct BoundingBoxQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub min_lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub min_lng: f64,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub max_lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub max_lng: f64,
+    #[serde(default)]
+    pub time: TimeFilter,
+    #[schemars(range(min = 1, max = "MAX_PAGE_SIZE"))]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE))]
+    pub limit: Option<i64>,
+}
+
+/// Default search radius for GET /api/events/nearby
+pub const DEFAULT_RADIUS_KM: f64 = 50.0;
+
+/// Query parameters for GET /api/events/nearby
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema)]
+pub struct NearbyQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lng: f64,
+    #[validate(range(min = 0.0, max = 20000.0))]
+    pub radius_km: Option<f64>,
+    #[serde(default)]
+    pub time: TimeFilter,
+    #[schemars(range(min = 1, max = "MAX_PAGE_SIZE"))]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE))]
+    pub limit: Option<i64>,
+}
+
+/// Event with its distance from the query point
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventWithDistance {
+    #[serde(flatten)]
+    pub event: Event,
+    pub distance_km: f64,
+}
+
+/// Planned route of a ride, simplified for display
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct EventRoute {
+    pub event_id: i64,
+    /// `[longitude, latitude]` pairs, as in GeoJSON
+    #[sqlx(rename = "coordinates_json")]
+    #[schemars(with = "Vec<[f64; 2]>")]
+    pub coordinates: Json<Vec<[f64; 2]>>,
+    /// Length of the uploaded track, measured before simplification
+    pub distance_km: f64,
+    /// `[min_lng, min_lat, max_lng, max_lat]`, as a GeoJSON bbox
+    #[sqlx(skip)]
+    pub bbox: [f64; 4],
+    /// Points in the uploaded track
+    pub source_points: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventRoute {
+    /// Fill in the bounding box from the coordinates
+    pub fn with_bbox(mut self) -> Self {
+        let mut bbox = [f64::MAX, f64::MAX, f64::MIN, f64::MIN];
+        for [lng, lat] in self.coordinates.iter() {
+            bbox = [
+                bbox[0].min(*lng),
+                bbox[1].min(*lat),
+                bbox[2].max(*lng),
+                bbox[3].max(*lat),
+            ];
+        }
+        if !self.coordinates.is_empty() {
+            self.bbox = bbox;
+        }
+        self
+    }
+}
+
+/// Response for GET /api/events/:id: the event with its planned route, if any
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventDetail {
+    #[serde(flatten)]
+    pub event: Event,
+    pub route: Option<EventRoute>,
+}
+
+/// Response for radius queries, sorted by distance
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NearbyEventsResponse {
+    pub events: Vec<EventWithDistance>,
+    pub total: usize,
+}
+
+/// Default number of results per section for GET /api/search
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+/// Query parameters for GET /api/search
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema)]
+pub struct SearchQuery {
+    #[validate(length(min = 1, max = 200))]
+    pub q: String,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+}
+
+/// Approved event matching a search, best matches first
+#[derive(Debug, Serialize, FromRow, JsonSchema)]
+pub struct EventSearchHit {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub event: Event,
+    /// Relevance (negated bm25); higher is better
+    pub rank: f64,
+    /// HTML-escaped excerpt with matches wrapped in `<mark>`
+    pub snippet: String,
+}
+
+/// Organizer matching a search, best matches first
+#[derive(Debug, Serialize, FromRow, JsonSchema)]
+pub struct OrganizerSearchHit {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub organizer: Organizer,
+    pub rank: f64,
+    pub snippet: String,
+}
+
+/// Response for GET /api/search
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SearchResponse {
+    pub events: Vec<EventSearchHit>,
+    pub organizers: Vec<OrganizerSearchHit>,
+}
+
+/// Response for POST /api/events: the created event plus its secret edit token
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CreatedEventResponse {
+    #[serde(flatten)]
+    pub event: Event,
+    /// Returned only once; send it as `X-Edit-Token` to /api/submissions/:id
+    pub edit_token: String,
+}
+
+/// Submitter-proposed change to an approved event, waiting for review
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct EventRevision {
+    pub id: i64,
+    pub event_id: i64,
+    #[sqlx(rename = "changes_json")]
+    #[schemars(with = "UpdateEventRequest")]
+    pub changes: Json<UpdateEventRequest>,
+    pub status: String,
+    pub reviewer: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// Response for revision lists
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RevisionsResponse {
+    pub revisions: Vec<EventRevision>,
     pub total: usize,
 }
 
-/// Request body for updating an event (admin)
-#[derive(Debug, Deserialize, Validate)]
+/// A submission as seen by its submitter
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SubmissionResponse {
+    pub event: Event,
+    /// Reason code of the rejection, if rejected
+    pub reason: Option<String>,
+    /// Edit to an approved event that is still under review
+    pub pending_revision: Option<EventRevision>,
+}
+
+/// Request body for updating an event (admin, or submitter without `status`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, JsonSchema)]
 pub struct UpdateEventRequest {
     #[validate(length(min = 3))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub organizer: Option<String>,
     #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
     #[validate(range(min = -90.0, max = 90.0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latitude: Option<f64>,
     #[validate(range(min = -180.0, max = 180.0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub longitude: Option<f64>,
-    pub event_date: Option<DateTime<Utc>>,
-    // Allow empty string to clear the field (handled in routes.rs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_date: Option<EventTime>,
+    /// `null` clears the end date
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_date: Option<Option<EventTime>>,
+    /// Re-derived from the coordinates when they change, unless given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<String>,
+    // Allow empty string to clear the field (handled in db::apply_event_update)
+    #[validate(custom(function = "web_link_or_empty"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    #[validate(custom(function = "web_link_or_empty"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_url: Option<String>,
+    #[validate(custom(function = "web_link_or_empty"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
 }
 
+/// Links shown on event pages must be http(s) URLs, so `javascript:` and
+/// similar schemes never reach a browser
+fn web_link(value: &str) -> Result<(), ValidationError> {
+    let web = value.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+    });
+    if web && value.validate_url() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("url").with_message("must be an http or https URL".into()))
+    }
+}
+
+/// [`web_link`] for updates, where an empty string clears the link
+fn web_link_or_empty(value: &str) -> Result<(), ValidationError> {
+    if value.is_empty() {
+        Ok(())
+    } else {
+        web_link(value)
+    }
+}
+
+/// Distinguish an absent field (`None`) from an explicit `null` (`Some(None)`)
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl UpdateEventRequest {
+    /// True if at least one field is set
+    pub fn has_changes(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.organizer.is_some()
+            || self.location_name.is_some()
+            || self.country.is_some()
+            || self.latitude.is_some()
+            || self.longitude.is_some()
+            || self.event_date.is_some()
+            || self.end_date.is_some()
+            || self.timezone.is_some()
+            || self.rrule.is_some()
+            || self.image_url.is_some()
+            || self.video_url.is_some()
+            || self.event_link.is_some()
+            || self.status.is_some()
+    }
+}
+
+/// What an admin may do; each role includes the ones before it
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Read moderation queues, histories and the audit log
+    Viewer,
+    /// Approve or reject events, suggestions and revisions; merge duplicates
+    Moderator,
+    /// Edit events and organizers
+    Editor,
+    /// Delete events and organizers, manage admin and organizer accounts
+    Owner,
+}
+
+impl From<String> for Role {
+    fn from(s: String) -> Self {
+        // Unknown values get the least access
+        match s.to_lowercase().as_str() {
+            "moderator" => Self::Moderator,
+            "editor" => Self::Editor,
+            "owner" => Self::Owner,
+            _ => Self::Viewer,
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Viewer => write!(f, "viewer"),
+            Self::Moderator => write!(f, "moderator"),
+            Self::Editor => write!(f, "editor"),
+            Self::Owner => write!(f, "owner"),
+        }
+    }
+}
+
+/// Admin account (password hash is never loaded into this model)
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct Admin {
+    pub id: i64,
+    pub username: String,
+    #[sqlx(try_from = "String")]
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+/// Request body for POST /api/admin/login
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct LoginRequest {
+    #[validate(length(min = 1))]
+    pub username: String,
+    #[validate(length(min = 1))]
+    pub password: String,
+}
+
+/// Response for GET /api/admin/users
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AdminsResponse {
+    pub admins: Vec<Admin>,
+    pub total: usize,
+}
+
+/// Request body for POST /api/admin/users
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct CreateAdminRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub username: String,
+    pub password: String,
+    pub role: Role,
+}
+
+/// Request body for PUT /api/admin/users/:id (absent fields are kept)
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct UpdateAdminRequest {
+    pub role: Option<Role>,
+    pub password: Option<String>,
+}
+
+/// Response for a successful login
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LoginResponse {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub admin: Admin,
+}
+
+/// Organizer self-service account (without the password hash)
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct OrganizerAccount {
+    pub id: i64,
+    pub username: String,
+    /// Verified accounts publish without moderation
+    pub verified: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// Organizers this account manages
+    #[sqlx(skip)]
+    pub organizer_ids: Vec<i64>,
+}
+
+/// Response for a successful organizer login
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OrganizerLoginResponse {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub account: OrganizerAccount,
+}
+
+/// Response for GET /api/admin/organizer-accounts
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OrganizerAccountsResponse {
+    pub accounts: Vec<OrganizerAccount>,
+    pub total: usize,
+}
+
+/// Request body for POST /api/admin/organizer-accounts
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct CreateOrganizerAccountRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub username: String,
+    pub password: String,
+    #[validate(length(min = 1))]
+    pub organizer_ids: Vec<i64>,
+    #[serde(default)]
+    pub verified: bool,
+}
+
+/// Request body for PUT /api/admin/organizer-accounts/:id (absent fields are kept)
+#[derive(Debug, Default, Deserialize, Validate, JsonSchema)]
+pub struct UpdateOrganizerAccountRequest {
+    pub password: Option<String>,
+    #[validate(length(min = 1))]
+    pub organizer_ids: Option<Vec<i64>>,
+    pub verified: Option<bool>,
+}
+
+/// Reason code attached when rejecting an event
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionReason {
+    Duplicate,
+    Spam,
+    InsufficientInfo,
+    OutsideScope,
+}
+
+impl std::fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Duplicate => write!(f, "duplicate"),
+            Self::Spam => write!(f, "spam"),
+            Self::InsufficientInfo => write!(f, "insufficient_info"),
+            Self::OutsideScope => write!(f, "outside_scope"),
+        }
+    }
+}
+
+/// Optional request body for approve/reject
+#[derive(Debug, Clone, Default, Deserialize, Validate, JsonSchema)]
+pub struct ModerationRequest {
+    /// Only allowed when rejecting
+    pub reason: Option<RejectionReason>,
+    /// Internal notes, never shown to the submitter
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
+
+/// A recorded approve/reject decision
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct ModerationDecision {
+    pub id: i64,
+    pub event_id: i64,
+    pub decision: String,
+    pub reason: Option<String>,
+    pub notes: Option<String>,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+    /// The duplicate event the decision was made on, if it came over in a merge
+    pub merged_from: Option<i64>,
+}
+
+/// Request body for PATCH /api/admin/events/:id/lifecycle
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema)]
+pub struct LifecycleRequest {
+    pub state: EventLifecycle,
+    /// New date, only for `postponed` (omit while still to be announced)
+    pub postponed_to: Option<DateTime<Utc>>,
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// A recorded lifecycle transition
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct LifecycleChange {
+    pub id: i64,
+    pub event_id: i64,
+    pub from_state: String,
+    pub to_state: String,
+    pub postponed_to: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+    /// The duplicate event the change was made on, if it came over in a merge
+    pub merged_from: Option<i64>,
+}
+
+/// Response for an event's lifecycle history, oldest first
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LifecycleHistoryResponse {
+    pub changes: Vec<LifecycleChange>,
+    pub total: usize,
+}
+
+/// Response for an event's moderation history, oldest first
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ModerationHistoryResponse {
+    pub decisions: Vec<ModerationDecision>,
+    pub total: usize,
+}
+
+/// Event as shown in admin views, with its latest moderation decision
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AdminEvent {
+    #[serde(flatten)]
+    pub event: Event,
+    pub latest_decision: Option<ModerationDecision>,
+    /// Existing events this one likely duplicates, best match first
+    pub duplicate_candidates: Vec<DuplicateCandidate>,
+    /// Organizers the free-text name might refer to, if it was ambiguous
+    pub organizer_suggestions: Vec<OrganizerSuggestion>,
+}
+
+/// An existing event flagged as a likely duplicate of a submission
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct DuplicateCandidate {
+    #[serde(skip_serializing)]
+    #[schemars(skip)]
+    pub event_id: i64,
+    pub candidate_id: i64,
+    pub score: f64,
+    pub title: String,
+    pub organizer: String,
+    pub location_name: String,
+    pub event_date: DateTime<Utc>,
+    pub status: String,
+    /// Admin API path of the candidate event
+    pub link: String,
+}
+
+/// Organizer an unlinked event's name might refer to
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct OrganizerSuggestion {
+    #[serde(skip_serializing)]
+    #[schemars(skip)]
+    pub event_id: i64,
+    pub organizer_id: i64,
+    pub name: String,
+    pub slug: String,
+    pub score: f64,
+}
+
+/// Request body for PUT /api/admin/events/:id/organizer
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct LinkOrganizerRequest {
+    /// `null` unlinks the event
+    pub organizer_id: Option<i64>,
+    /// Also save the event's organizer name as an alias of the organizer
+    #[serde(default)]
+    pub remember_alias: bool,
+}
+
+/// Request to merge a duplicate event into the event it duplicates
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MergeEventRequest {
+    /// ID of the surviving event
+    pub into: i64,
+    pub reason: Option<String>,
+}
+
+/// Response for admin event lists
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AdminEventsResponse {
+    pub events: Vec<AdminEvent>,
+    pub total: usize,
+}
+
+/// Query parameters for DELETE /api/admin/events/:id
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct DeleteEventQuery {
+    /// Free-text reason kept in the audit log
+    pub reason: Option<String>,
+}
+
+/// Audit log entry for an admin action
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub actor_id: Option<i64>,
+    pub actor: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: i64,
+    #[sqlx(rename = "before_json")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub before: Option<Json<serde_json::Value>>,
+    #[sqlx(rename = "after_json")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub after: Option<Json<serde_json::Value>>,
+    #[sqlx(rename = "diff_json")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub changes: Option<Json<serde_json::Value>>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Query parameters for GET /api/admin/audit
+#[derive(Debug, Clone, Default, Deserialize, Validate, JsonSchema)]
+pub struct AuditLogQuery {
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i64>,
+    /// Admin username
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    #[schemars(range(min = 1, max = "MAX_PAGE_SIZE"))]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0))]
+    pub offset: Option<i64>,
+}
+
+/// Response for audit log queries, newest first
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AuditLogResponse {
+    pub entries: Vec<AuditLogEntry>,
+    /// Number of matching entries before pagination
+    pub total: usize,
+}
+
 /// Video suggestion model
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, JsonSchema)]
 pub struct VideoSuggestion {
     pub id: i64,
     pub event_id: i64,
@@ -157,7 +1184,7 @@ pub struct VideoSuggestion {
 }
 
 /// Request to create a suggestion
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
 pub struct CreateSuggestionRequest {
     pub event_id: i64,
     #[validate(url)]
@@ -165,12 +1192,93 @@ pub struct CreateSuggestionRequest {
 }
 
 /// Response for suggestions list
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct SuggestionsResponse {
     pub suggestions: Vec<VideoSuggestion>,
     pub total: usize,
 }
 
+/// A live tracking session: the sound bike sharing its position during a ride
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct LiveSession {
+    pub id: i64,
+    pub event_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub last_fix_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+    /// `stopped` or `inactive`
+    pub end_reason: Option<String>,
+}
+
+/// One GPS fix of a live session
+#[derive(Debug, Clone, Serialize, FromRow, JsonSchema)]
+pub struct LivePosition {
+    pub session_id: i64,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Degrees clockwise from north
+    pub heading: Option<f64>,
+    /// Metres per second
+    pub speed: Option<f64>,
+    /// Metres
+    pub accuracy: Option<f64>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Request body for POST /api/live/:session_id/positions
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct LiveFixRequest {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: f64,
+    #[validate(range(min = 0.0, max = 360.0))]
+    pub heading: Option<f64>,
+    #[validate(range(min = 0.0))]
+    pub speed: Option<f64>,
+    #[validate(range(min = 0.0))]
+    pub accuracy: Option<f64>,
+    /// When the device took the fix; defaults to when it arrives
+    pub recorded_at: Option<DateTime<Utc>>,
+}
+
+/// Response for POST /api/events/:id/live. The token is shown only once.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct StartLiveResponse {
+    pub session: LiveSession,
+    pub token: String,
+}
+
+/// A session with its latest position and the trail leading up to it (oldest first)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LiveSnapshot {
+    pub session: LiveSession,
+    pub position: Option<LivePosition>,
+    pub trail: Vec<LivePosition>,
+}
+
+/// Response for GET /api/live
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LiveNowResponse {
+    pub sessions: Vec<LiveSnapshot>,
+    pub total: usize,
+}
+
+/// Response for GET /api/events/:id/live/sessions, newest first
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LiveSessionsResponse {
+    pub sessions: Vec<LiveSession>,
+    pub total: usize,
+}
+
+/// Response for GET /api/live/:session_id/track: the whole recorded track for replay
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LiveTrackResponse {
+    pub session: LiveSession,
+    pub positions: Vec<LivePosition>,
+    pub distance_km: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,17 +1302,33 @@ mod tests {
         assert_eq!(EventStatus::Pending.to_string(), "pending");
     }
 
+    #[test]
+    fn test_role_parsing_and_order() {
+        assert_eq!(Role::from("editor".to_string()), Role::Editor);
+        assert_eq!(Role::from("OWNER".to_string()), Role::Owner);
+        assert_eq!(Role::from("superuser".to_string()), Role::Viewer);
+        assert_eq!(Role::Moderator.to_string(), "moderator");
+        assert!(Role::Viewer < Role::Moderator);
+        assert!(Role::Moderator < Role::Editor);
+        assert!(Role::Editor < Role::Owner);
+    }
+
     #[test]
     fn test_create_event_request_validation() {
         let valid_request = CreateEventRequest {
             title: "Valid Title".to_string(),
             description: None,
             organizer: "Organizer".to_string(),
+            organizer_id: None,
             location_name: "Location".to_string(),
             country: Some("Country".to_string()),
             latitude: 50.0,
             longitude: 10.0,
-            event_date: Utc::now(),
+            event_date: EventTime::Utc(Utc::now()),
+            end_date: None,
+            timezone: None,
+            rrule: None,
+            exdates: vec![],
             image_url: Some("https://example.com/image.jpg".to_string()),
             video_url: None,
             event_link: None,
@@ -230,6 +1354,112 @@ mod tests {
         assert!(invalid_url.validate().is_err());
     }
 
+    #[test]
+    fn test_event_filters_pagination() {
+        let filters = EventFilters {
+            limit: Some(10),
+            offset: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(filters.start_offset(), Some(20));
+        assert_eq!(filters.next_cursor(20, 10, 45), Some("30".to_string()));
+        assert_eq!(filters.next_cursor(40, 5, 45), None);
+
+        let with_cursor = EventFilters {
+            cursor: Some("30".to_string()),
+            ..filters.clone()
+        };
+        assert_eq!(with_cursor.start_offset(), Some(30));
+
+        let bad_cursor = EventFilters {
+            cursor: Some("abc".to_string()),
+            ..filters.clone()
+        };
+        assert_eq!(bad_cursor.start_offset(), None);
+
+        let unpaged = EventFilters::default();
+        assert_eq!(unpaged.start_offset(), Some(0));
+        assert_eq!(unpaged.next_cursor(0, 45, 45), None);
+
+        let too_big = EventFilters {
+            limit: Some(MAX_PAGE_SIZE + 1),
+            ..Default::default()
+        };
+        assert!(too_big.validate().is_err());
+    }
+
+    #[test]
+    fn test_lifecycle_transitions() {
+        use EventLifecycle::*;
+        assert!(Scheduled.can_transition_to(Cancelled));
+        assert!(Scheduled.can_transition_to(Postponed));
+        assert!(Postponed.can_transition_to(Postponed));
+        assert!(Postponed.can_transition_to(Scheduled));
+        assert!(Cancelled.can_transition_to(Scheduled));
+        assert!(!Scheduled.can_transition_to(Scheduled));
+        assert!(!Cancelled.can_transition_to(Completed));
+        assert!(!Completed.can_transition_to(Cancelled));
+
+        assert_eq!(EventLifecycle::from("CANCELLED".to_string()), Cancelled);
+        assert_eq!(EventLifecycle::from("".to_string()), Scheduled);
+        assert_eq!(Postponed.to_string(), "postponed");
+    }
+
+    #[test]
+    fn test_update_request_distinguishes_null_from_absent() {
+        let absent: UpdateEventRequest = serde_json::from_str(r#"{"title": "New"}"#).unwrap();
+        assert_eq!(absent.end_date, None);
+
+        let cleared: UpdateEventRequest = serde_json::from_str(r#"{"end_date": null}"#).unwrap();
+        assert_eq!(cleared.end_date, Some(None));
+        assert!(cleared.has_changes());
+        // Round-trips through the revision queue unchanged
+        let stored = serde_json::to_string(&cleared).unwrap();
+        assert_eq!(stored, r#"{"end_date":null}"#);
+        let restored: UpdateEventRequest = serde_json::from_str(&stored).unwrap();
+        assert_eq!(restored.end_date, Some(None));
+    }
+
+    #[test]
+    fn test_event_time_accepts_instants_and_local_times() {
+        let utc: EventTime = serde_json::from_str(r#""2026-04-05T15:00:00Z""#).unwrap();
+        let offset: EventTime = serde_json::from_str(r#""2026-04-05T17:00:00+02:00""#).unwrap();
+        let local: EventTime = serde_json::from_str(r#""2026-04-05T17:00:00""#).unwrap();
+        assert!(matches!(local, EventTime::Local(_)));
+
+        let expected = Utc.with_ymd_and_hms(2026, 4, 5, 15, 0, 0).unwrap();
+        for time in [utc, offset, local] {
+            assert_eq!(time.resolve(Tz::Europe__Warsaw), Ok(expected));
+        }
+        // 14:00 in Adelaide in March is UTC+10:30
+        let adelaide: EventTime = serde_json::from_str(r#""2026-03-15T14:00:00""#).unwrap();
+        assert_eq!(
+            adelaide.resolve(Tz::Australia__Adelaide),
+            Ok(Utc.with_ymd_and_hms(2026, 3, 15, 3, 30, 0).unwrap())
+        );
+
+        // Clocks in Warsaw jump from 02:00 to 03:00 on 29 March 2026
+        let skipped: EventTime = serde_json::from_str(r#""2026-03-29T02:30:00""#).unwrap();
+        assert!(skipped.resolve(Tz::Europe__Warsaw).is_err());
+    }
+
+    #[test]
+    fn test_moderation_request_parsing() {
+        let request: ModerationRequest =
+            serde_json::from_str(r#"{"reason": "insufficient_info", "notes": "No date"}"#).unwrap();
+        assert_eq!(request.reason, Some(RejectionReason::InsufficientInfo));
+        assert_eq!(request.reason.unwrap().to_string(), "insufficient_info");
+        assert!(request.validate().is_ok());
+
+        assert!(serde_json::from_str::<ModerationRequest>(r#"{"reason": "boring"}"#).is_err());
+
+        let too_long = ModerationRequest {
+            reason: None,
+            notes: Some("x".repeat(2001)),
+        };
+        assert!(too_long.validate().is_err());
+    }
+
     #[test]
     fn test_create_suggestion_request_validation() {
         let valid = CreateSuggestionRequest {
@@ -0,0 +1,869 @@
+use std::sync::Arc;
+
+use axum::{
+    http::Method,
+    response::Html,
+    routing::{MethodFilter, MethodRouter},
+    Json,
+};
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::error::ErrorBody;
+use crate::models::Role;
+use crate::routes::OPERATIONS;
+use crate::AppState;
+
+/// Who may call an operation
+#[derive(Debug, Clone, Copy)]
+pub enum Auth {
+    Public,
+    /// Anonymous, or an organizer account's bearer token
+    OptionalOrganizer,
+    /// A session token from one of the login endpoints
+    Session,
+    Organizer,
+    /// The submitter's `X-Edit-Token`
+    EditToken,
+    /// A tracking device's `X-Live-Token`
+    LiveToken,
+    /// A tracking device's `X-Live-Token`, or a session of an account managing the event
+    LiveTokenOrSession,
+    /// An admin session (or the break-glass key) with at least this role
+    Admin(Role),
+}
+
+/// Adds a model's schema to the generator's definitions and returns a
+/// reference to it
+pub type SchemaFn = fn(&mut SchemaGenerator) -> schemars::schema::Schema;
+
+const fn schema_of<T: JsonSchema>() -> SchemaFn {
+    SchemaGenerator::subschema_for::<T>
+}
+
+/// Serves an operation for the method it is given
+pub type Handler = fn(MethodFilter) -> MethodRouter<Arc<AppState>>;
+
+/// Successful response of an operation
+#[derive(Debug, Clone, Copy)]
+pub enum Body {
+    /// JSON described by a model's schema
+    Json(SchemaFn),
+    /// Non-JSON content of the given media type
+    Media(&'static str),
+    Empty,
+}
+
+/// One endpoint: its route, handler and documentation. Query strings and
+/// bodies refer to the model types the handler uses, so their schemas are
+/// derived from the code.
+#[derive(Debug, Clone, Copy)]
+pub struct Operation {
+    /// Method and OpenAPI path template, e.g. `GET /api/events/{id}`
+    pub route: &'static str,
+    pub handler: Handler,
+    pub tag: &'static str,
+    pub summary: &'static str,
+    pub auth: Auth,
+    pub query: Option<SchemaFn>,
+    pub request: Option<SchemaFn>,
+    /// The JSON request body may be left out
+    pub optional_request: bool,
+    /// Media types accepted as a non-JSON request body
+    pub upload: &'static [&'static str],
+    pub status: u16,
+    pub response: Body,
+}
+
+pub const fn op(
+    route: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    handler: Handler,
+) -> Operation {
+    Operation {
+        route,
+        handler,
+        tag,
+        summary,
+        auth: Auth::Public,
+        query: None,
+        request: None,
+        optional_request: false,
+        upload: &[],
+        status: 200,
+        response: Body::Empty,
+    }
+}
+
+impl Operation {
+    pub fn method(&self) -> &'static str {
+        self.route
+            .split_once(' ')
+            .expect("route is `METHOD /path`")
+            .0
+    }
+
+    pub fn path(&self) -> &'static str {
+        self.route
+            .split_once(' ')
+            .expect("route is `METHOD /path`")
+            .1
+    }
+
+    /// The path in axum's syntax, e.g. `/api/events/:id`
+    pub fn router_path(&self) -> String {
+        self.path()
+            .split('/')
+            .map(|segment| match segment.strip_prefix('{') {
+                Some(name) => format!(":{}", name.trim_end_matches('}')),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// The handler, served for this operation's method only
+    pub fn method_router(&self) -> MethodRouter<Arc<AppState>> {
+        let method =
+            Method::from_bytes(self.method().as_bytes()).expect("route has a valid method");
+        let filter = MethodFilter::try_from(method).expect("route has a routable method");
+        (self.handler)(filter)
+    }
+
+    pub const fn auth(self, auth: Auth) -> Self {
+        Self { auth, ..self }
+    }
+
+    pub const fn admin(self, role: Role) -> Self {
+        self.auth(Auth::Admin(role))
+    }
+
+    pub const fn query<T: JsonSchema>(self) -> Self {
+        Self {
+            query: Some(schema_of::<T>()),
+            ..self
+        }
+    }
+
+    pub const fn request<T: JsonSchema>(self) -> Self {
+        Self {
+            request: Some(schema_of::<T>()),
+            ..self
+        }
+    }
+
+    pub const fn optional_request<T: JsonSchema>(self) -> Self {
+        Self {
+            optional_request: true,
+            ..self.request::<T>()
+        }
+    }
+
+    pub const fn upload(self, media_types: &'static [&'static str]) -> Self {
+        Self {
+            upload: media_types,
+            ..self
+        }
+    }
+
+    pub const fn returns(self, status: u16, response: Body) -> Self {
+        Self {
+            status,
+            response,
+            ..self
+        }
+    }
+
+    pub const fn json<T: JsonSchema>(self) -> Self {
+        self.returns(200, json::<T>())
+    }
+}
+
+pub const fn json<T: JsonSchema>() -> Body {
+    Body::Json(schema_of::<T>())
+}
+
+pub const CALENDAR: Body = Body::Media("text/calendar");
+pub const RSS: Body = Body::Media("application/rss+xml");
+pub const ATOM: Body = Body::Media("application/atom+xml");
+pub const EVENT_STREAM: Body = Body::Media("text/event-stream");
+
+/// GET /api/openapi.json - OpenAPI 3.1 description of the API
+pub async fn spec() -> Json<Value> {
+    Json(document())
+}
+
+/// GET /api/docs - API reference rendered by Swagger UI
+pub async fn docs() -> Html<&'static str> {
+    Html(DOCS_PAGE)
+}
+
+const DOCS_PAGE: &str = r##"<!DOCTYPE html>
+<html>
+  <head>
+    <title>DNB RIDE API</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5.17.14/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5.17.14/swagger-ui-bundle.js"></script>
+    <script>
+      SwaggerUIBundle({ url: "/api/openapi.json", dom_id: "#swagger-ui" });
+    </script>
+  </body>
+</html>
+"##;
+
+/// Generator for the component schemas, derived from the models with
+/// `#[derive(JsonSchema)]`
+fn generator() -> SchemaGenerator {
+    SchemaSettings::draft2019_09()
+        .with(|settings| {
+            settings.definitions_path = "#/components/schemas/".to_string();
+            settings.meta_schema = None;
+        })
+        .into_generator()
+}
+
+/// Build the OpenAPI document from [`OPERATIONS`] and the schemas of the
+/// model types they refer to
+pub fn document() -> Value {
+    let mut generator = generator();
+    let mut paths = Map::new();
+    for operation in OPERATIONS {
+        let item = paths
+            .entry(operation.path())
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .expect("path items are objects");
+        item.insert(
+            operation.method().to_lowercase(),
+            operation_object(operation, &mut generator),
+        );
+    }
+
+    let error = schema_value(generator.subschema_for::<ErrorBody>());
+    let schemas: Map<String, Value> = generator
+        .take_definitions()
+        .into_iter()
+        .map(|(name, schema)| (name, schema_value(schema)))
+        .collect();
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "DNB RIDE API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Drum and bass bike rides: public event listings, submissions and moderation."
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "responses": {
+                "Error": {
+                    "description": "Error with a machine-readable code and, for validation failures, per-field messages",
+                    "content": { "application/json": { "schema": error } }
+                }
+            },
+            "securitySchemes": {
+                "bearer": { "type": "http", "scheme": "bearer" },
+                "adminKey": { "type": "apiKey", "in": "header", "name": "X-Admin-Key" },
+                "editToken": { "type": "apiKey", "in": "header", "name": "X-Edit-Token" },
+                "liveToken": { "type": "apiKey", "in": "header", "name": "X-Live-Token" }
+            }
+        }
+    })
+}
+
+fn operation_object(operation: &Operation, generator: &mut SchemaGenerator) -> Value {
+    let mut parameters: Vec<Value> = path_params(operation.path())
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": path_param_schema(name),
+            })
+        })
+        .collect();
+    if let Some(query) = operation.query {
+        // One parameter per field of the query struct
+        let reference = query(generator);
+        let object = schema_value(
+            generator
+                .dereference(&reference)
+                .expect("query parameters are a struct"),
+        );
+        let required = object["required"].as_array().cloned().unwrap_or_default();
+        for (name, schema) in object["properties"].as_object().into_iter().flatten() {
+            parameters.push(json!({
+                "name": name,
+                "in": "query",
+                "required": required.contains(&json!(name)),
+                "schema": schema,
+            }));
+        }
+    }
+
+    let mut object = json!({
+        "tags": [operation.tag],
+        "summary": operation.summary,
+        "operationId": operation_id(operation),
+        "parameters": parameters,
+        "responses": {
+            operation.status.to_string(): response_object(operation, generator),
+            "default": { "$ref": "#/components/responses/Error" }
+        }
+    });
+
+    if !operation.upload.is_empty() {
+        let content: Map<String, Value> = operation
+            .upload
+            .iter()
+            .map(|media_type| (media_type.to_string(), json!({})))
+            .collect();
+        object["requestBody"] = json!({ "required": true, "content": content });
+    }
+    if let Some(request) = operation.request {
+        object["requestBody"] = json!({
+            "required": !operation.optional_request,
+            "content": { "application/json": { "schema": schema_value(request(generator)) } }
+        });
+    }
+
+    let (security, description) = match operation.auth {
+        Auth::Public => (None, None),
+        Auth::OptionalOrganizer => (
+            Some(json!([{}, { "bearer": [] }])),
+            Some("Anonymous, or with an organizer account's session token".to_string()),
+        ),
+        Auth::Session => (Some(json!([{ "bearer": [] }])), None),
+        Auth::Organizer => (
+            Some(json!([{ "bearer": [] }])),
+            Some("Requires an organizer account session".to_string()),
+        ),
+        Auth::EditToken => (Some(json!([{ "editToken": [] }])), None),
+        Auth::LiveToken => (Some(json!([{ "liveToken": [] }])), None),
+        Auth::LiveTokenOrSession => (
+            Some(json!([{ "liveToken": [] }, { "bearer": [] }])),
+            Some(
+                "With the device token, or a session of an account managing the event".to_string(),
+            ),
+        ),
+        Auth::Admin(role) => (
+            Some(json!([{ "bearer": [] }, { "adminKey": [] }])),
+            Some(format!("Requires the `{}` admin role or higher", role)),
+        ),
+    };
+    if let Some(security) = security {
+        object["security"] = security;
+    }
+    if let Some(description) = description {
+        object["description"] = json!(description);
+    }
+    object
+}
+
+fn response_object(operation: &Operation, generator: &mut SchemaGenerator) -> Value {
+    let description = match operation.status {
+        201 => "Created",
+        204 => "No content",
+        _ => "OK",
+    };
+    match operation.response {
+        Body::Json(schema) => json!({
+            "description": description,
+            "content": { "application/json": { "schema": schema_value(schema(generator)) } }
+        }),
+        Body::Media(media_type) => json!({
+            "description": description,
+            "content": { media_type: {} }
+        }),
+        Body::Empty => json!({ "description": description }),
+    }
+}
+
+/// `GET /api/events/{id}/share` -> `get_api_events_id_share`
+fn operation_id(operation: &Operation) -> String {
+    let path: String = operation
+        .path()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let path: Vec<&str> = path.split('_').filter(|s| !s.is_empty()).collect();
+    format!("{}_{}", operation.method().to_lowercase(), path.join("_"))
+}
+
+/// Names of the `{param}` segments of a path template
+pub fn path_params(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+}
+
+fn path_param_schema(name: &str) -> Value {
+    match name {
+        "id" | "alias_id" | "session_id" => json!({ "type": "integer" }),
+        "start" => json!({ "type": "string", "format": "date-time" }),
+        _ => json!({ "type": "string" }),
+    }
+}
+
+fn schema_value(schema: impl Serialize) -> Value {
+    serde_json::to_value(schema).expect("schemas serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Role;
+    use crate::{db, routes};
+    use axum::body::Body as HttpBody;
+    use axum::http::{header, Method, Request, StatusCode};
+    use axum::Router;
+    use std::collections::BTreeSet;
+    use std::sync::LazyLock;
+    use tower::ServiceExt;
+
+    /// Component schemas of the document
+    static SCHEMAS: LazyLock<Value> = LazyLock::new(|| document()["components"]["schemas"].clone());
+
+    #[test]
+    fn test_operations_are_unique() {
+        let routes: BTreeSet<(&str, &str)> = OPERATIONS
+            .iter()
+            .map(|op| (op.method(), op.path()))
+            .collect();
+        assert_eq!(routes.len(), OPERATIONS.len(), "duplicate operations");
+        let ids: BTreeSet<String> = OPERATIONS.iter().map(operation_id).collect();
+        assert_eq!(ids.len(), OPERATIONS.len(), "duplicate operation ids");
+    }
+
+    #[test]
+    fn test_every_reference_resolves() {
+        let text = document().to_string();
+        for (at, _) in text.match_indices("#/components/schemas/") {
+            let rest = &text[at + "#/components/schemas/".len()..];
+            let name = &rest[..rest.find('"').unwrap()];
+            assert!(SCHEMAS.get(name).is_some(), "unknown schema {}", name);
+        }
+    }
+
+    /// The app from the `routes` tests with the sample data, and a session
+    /// token of an owner
+    async fn seeded_app() -> (Router, String) {
+        let (app, db) = routes::tests::test_app().await;
+        db::seed_sample_data(&db).await.unwrap();
+        (app, routes::tests::admin_token(&db, Role::Owner).await)
+    }
+
+    /// Reference to the component schema of `T`
+    fn reference<T: JsonSchema>() -> Value {
+        schema_value(generator().subschema_for::<T>())
+    }
+
+    /// Name of the component schema behind a [`SchemaFn`]
+    fn schema_name(schema: SchemaFn) -> String {
+        let reference = schema_value(schema(&mut generator()));
+        reference["$ref"]
+            .as_str()
+            .unwrap()
+            .trim_start_matches("#/components/schemas/")
+            .to_string()
+    }
+
+    async fn call(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        headers: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> (StatusCode, Vec<u8>) {
+        let mut request = Request::builder()
+            .method(Method::from_bytes(method.as_bytes()).unwrap())
+            .uri(uri);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let body = match body {
+            Some(body) => {
+                request = request.header(header::CONTENT_TYPE, "application/json");
+                HttpBody::from(body.to_string())
+            }
+            None => HttpBody::empty(),
+        };
+        let response = app
+            .clone()
+            .oneshot(request.body(body).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        // Event streams never end
+        if response.headers().get(header::CONTENT_TYPE)
+            == Some(&header::HeaderValue::from_static("text/event-stream"))
+        {
+            return (status, Vec::new());
+        }
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, bytes.to_vec())
+    }
+
+    /// A URI for an operation, with path parameters pointing at seeded rows
+    fn example_uri(operation: &Operation) -> String {
+        let mut uri = operation.path().to_string();
+        for name in path_params(operation.path()) {
+            let value = match name {
+                "slug" => "nh-kolektyw",
+                "country" => "Poland",
+                "start" => "2026-01-01T00:00:00Z",
+                _ => "1",
+            };
+            uri = uri.replace(&format!("{{{}}}", name), value);
+        }
+        let query = match operation.query.map(schema_name).as_deref() {
+            Some("SearchQuery") => "?q=warszawa",
+            Some("NearbyQuery") => "?lat=52.2&lng=21.0&radius_km=2000",
+            Some("BoundingBoxQuery") => "?min_lat=35&min_lng=-10&max_lat=60&max_lng=30",
+            _ => "",
+        };
+        uri + query
+    }
+
+    #[tokio::test]
+    async fn test_documented_operations_are_routed() {
+        let (app, token) = seeded_app().await;
+        let bearer = format!("Bearer {}", token);
+        for operation in OPERATIONS {
+            let uri = example_uri(operation).replace("/1", "/999");
+            let body = operation.request.map(|_| json!({}));
+            let (status, bytes) = call(
+                &app,
+                operation.method(),
+                &uri,
+                &[("authorization", &bearer)],
+                body,
+            )
+            .await;
+            // The router's own 404 has no body; handler errors are JSON
+            assert!(
+                status != StatusCode::METHOD_NOT_ALLOWED
+                    && !(status == StatusCode::NOT_FOUND && bytes.is_empty()),
+                "{} {} is not routed ({})",
+                operation.method(),
+                uri,
+                status
+            );
+        }
+    }
+
+    /// Check a JSON value against a schema from this document. Objects may
+    /// not carry undocumented properties, so model changes show up here.
+    fn check(value: &Value, schema: &Value, at: &str) -> Result<(), String> {
+        if let Some(name) = schema["$ref"].as_str() {
+            let name = name.trim_start_matches("#/components/schemas/");
+            let schema = SCHEMAS
+                .get(name)
+                .ok_or_else(|| format!("{}: unknown schema {}", at, name))?;
+            return check(value, schema, at);
+        }
+        if let Some(options) = schema["anyOf"].as_array().or(schema["oneOf"].as_array()) {
+            return options
+                .iter()
+                .find_map(|option| check(value, option, at).ok())
+                .ok_or_else(|| format!("{}: {} matches no alternative", at, value));
+        }
+        if let Some(parts) = schema["allOf"].as_array() {
+            return parts.iter().try_for_each(|part| check(value, part, at));
+        }
+
+        let types: Vec<&str> = match &schema["type"] {
+            Value::String(ty) => vec![ty.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => return Ok(()),
+        };
+        let matches = types.iter().any(|ty| match *ty {
+            "null" => value.is_null(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "string" => value.is_string(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => false,
+        });
+        if !matches {
+            return Err(format!("{}: {} is not {:?}", at, value, types));
+        }
+        if let (Some(values), Some(value)) = (schema["enum"].as_array(), value.as_str()) {
+            if !values.iter().any(|v| v == value) {
+                return Err(format!("{}: {} is not one of {:?}", at, value, values));
+            }
+        }
+        if let Some(items) = value.as_array() {
+            for (i, item) in items.iter().enumerate() {
+                check(item, &schema["items"], &format!("{}[{}]", at, i))?;
+            }
+        }
+        if let Some(object) = value.as_object() {
+            if let Some(properties) = schema["properties"].as_object() {
+                for name in object.keys() {
+                    if !properties.contains_key(name) {
+                        return Err(format!("{}.{} is not documented", at, name));
+                    }
+                }
+                for name in schema["required"].as_array().into_iter().flatten() {
+                    let name = name.as_str().unwrap();
+                    if !object.contains_key(name) {
+                        return Err(format!("{}.{} is required but missing", at, name));
+                    }
+                }
+                for (name, property) in properties {
+                    if let Some(field) = object.get(name) {
+                        check(field, property, &format!("{}.{}", at, name))?;
+                    }
+                }
+            } else if schema["additionalProperties"].is_object() {
+                for (name, field) in object {
+                    check(
+                        field,
+                        &schema["additionalProperties"],
+                        &format!("{}.{}", at, name),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_response(operation: &Operation, bytes: &[u8]) {
+        let Body::Json(schema) = operation.response else {
+            return;
+        };
+        let value: Value = serde_json::from_slice(bytes).unwrap();
+        let reference = schema_value(schema(&mut generator()));
+        if let Err(error) = check(&value, &reference, &schema_name(schema)) {
+            panic!("{} {}: {}", operation.method(), operation.path(), error);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_responses_match_schemas() {
+        let (app, token) = seeded_app().await;
+        let bearer = format!("Bearer {}", token);
+
+        // Something for the moderation, lifecycle and audit views to show
+        let rejected = json!({ "reason": "spam", "notes": "test" });
+        call(
+            &app,
+            "PATCH",
+            "/api/admin/events/2/reject",
+            &[("authorization", &bearer)],
+            Some(rejected),
+        )
+        .await;
+        let postponed = json!({ "state": "postponed", "postponed_to": "2030-01-01T00:00:00Z" });
+        call(
+            &app,
+            "PATCH",
+            "/api/admin/events/1/lifecycle",
+            &[("authorization", &bearer)],
+            Some(postponed),
+        )
+        .await;
+        let (_, bytes) = call(
+            &app,
+            "POST",
+            "/api/events/1/live",
+            &[("authorization", &bearer)],
+            None,
+        )
+        .await;
+        let live_token = serde_json::from_slice::<Value>(&bytes).unwrap()["token"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let route = json!({ "type": "LineString", "coordinates": [[21.0, 52.2], [21.01, 52.21]] });
+        call(
+            &app,
+            "PUT",
+            "/api/events/1/route",
+            &[("authorization", &bearer)],
+            Some(route),
+        )
+        .await;
+        let fix = json!({ "latitude": 52.23, "longitude": 21.01, "speed": 4.2 });
+        call(
+            &app,
+            "POST",
+            "/api/live/1/positions",
+            &[("x-live-token", &live_token)],
+            Some(fix),
+        )
+        .await;
+
+        for operation in OPERATIONS.iter().filter(|op| op.method() == "GET") {
+            if matches!(operation.auth, Auth::Organizer | Auth::EditToken) {
+                continue;
+            }
+            let uri = example_uri(operation);
+            let (status, bytes) =
+                call(&app, "GET", &uri, &[("authorization", &bearer)], None).await;
+            assert_eq!(status.as_u16(), operation.status, "GET {}", uri);
+            check_response(operation, &bytes);
+        }
+
+        // Submissions, and the submitter's view of them
+        let create = OPERATIONS
+            .iter()
+            .find(|op| op.method() == "POST" && op.path() == "/api/events")
+            .unwrap();
+        let submission = json!({
+            "title": "Contract Ride",
+            "organizer": "NH Kolektyw",
+            "location_name": "Rynek",
+            "latitude": 50.06,
+            "longitude": 19.94,
+            "event_date": "2030-06-01T18:00:00",
+            "exdates": []
+        });
+        let (status, bytes) = call(&app, "POST", "/api/events", &[], Some(submission)).await;
+        assert_eq!(status.as_u16(), create.status);
+        check_response(create, &bytes);
+
+        let created: Value = serde_json::from_slice(&bytes).unwrap();
+        let uri = format!("/api/submissions/{}", created["id"]);
+        let edit_token = created["edit_token"].as_str().unwrap();
+        let get = OPERATIONS
+            .iter()
+            .find(|op| op.method() == "GET" && op.path() == "/api/submissions/{id}")
+            .unwrap();
+        let (status, bytes) = call(&app, "GET", &uri, &[("x-edit-token", edit_token)], None).await;
+        assert_eq!(status.as_u16(), get.status);
+        check_response(get, &bytes);
+
+        // Organizer self-service
+        let account = json!({ "username": "crew", "password": "correct horse battery", "organizer_ids": [2] });
+        call(
+            &app,
+            "POST",
+            "/api/admin/organizer-accounts",
+            &[("authorization", &bearer)],
+            Some(account),
+        )
+        .await;
+        let login = json!({ "username": "crew", "password": "correct horse battery" });
+        let (_, bytes) = call(&app, "POST", "/api/organizer/login", &[], Some(login)).await;
+        let organizer_token = serde_json::from_slice::<Value>(&bytes).unwrap()["token"]
+            .as_str()
+            .map(|t| format!("Bearer {}", t))
+            .unwrap();
+        for operation in OPERATIONS
+            .iter()
+            .filter(|op| op.method() == "GET" && matches!(op.auth, Auth::Organizer))
+        {
+            let (status, bytes) = call(
+                &app,
+                "GET",
+                operation.path(),
+                &[("authorization", &organizer_token)],
+                None,
+            )
+            .await;
+            assert_eq!(
+                status.as_u16(),
+                operation.status,
+                "GET {}",
+                operation.path()
+            );
+            check_response(operation, &bytes);
+        }
+
+        // Errors share one shape
+        let (status, bytes) = call(
+            &app,
+            "POST",
+            "/api/events",
+            &[],
+            Some(json!({ "title": "x" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let error: Value = serde_json::from_slice(&bytes).unwrap();
+        check(&error, &reference::<ErrorBody>(), "ErrorBody").unwrap();
+    }
+
+    #[test]
+    fn test_request_schemas_match_models() {
+        use crate::models::{
+            CreateEventRequest, CreateOrganizerRequest, LiveFixRequest, UpdateEventRequest,
+        };
+
+        // A value for every documented property, of the first documented type
+        fn example(schema: &Value) -> Value {
+            if let Some(name) = schema["$ref"].as_str() {
+                return example(&SCHEMAS[name.trim_start_matches("#/components/schemas/")]);
+            }
+            if let Some(options) = schema["anyOf"].as_array().or(schema["oneOf"].as_array()) {
+                return example(&options[0]);
+            }
+            if let Some(values) = schema["enum"].as_array() {
+                return values[0].clone();
+            }
+            let ty = match &schema["type"] {
+                Value::Array(types) => types.iter().find(|ty| *ty != "null").unwrap(),
+                ty => ty,
+            };
+            match (ty.as_str(), schema["format"].as_str()) {
+                (Some("integer"), _) => json!(1),
+                (Some("number"), _) => json!(1.5),
+                (Some("boolean"), _) => json!(true),
+                (Some("string"), Some("date-time")) => json!("2030-01-01T10:00:00Z"),
+                (Some("string"), Some("uri")) => json!("https://example.com"),
+                (Some("string"), Some("email")) => json!("crew@example.com"),
+                (Some("array"), _) => json!([example(&schema["items"])]),
+                (Some("object"), _) => Value::Object(
+                    schema["properties"]
+                        .as_object()
+                        .unwrap()
+                        .iter()
+                        .map(|(name, property)| (name.clone(), example(property)))
+                        .collect(),
+                ),
+                _ => json!("text"),
+            }
+        }
+
+        // A body using every documented property must be accepted by the model
+        serde_json::from_value::<CreateEventRequest>(example(&reference::<CreateEventRequest>()))
+            .unwrap();
+        let update = serde_json::from_value::<UpdateEventRequest>(example(&reference::<
+            UpdateEventRequest,
+        >()))
+        .unwrap();
+        let organizer = serde_json::from_value::<CreateOrganizerRequest>(example(&reference::<
+            CreateOrganizerRequest,
+        >()))
+        .unwrap();
+        serde_json::from_value::<LiveFixRequest>(example(&reference::<LiveFixRequest>())).unwrap();
+
+        // Serializable requests must not have undocumented fields
+        check(
+            &serde_json::to_value(update).unwrap(),
+            &reference::<UpdateEventRequest>(),
+            "UpdateEventRequest",
+        )
+        .unwrap();
+        check(
+            &serde_json::to_value(organizer).unwrap(),
+            &reference::<CreateOrganizerRequest>(),
+            "CreateOrganizerRequest",
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,447 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, Sqlite, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+
+use crate::db;
+use crate::dedup;
+use crate::models::{Organizer, OrganizerBackfillResponse, OrganizerStats};
+
+/// Column list matching the `Organizer` model
+pub const ORGANIZER_COLUMNS: &str =
+    "id, name, slug, description, website, logo_url, city, country, \
+     instagram_url, facebook_url, youtube_url, soundcloud_url, contact_email, created_at";
+
+/// Hosts accepted for each social profile link (subdomains such as `www.` and `m.` included)
+pub const INSTAGRAM_HOSTS: &[&str] = &["instagram.com"];
+pub const FACEBOOK_HOSTS: &[&str] = &["facebook.com", "fb.com"];
+pub const YOUTUBE_HOSTS: &[&str] = &["youtube.com", "youtu.be"];
+pub const SOUNDCLOUD_HOSTS: &[&str] = &["soundcloud.com"];
+
+/// Longest slug we generate or accept
+pub const MAX_SLUG_LENGTH: usize = 80;
+
+/// URL slug for a name: ASCII, lowercase, words joined by hyphens
+/// (e.g. "Kraków Bass Crew!" -> "krakow-bass-crew")
+pub fn slugify(name: &str) -> String {
+    let slug = dedup::normalize(name).replace(' ', "-");
+    match slug.char_indices().nth(MAX_SLUG_LENGTH) {
+        Some((cut, _)) => slug[..cut].trim_end_matches('-').to_string(),
+        None => slug,
+    }
+}
+
+/// True for slugs `slugify` could have produced
+pub fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug.len() <= MAX_SLUG_LENGTH
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && !slug.contains("--")
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// `base`, or `base-2`, `base-3`, ... if taken by another organizer
+pub async fn unique_slug(
+    conn: &mut SqliteConnection,
+    base: &str,
+    exclude_id: Option<i64>,
+) -> Result<String, sqlx::Error> {
+    let mut candidate = base.to_string();
+    let mut suffix = 2;
+    while slug_taken(&mut *conn, &candidate, exclude_id).await? {
+        candidate = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    Ok(candidate)
+}
+
+/// True if another organizer already uses `slug`
+pub async fn slug_taken<'e, E>(
+    executor: E,
+    slug: &str,
+    exclude_id: Option<i64>,
+) -> Result<bool, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let row: Option<(i64,)> =
+        sqlx::query_as("SELECT id FROM organizers WHERE slug = ? AND id != ?")
+            .bind(slug)
+            .bind(exclude_id.unwrap_or(-1))
+            .fetch_optional(executor)
+            .await?;
+    Ok(row.is_some())
+}
+
+/// True if another organizer already uses `name` (compared case-insensitively)
+pub async fn name_taken<'e, E>(
+    executor: E,
+    name: &str,
+    exclude_id: Option<i64>,
+) -> Result<bool, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let row: Option<(i64,)> =
+        sqlx::query_as("SELECT id FROM organizers WHERE name = ? COLLATE NOCASE AND id != ?")
+            .bind(name)
+            .bind(exclude_id.unwrap_or(-1))
+            .fetch_optional(executor)
+            .await?;
+    Ok(row.is_some())
+}
+
+/// True if `url` is an http(s) link on one of `hosts` or their subdomains
+pub fn is_profile_link(url: &str, hosts: &[&str]) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return false;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    hosts.iter().any(|h| {
+        host == *h
+            || host
+                .strip_suffix(h)
+                .is_some_and(|subdomain| subdomain.ends_with('.'))
+    })
+}
+
+/// Ride statistics for an organizer's profile
+pub async fn stats(
+    db: &SqlitePool,
+    organizer_id: i64,
+    now: DateTime<Utc>,
+) -> Result<OrganizerStats, sqlx::Error> {
+    let mut stats: OrganizerStats = sqlx::query_as(&format!(
+        r#"
+        SELECT
+            COUNT(*) AS total_rides,
+            COALESCE(SUM({0} IS NULL OR {0} > ?), 0) AS upcoming_rides,
+            MIN(event_date) AS first_ride,
+            MAX(event_date) AS last_ride,
+            COALESCE(SUM(video_url IS NOT NULL AND video_url != ''), 0) AS rides_with_video
+        FROM events
+        WHERE organizer_id = ? AND status = 'approved' AND lifecycle != 'cancelled'
+        "#,
+        db::EVENT_END_SQL
+    ))
+    .bind(now)
+    .bind(organizer_id)
+    .fetch_one(db)
+    .await?;
+
+    stats.countries_visited = sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT country FROM events
+        WHERE organizer_id = ? AND status = 'approved' AND lifecycle != 'cancelled'
+        ORDER BY country
+        "#,
+    )
+    .bind(organizer_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(stats)
+}
+
+/// Load a single organizer by id
+pub async fn fetch_organizer<'e, E>(executor: E, id: i64) -> Result<Option<Organizer>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    sqlx::query_as(&format!(
+        "SELECT {} FROM organizers WHERE id = ?",
+        ORGANIZER_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// Auto-link when the best match is at least this similar...
+pub const AUTO_LINK_THRESHOLD: f64 = 0.85;
+
+/// ...and the runner-up is at least this much less similar
+pub const AUTO_LINK_MARGIN: f64 = 0.1;
+
+/// Organizers at least this similar are offered to moderators
+pub const SUGGEST_THRESHOLD: f64 = 0.5;
+
+/// Outcome of matching a free-text organizer name
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrganizerMatch {
+    /// One organizer is a confident match
+    Linked(i64),
+    /// Several plausible organizers (id, score), best first
+    Ambiguous(Vec<(i64, f64)>),
+    Unmatched,
+}
+
+/// Similarity in [0, 1] of two organizer names, ignoring case, accents,
+/// punctuation and spacing ("N.H. Kolektyw" = "nh kolektyw")
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (dedup::normalize(a), dedup::normalize(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a.replace(' ', "") == b.replace(' ', "") {
+        return 1.0;
+    }
+    dedup::title_similarity(&a, &b)
+}
+
+/// Best score per organizer for `name` against every known spelling
+/// (`candidates` are (organizer id, spelling) pairs), best first
+pub fn rank_candidates(name: &str, candidates: &[(i64, String)]) -> Vec<(i64, f64)> {
+    let mut best: HashMap<i64, f64> = HashMap::new();
+    for (id, spelling) in candidates {
+        let score = name_similarity(name, spelling);
+        let entry = best.entry(*id).or_insert(0.0);
+        *entry = entry.max(score);
+    }
+    let mut ranked: Vec<(i64, f64)> = best.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked
+}
+
+/// Decide what to do with ranked candidates
+pub fn classify(ranked: &[(i64, f64)]) -> OrganizerMatch {
+    let runner_up = ranked.get(1).map_or(0.0, |c| c.1);
+    match ranked.first() {
+        Some(&(id, score))
+            if score >= AUTO_LINK_THRESHOLD && score - runner_up >= AUTO_LINK_MARGIN =>
+        {
+            OrganizerMatch::Linked(id)
+        }
+        _ => {
+            let plausible: Vec<(i64, f64)> = ranked
+                .iter()
+                .copied()
+                .filter(|c| c.1 >= SUGGEST_THRESHOLD)
+                .collect();
+            if plausible.is_empty() {
+                OrganizerMatch::Unmatched
+            } else {
+                OrganizerMatch::Ambiguous(plausible)
+            }
+        }
+    }
+}
+
+/// Every known spelling of every organizer: names, slugs and aliases
+pub async fn load_candidates<'e, E>(executor: E) -> Result<Vec<(i64, String)>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    sqlx::query_as(
+        r#"
+        SELECT id, name FROM organizers
+        UNION ALL SELECT id, replace(slug, '-', ' ') FROM organizers
+        UNION ALL SELECT organizer_id, alias FROM organizer_aliases
+        "#,
+    )
+    .fetch_all(executor)
+    .await
+}
+
+/// Link an unlinked event to its organizer, or store suggestions if the name is ambiguous
+pub async fn link_event(
+    db: &SqlitePool,
+    event_id: i64,
+    name: &str,
+) -> Result<OrganizerMatch, sqlx::Error> {
+    let candidates = load_candidates(db).await?;
+    let result = classify(&rank_candidates(name, &candidates));
+    apply_match(&mut *db.acquire().await?, event_id, &result).await?;
+    Ok(result)
+}
+
+async fn apply_match(
+    conn: &mut SqliteConnection,
+    event_id: i64,
+    result: &OrganizerMatch,
+) -> Result<(), sqlx::Error> {
+    match result {
+        OrganizerMatch::Linked(organizer_id) => {
+            sqlx::query("UPDATE events SET organizer_id = ? WHERE id = ? AND organizer_id IS NULL")
+                .bind(organizer_id)
+                .bind(event_id)
+                .execute(&mut *conn)
+                .await?;
+            sqlx::query("DELETE FROM event_organizer_suggestions WHERE event_id = ?")
+                .bind(event_id)
+                .execute(&mut *conn)
+                .await?;
+        }
+        OrganizerMatch::Ambiguous(suggestions) => {
+            sqlx::query("DELETE FROM event_organizer_suggestions WHERE event_id = ?")
+                .bind(event_id)
+                .execute(&mut *conn)
+                .await?;
+            for (organizer_id, score) in suggestions {
+                sqlx::query(
+                    "INSERT INTO event_organizer_suggestions (event_id, organizer_id, score) VALUES (?, ?, ?)",
+                )
+                .bind(event_id)
+                .bind(organizer_id)
+                .bind(score)
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+        OrganizerMatch::Unmatched => {}
+    }
+    Ok(())
+}
+
+/// Match every event without an organizer link against the current organizers.
+/// Also returns the ids of the events that were linked.
+pub async fn backfill(
+    conn: &mut SqliteConnection,
+) -> Result<(OrganizerBackfillResponse, Vec<i64>), sqlx::Error> {
+    let candidates = load_candidates(&mut *conn).await?;
+    let events: Vec<(i64, String)> =
+        sqlx::query_as("SELECT id, organizer FROM events WHERE organizer_id IS NULL ORDER BY id")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let mut summary = OrganizerBackfillResponse::default();
+    let mut linked = Vec::new();
+    for (event_id, name) in events {
+        let result = classify(&rank_candidates(&name, &candidates));
+        apply_match(&mut *conn, event_id, &result).await?;
+        match result {
+            OrganizerMatch::Linked(_) => {
+                summary.linked += 1;
+                linked.push(event_id);
+            }
+            OrganizerMatch::Ambiguous(_) => summary.ambiguous += 1,
+            OrganizerMatch::Unmatched => summary.unmatched += 1,
+        }
+    }
+    Ok((summary, linked))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("NH Kolektyw"), "nh-kolektyw");
+        assert_eq!(slugify("  Kraków Bass Crew! "), "krakow-bass-crew");
+        assert_eq!(slugify("Łódź -- DnB & Bikes"), "lodz-dnb-bikes");
+        assert_eq!(slugify("!!!"), "");
+        assert!(slugify(&"ride ".repeat(40)).len() <= MAX_SLUG_LENGTH);
+        assert!(is_valid_slug(&slugify(&"ride ".repeat(40))));
+    }
+
+    #[test]
+    fn test_is_valid_slug() {
+        assert!(is_valid_slug("berlin-dnb-crew"));
+        assert!(is_valid_slug("crew-2"));
+        assert!(!is_valid_slug(""));
+        assert!(!is_valid_slug("Berlin"));
+        assert!(!is_valid_slug("-crew"));
+        assert!(!is_valid_slug("dnb--crew"));
+        assert!(!is_valid_slug("dnb crew"));
+        assert!(!is_valid_slug("kraków"));
+    }
+
+    #[test]
+    fn test_is_profile_link() {
+        assert!(is_profile_link(
+            "https://www.instagram.com/nhkolektyw/",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(is_profile_link(
+            "https://instagram.com/nhkolektyw",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(is_profile_link(
+            "http://m.facebook.com/dnbride",
+            FACEBOOK_HOSTS
+        ));
+        assert!(is_profile_link("https://youtu.be/abc", YOUTUBE_HOSTS));
+        assert!(is_profile_link(
+            "https://SoundCloud.com/crew",
+            SOUNDCLOUD_HOSTS
+        ));
+        // Lookalike hosts, other sites and other schemes
+        assert!(!is_profile_link(
+            "https://notinstagram.com/x",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(!is_profile_link(
+            "https://instagram.com.evil.io/x",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(!is_profile_link(
+            "https://instagram.com@evil.io/x",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(!is_profile_link("https://facebook.com/x", INSTAGRAM_HOSTS));
+        assert!(!is_profile_link(
+            "javascript://instagram.com/",
+            INSTAGRAM_HOSTS
+        ));
+        assert!(!is_profile_link(
+            "instagram.com/nhkolektyw",
+            INSTAGRAM_HOSTS
+        ));
+    }
+
+    fn candidates() -> Vec<(i64, String)> {
+        vec![
+            (1, "Dom Whiting".to_string()),
+            (2, "NH Kolektyw".to_string()),
+            (2, "Nowa Huta Kolektyw".to_string()),
+            (3, "Berlin DNB Crew".to_string()),
+            (4, "Berlin DnB Collective".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("N.H. Kolektyw", "nh kolektyw"), 1.0);
+        assert_eq!(name_similarity("DOM WHITING", "Dom Whiting"), 1.0);
+        assert!(name_similarity("Dom Whitting", "Dom Whiting") > AUTO_LINK_THRESHOLD);
+        assert!(name_similarity("Someone Else", "Dom Whiting") < SUGGEST_THRESHOLD);
+        assert_eq!(name_similarity("", "Dom Whiting"), 0.0);
+    }
+
+    #[test]
+    fn test_classify_links_confident_matches() {
+        let candidates = candidates();
+        let classify_name = |name: &str| classify(&rank_candidates(name, &candidates));
+
+        assert_eq!(classify_name("nh kolektyw"), OrganizerMatch::Linked(2));
+        // Alias
+        assert_eq!(
+            classify_name("Nowa Huta Kolektyw"),
+            OrganizerMatch::Linked(2)
+        );
+        // Typo
+        assert_eq!(classify_name("Dom Whitting"), OrganizerMatch::Linked(1));
+        assert_eq!(classify_name("Totally Unknown"), OrganizerMatch::Unmatched);
+
+        // Close to two crews: left for a moderator
+        match classify_name("Berlin DnB") {
+            OrganizerMatch::Ambiguous(options) => {
+                let ids: Vec<i64> = options.iter().map(|o| o.0).collect();
+                assert!(ids.contains(&3) && ids.contains(&4), "{:?}", options);
+            }
+            other => panic!("expected ambiguous, got {:?}", other),
+        }
+    }
+}
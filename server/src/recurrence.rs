@@ -0,0 +1,726 @@
+use chrono::{
+    DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::models::{
+    Event, EventLifecycle, EventOccurrence, OccurrenceOverride, UpdateEventRequest,
+};
+use crate::timezone;
+
+/// Recurring series are expanded this far from now when no window is given
+pub const DEFAULT_WINDOW_DAYS: i64 = 365;
+
+/// Hard cap on occurrences generated for one series in one request
+pub const MAX_OCCURRENCES: usize = 1000;
+
+/// Upper bound on periods walked, so sparse rules (e.g. every 31st) always terminate
+const MAX_PERIODS: u32 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The subset of RFC 5545 RRULE we support: FREQ, INTERVAL, COUNT, UNTIL,
+/// BYDAY (WEEKLY, or MONTHLY with an optional ordinal like `-1SU`) and BYMONTHDAY (MONTHLY).
+/// Occurrences keep the local wall-clock time of the first one, so an 18:00 ride
+/// stays at 18:00 across daylight saving changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_uppercase();
+        let value = value.strip_prefix("RRULE:").unwrap_or(&value);
+
+        let mut freq = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+        let mut by_month_day = Vec::new();
+
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("malformed rule part '{}'", part))?;
+            match key {
+                "FREQ" => {
+                    freq = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(format!("unsupported FREQ '{}'", other)),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n >= 1)
+                        .ok_or("INTERVAL must be a positive integer")?
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|n| *n >= 1)
+                            .ok_or("COUNT must be a positive integer")?,
+                    )
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    by_month_day = value
+                        .split(',')
+                        .map(|d| {
+                            d.parse::<i32>()
+                                .ok()
+                                .filter(|n| *n != 0 && n.abs() <= 31)
+                                .ok_or_else(|| format!("invalid BYMONTHDAY '{}'", d))
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                // Only the default week start is supported
+                "WKST" if value == "MO" => {}
+                other => return Err(format!("unsupported rule part '{}'", other)),
+            }
+        }
+
+        let freq = freq.ok_or("FREQ is required")?;
+        if count.is_some() && until.is_some() {
+            return Err("COUNT and UNTIL cannot both be set".to_string());
+        }
+        match freq {
+            Frequency::Daily | Frequency::Yearly
+                if !by_day.is_empty() || !by_month_day.is_empty() =>
+            {
+                return Err("BYDAY/BYMONTHDAY need FREQ=WEEKLY or MONTHLY".to_string());
+            }
+            Frequency::Weekly
+                if !by_month_day.is_empty() || by_day.iter().any(|(n, _)| n.is_some()) =>
+            {
+                return Err("FREQ=WEEKLY only supports plain BYDAY weekdays".to_string());
+            }
+            Frequency::Monthly if !by_day.is_empty() && !by_month_day.is_empty() => {
+                return Err("BYDAY and BYMONTHDAY cannot be combined".to_string());
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            freq,
+            interval,
+            count,
+            until,
+            by_day,
+            by_month_day,
+        })
+    }
+}
+
+/// Canonical form, as stored and written to calendar feeds
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let freq = match self.freq {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={}", freq)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|(n, day)| {
+                    let code = &day.to_string()[..2].to_ascii_uppercase();
+                    match n {
+                        Some(n) => format!("{}{}", n, code),
+                        None => code.to_string(),
+                    }
+                })
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// UNTIL as a UTC date-time, or a date meaning the end of that day
+fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+    let invalid = || format!("invalid UNTIL '{}'", value);
+    if let Some(datetime) = value.strip_suffix('Z') {
+        let naive = chrono::NaiveDateTime::parse_from_str(datetime, "%Y%m%dT%H%M%S")
+            .map_err(|_| invalid())?;
+        return Ok(Utc.from_utc_datetime(&naive));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| invalid())?;
+    Ok(Utc.from_utc_datetime(&date.and_hms_opt(23, 59, 59).ok_or_else(invalid)?))
+}
+
+fn parse_by_day(value: &str) -> Result<(Option<i32>, Weekday), String> {
+    let invalid = || format!("invalid BYDAY '{}'", value);
+    if value.len() < 2 {
+        return Err(invalid());
+    }
+    let (ordinal, day) = value.split_at(value.len() - 2);
+    let day = match day {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(invalid()),
+    };
+    let ordinal = match ordinal {
+        "" => None,
+        n => Some(
+            n.parse::<i32>()
+                .ok()
+                .filter(|n| *n != 0 && n.abs() <= 5)
+                .ok_or_else(invalid)?,
+        ),
+    };
+    Ok((ordinal, day))
+}
+
+impl RecurrenceRule {
+    /// All occurrence starts in order, honouring COUNT and UNTIL,
+    /// with days counted in the event's timezone
+    pub fn iter(&self, dtstart: DateTime<Utc>, tz: Tz) -> Occurrences<'_> {
+        Occurrences {
+            rule: self,
+            dtstart,
+            tz,
+            period: 0,
+            buffer: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        }
+    }
+
+    /// Candidate starts in the `period`-th interval after `dtstart`
+    fn period_starts(&self, dtstart: DateTime<Utc>, tz: Tz, period: u32) -> Vec<DateTime<Utc>> {
+        let local = dtstart.with_timezone(&tz);
+        let date = local.date_naive();
+        let at = |day: NaiveDate| wall_clock(tz, day.and_time(local.time()));
+        let step = period.saturating_mul(self.interval);
+
+        match self.freq {
+            Frequency::Daily => vec![at(date + Duration::days(step as i64))],
+            Frequency::Weekly => {
+                if self.by_day.is_empty() {
+                    return vec![at(date + Duration::weeks(step as i64))];
+                }
+                let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64)
+                    + Duration::weeks(step as i64);
+                self.by_day
+                    .iter()
+                    .map(|(_, day)| at(monday + Duration::days(day.num_days_from_monday() as i64)))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let Some(first) = date
+                    .with_day(1)
+                    .and_then(|d| d.checked_add_months(Months::new(step)))
+                else {
+                    return Vec::new();
+                };
+                let days_in_month = days_in_month(first);
+                let days: Vec<u32> = if !self.by_day.is_empty() {
+                    self.by_day
+                        .iter()
+                        .flat_map(|(n, day)| weekdays_in_month(first, days_in_month, *n, *day))
+                        .collect()
+                } else if !self.by_month_day.is_empty() {
+                    self.by_month_day
+                        .iter()
+                        .filter_map(|d| match *d {
+                            d if d > 0 => Some(d as u32),
+                            d => (days_in_month as i32 + d + 1).try_into().ok(),
+                        })
+                        .collect()
+                } else {
+                    vec![date.day()]
+                };
+                days.into_iter()
+                    .filter(|d| *d >= 1 && *d <= days_in_month)
+                    .filter_map(|d| first.with_day(d))
+                    .map(at)
+                    .collect()
+            }
+            // Feb 29 only recurs in leap years
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(date.year() + step as i32, date.month(), date.day())
+                    .map(at)
+                    .into_iter()
+                    .collect()
+            }
+        }
+    }
+}
+
+/// UTC instant of a local time; the first of two in a DST overlap, and an hour
+/// later for times skipped by a DST gap
+fn wall_clock(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
+
+fn days_in_month(first: NaiveDate) -> u32 {
+    first
+        .checked_add_months(Months::new(1))
+        .map(|next| (next - first).num_days() as u32)
+        .unwrap_or(31)
+}
+
+/// Days of the month falling on `day`; an ordinal picks one (negative counts from the end)
+fn weekdays_in_month(first: NaiveDate, len: u32, ordinal: Option<i32>, day: Weekday) -> Vec<u32> {
+    let offset = (7 + day.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+    let all: Vec<u32> = (1 + offset..=len).step_by(7).collect();
+    match ordinal {
+        None => all,
+        Some(n) if n > 0 => all.get(n as usize - 1).copied().into_iter().collect(),
+        Some(n) => all
+            .len()
+            .checked_sub(n.unsigned_abs() as usize)
+            .and_then(|i| all.get(i).copied())
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Iterator over the occurrence starts of a rule
+pub struct Occurrences<'a> {
+    rule: &'a RecurrenceRule,
+    dtstart: DateTime<Utc>,
+    tz: Tz,
+    period: u32,
+    buffer: VecDeque<DateTime<Utc>>,
+    emitted: u32,
+    done: bool,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if let Some(next) = self.buffer.pop_front() {
+                let over_count = self.rule.count.is_some_and(|c| self.emitted >= c);
+                let over_until = self.rule.until.is_some_and(|u| next > u);
+                if over_count || over_until {
+                    self.done = true;
+                    return None;
+                }
+                self.emitted += 1;
+                return Some(next);
+            }
+            if self.period >= MAX_PERIODS {
+                self.done = true;
+                return None;
+            }
+            let mut starts = self.rule.period_starts(self.dtstart, self.tz, self.period);
+            starts.retain(|s| *s >= self.dtstart);
+            starts.sort();
+            starts.dedup();
+            self.buffer.extend(starts);
+            self.period += 1;
+        }
+        None
+    }
+}
+
+/// Start, optional end, timezone and optional recurrence of an event
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    pub tz: Tz,
+    pub rule: Option<RecurrenceRule>,
+}
+
+impl Schedule {
+    /// Validate a schedule; the start must be the first occurrence of the rule
+    pub fn new(
+        start: DateTime<Utc>,
+        end: Option<DateTime<Utc>>,
+        rrule: Option<&str>,
+        tz: Tz,
+    ) -> Result<Self, String> {
+        if end.is_some_and(|end| end <= start) {
+            return Err("end_date must be after event_date".to_string());
+        }
+        let rule = match rrule.map(str::trim).filter(|r| !r.is_empty()) {
+            Some(rrule) => Some(rrule.parse::<RecurrenceRule>()?),
+            None => None,
+        };
+        if let Some(ref rule) = rule {
+            if rule.iter(start, tz).next() != Some(start) {
+                return Err("event_date must be the first occurrence of the rule".to_string());
+            }
+        }
+        Ok(Self {
+            start,
+            end,
+            tz,
+            rule,
+        })
+    }
+
+    /// Schedule of a stored event; a rule that no longer parses is treated as a one-off
+    pub fn of(event: &Event) -> Self {
+        let tz = event.tz();
+        Self::new(event.event_date, event.end_date, event.rrule.as_deref(), tz).unwrap_or(Self {
+            start: event.event_date,
+            end: event.end_date,
+            tz,
+            rule: None,
+        })
+    }
+
+    /// Schedule an event would have after an update (empty `rrule` clears it).
+    /// The timezone is the explicit one, else re-derived if the event moved;
+    /// local times in the payload are read in that timezone.
+    pub fn updated(event: &Event, payload: &UpdateEventRequest) -> Result<Self, String> {
+        let tz = match payload.timezone {
+            Some(ref name) => {
+                timezone::parse(name).ok_or_else(|| format!("unknown timezone '{}'", name))?
+            }
+            None if payload.latitude.is_some() || payload.longitude.is_some() => timezone::resolve(
+                payload.latitude.unwrap_or(event.latitude),
+                payload.longitude.unwrap_or(event.longitude),
+            ),
+            None => event.tz(),
+        };
+        let start = match payload.event_date {
+            Some(time) => time.resolve(tz)?,
+            None => event.event_date,
+        };
+        let end = match payload.end_date {
+            Some(Some(time)) => Some(time.resolve(tz)?),
+            Some(None) => None,
+            None => event.end_date,
+        };
+        let rrule = match payload.rrule {
+            Some(ref rrule) => Some(rrule.as_str()),
+            None => event.rrule.as_deref(),
+        };
+        Self::new(start, end, rrule, tz)
+    }
+
+    /// Canonical rule string to store, if recurring
+    pub fn rrule(&self) -> Option<String> {
+        self.rule.as_ref().map(RecurrenceRule::to_string)
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end
+            .map(|end| end - self.start)
+            .unwrap_or_else(Duration::zero)
+    }
+
+    /// End of the last occurrence, or `None` for an open-ended series
+    pub fn series_end(&self) -> Option<DateTime<Utc>> {
+        match self.rule {
+            None => Some(self.end.unwrap_or(self.start)),
+            Some(ref rule) if rule.count.is_none() && rule.until.is_none() => None,
+            Some(ref rule) => rule
+                .iter(self.start, self.tz)
+                .last()
+                .map(|s| s + self.duration()),
+        }
+    }
+
+    /// Starts of the occurrences overlapping `[from, to]`, capped at `MAX_OCCURRENCES`
+    pub fn occurrences(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let earliest = from - self.duration();
+        match self.rule {
+            None => (self.start >= earliest && self.start <= to)
+                .then_some(self.start)
+                .into_iter()
+                .collect(),
+            Some(ref rule) => rule
+                .iter(self.start, self.tz)
+                .skip_while(|s| *s < earliest)
+                .take_while(|s| *s <= to)
+                .take(MAX_OCCURRENCES)
+                .collect(),
+        }
+    }
+
+    pub fn is_occurrence(&self, start: DateTime<Utc>) -> bool {
+        self.occurrences(start, start).contains(&start)
+    }
+}
+
+/// Build one occurrence of an event, applying its override if any
+pub fn occurrence(
+    event: &Event,
+    start: DateTime<Utc>,
+    ov: Option<&OccurrenceOverride>,
+) -> EventOccurrence {
+    let duration = Schedule::of(event).duration();
+    let mut occurrence = event.clone();
+    occurrence.event_date = start;
+    occurrence.end_date = event.end_date.map(|_| start + duration);
+
+    if let Some(ov) = ov {
+        if let Some(moved) = ov.start_date {
+            occurrence.event_date = moved;
+            occurrence.end_date = event.end_date.map(|_| moved + duration);
+        }
+        if ov.end_date.is_some() {
+            occurrence.end_date = ov.end_date;
+        }
+        if let Some(ref title) = ov.title {
+            occurrence.title = title.clone();
+        }
+        if ov.description.is_some() {
+            occurrence.description = ov.description.clone();
+        }
+        if let Some(ref location_name) = ov.location_name {
+            occurrence.location_name = location_name.clone();
+        }
+        if let (Some(lat), Some(lng)) = (ov.latitude, ov.longitude) {
+            occurrence.latitude = lat;
+            occurrence.longitude = lng;
+        }
+    }
+
+    occurrence.localize();
+
+    EventOccurrence {
+        event: occurrence,
+        occurrence_start: start,
+        cancelled: event.lifecycle == EventLifecycle::Cancelled || ov.is_some_and(|o| o.cancelled),
+        overridden: ov.is_some(),
+    }
+}
+
+/// Occurrences of an event overlapping `[from, to]`, including occurrences
+/// moved into the window and excluding those moved out of it
+pub fn expand(
+    event: &Event,
+    overrides: &[OccurrenceOverride],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<EventOccurrence> {
+    let schedule = Schedule::of(event);
+    let overrides: HashMap<DateTime<Utc>, &OccurrenceOverride> = overrides
+        .iter()
+        .filter(|o| o.event_id == event.id)
+        .map(|o| (o.occurrence_start, o))
+        .collect();
+
+    let mut starts = schedule.occurrences(from, to);
+    for ov in overrides.values() {
+        let moved_in = ov.start_date.is_some_and(|s| s >= from && s <= to);
+        if moved_in
+            && !starts.contains(&ov.occurrence_start)
+            && schedule.is_occurrence(ov.occurrence_start)
+        {
+            starts.push(ov.occurrence_start);
+        }
+    }
+
+    starts
+        .into_iter()
+        .map(|start| occurrence(event, start, overrides.get(&start).copied()))
+        .filter(|o| {
+            let end = o.event.end_date.unwrap_or(o.event.event_date);
+            o.event.event_date <= to && end >= from
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format_rule() {
+        let rule: RecurrenceRule = "RRULE:freq=weekly;interval=2;byday=TU,TH;until=20260630"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.freq, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(
+            rule.until,
+            Some(Utc.with_ymd_and_hms(2026, 6, 30, 23, 59, 59).unwrap())
+        );
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=20260630T235959Z;BYDAY=TU,TH"
+        );
+        assert_eq!(rule.to_string().parse::<RecurrenceRule>().unwrap(), rule);
+
+        assert!("INTERVAL=2".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=HOURLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;COUNT=3;UNTIL=20260101"
+            .parse::<RecurrenceRule>()
+            .is_err());
+        assert!("FREQ=WEEKLY;BYDAY=1MO".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;BYSETPOS=1".parse::<RecurrenceRule>().is_err());
+    }
+
+    #[test]
+    fn test_weekly_and_monthly_expansion() {
+        // Every Thursday evening, 3 times
+        let rule: RecurrenceRule = "FREQ=WEEKLY;BYDAY=TH;COUNT=3".parse().unwrap();
+        let starts: Vec<_> = rule.iter(utc(2026, 4, 2, 18), Tz::UTC).collect();
+        assert_eq!(
+            starts,
+            vec![
+                utc(2026, 4, 2, 18),
+                utc(2026, 4, 9, 18),
+                utc(2026, 4, 16, 18)
+            ]
+        );
+
+        // Last Sunday of the month
+        let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1SU;COUNT=3".parse().unwrap();
+        let starts: Vec<_> = rule.iter(utc(2026, 3, 29, 14), Tz::UTC).collect();
+        assert_eq!(
+            starts,
+            vec![
+                utc(2026, 3, 29, 14),
+                utc(2026, 4, 26, 14),
+                utc(2026, 5, 31, 14)
+            ]
+        );
+
+        // The 31st skips short months
+        let rule: RecurrenceRule = "FREQ=MONTHLY;COUNT=3".parse().unwrap();
+        let starts: Vec<_> = rule.iter(utc(2026, 1, 31, 12), Tz::UTC).collect();
+        assert_eq!(
+            starts,
+            vec![
+                utc(2026, 1, 31, 12),
+                utc(2026, 3, 31, 12),
+                utc(2026, 5, 31, 12)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schedule_validation_and_series_end() {
+        assert!(Schedule::new(
+            utc(2026, 4, 2, 18),
+            Some(utc(2026, 4, 2, 17)),
+            None,
+            Tz::UTC
+        )
+        .is_err());
+        // 2 April 2026 is a Thursday, not a Monday
+        assert!(Schedule::new(
+            utc(2026, 4, 2, 18),
+            None,
+            Some("FREQ=WEEKLY;BYDAY=MO"),
+            Tz::UTC
+        )
+        .is_err());
+
+        let weekend = Schedule::new(
+            utc(2026, 5, 1, 10),
+            Some(utc(2026, 5, 3, 18)),
+            None,
+            Tz::UTC,
+        )
+        .unwrap();
+        assert_eq!(weekend.series_end(), Some(utc(2026, 5, 3, 18)));
+
+        let weekly = Schedule::new(
+            utc(2026, 4, 2, 18),
+            Some(utc(2026, 4, 2, 21)),
+            Some("FREQ=WEEKLY;COUNT=3"),
+            Tz::UTC,
+        )
+        .unwrap();
+        assert_eq!(weekly.series_end(), Some(utc(2026, 4, 16, 21)));
+        // An occurrence still running at the window start is included
+        assert_eq!(
+            weekly.occurrences(utc(2026, 4, 9, 20), utc(2026, 4, 30, 0)),
+            vec![utc(2026, 4, 9, 18), utc(2026, 4, 16, 18)]
+        );
+        assert!(weekly.is_occurrence(utc(2026, 4, 9, 18)));
+        assert!(!weekly.is_occurrence(utc(2026, 4, 10, 18)));
+
+        let open_ended =
+            Schedule::new(utc(2026, 4, 2, 18), None, Some("FREQ=DAILY"), Tz::UTC).unwrap();
+        assert_eq!(open_ended.series_end(), None);
+    }
+
+    #[test]
+    fn test_expansion_keeps_local_time_across_dst() {
+        // Thursdays at 18:00 in Warsaw; clocks go forward on 29 March 2026
+        let rule: RecurrenceRule = "FREQ=WEEKLY;COUNT=3".parse().unwrap();
+        let starts: Vec<_> = rule
+            .iter(utc(2026, 3, 19, 17), Tz::Europe__Warsaw)
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                utc(2026, 3, 19, 17),
+                utc(2026, 3, 26, 17),
+                utc(2026, 4, 2, 16)
+            ]
+        );
+
+        // 02:30 does not exist on the changeover day and moves to 03:30
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=3".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 3, 28, 1, 30, 0).unwrap();
+        let starts: Vec<_> = rule.iter(start, Tz::Europe__Warsaw).collect();
+        assert_eq!(starts[1], start + Duration::days(1));
+        assert_eq!(starts[2], start + Duration::days(2) - Duration::hours(1));
+    }
+}
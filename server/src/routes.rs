@@ -1,680 +1,6596 @@
 use axum::{
-    extract::{Path, State},
-    http::{HeaderMap, StatusCode},
-    routing::{delete, get, patch, post, put},
+    body::Bytes,
+    extract::{DefaultBodyLimit, OriginalUri, State},
+    http::{header, HeaderMap, StatusCode, Uri},
+    response::{AppendHeaders, Html, IntoResponse, Response},
+    routing::on,
     Json, Router,
 };
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{types::Json as SqlJson, QueryBuilder, Sqlite};
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::audit::{self, AuditRecord};
+use crate::auth::{
+    self, AdminIdentity, Editor, Moderator, OrganizerIdentity, Owner, Permission, Principal,
+    RequireRole, Viewer,
+};
+use crate::db::{self, EVENT_COLUMNS};
+use crate::dedup;
+use crate::error::{ApiError, AppJson, AppJsonOrEmpty, AppPath, AppQuery};
+use crate::feed;
+use crate::geo::{self, BoundingBox};
+use crate::geojson;
+use crate::gpx;
+use crate::ics;
+use crate::jsonld;
+use crate::live::{self, LiveMessage};
 use crate::models::{
-    CreateEventRequest, CreateSuggestionRequest, Event, EventsResponse, Organizer,
-    OrganizersResponse, SuggestionsResponse, UpdateEventRequest, VideoSuggestion,
+    Admin, AdminEvent, AdminEventsResponse, AdminsResponse, AliasesResponse, AuditLogEntry,
+    AuditLogQuery, AuditLogResponse, BoundingBoxQuery, CreateAdminRequest, CreateAliasRequest,
+    CreateEventRequest, CreateOrganizerAccountRequest, CreateOrganizerRequest,
+    CreateSuggestionRequest, CreatedEventResponse, DeleteEventQuery, DeleteOrganizerQuery,
+    DuplicateCandidate, Event, EventDetail, EventFilters, EventLifecycle, EventOccurrence,
+    EventRevision, EventRoute, EventStatus, EventWithDistance, EventsResponse, LifecycleChange,
+    LifecycleHistoryResponse, LifecycleRequest, LinkOrganizerRequest, LiveFixRequest,
+    LiveNowResponse, LivePosition, LiveSession, LiveSessionsResponse, LiveSnapshot,
+    LiveTrackResponse, LoginRequest, LoginResponse, MergeEventRequest, MergeOrganizerRequest,
+    ModerationDecision, ModerationHistoryResponse, ModerationRequest, NearbyEventsResponse,
+    NearbyQuery, OccurrenceOverride, OccurrenceOverrideRequest, OccurrenceWindow,
+    OccurrencesResponse, Organizer, OrganizerAccount, OrganizerAccountsResponse, OrganizerAlias,
+    OrganizerBackfillResponse, OrganizerLoginResponse, OrganizerProfile, OrganizerSuggestion,
+    OrganizersResponse, RevisionsResponse, Role, SearchQuery, SearchResponse, SortOrder,
+    StartLiveResponse, SubmissionResponse, SuggestionsResponse, TimeFilter, UpdateAdminRequest,
+    UpdateEventRequest, UpdateOrganizerAccountRequest, UpdateOrganizerRequest, VideoSuggestion,
+    DEFAULT_RADIUS_KM, DEFAULT_SEARCH_LIMIT,
 };
+use crate::openapi::{self, json, op, Auth, Body, Operation, ATOM, CALENDAR, EVENT_STREAM, RSS};
+use crate::organizers::{self, OrganizerMatch};
+use crate::recurrence::{self, Schedule};
+use crate::search;
+use crate::share;
+use crate::timezone;
+use crate::updates::Audience;
 use crate::AppState;
-use validator::Validate;
+use validator::{Validate, ValidateEmail, ValidateUrl};
 
-/// Get admin API key from environment (required - panics if not set)
-fn get_admin_api_key() -> String {
-    std::env::var("ADMIN_API_KEY")
-        .expect("ADMIN_API_KEY environment variable must be set")
+/// Every API route, mounted under `/api`
+pub fn api_router() -> Router<Arc<AppState>> {
+    OPERATIONS.iter().fold(Router::new(), |router, operation| {
+        router.route(&operation.router_path(), operation.method_router())
+    })
 }
 
-/// Check if request has valid admin API key
-fn check_admin_auth(headers: &HeaderMap) -> bool {
-    let expected_key = get_admin_api_key();
-    headers
-        .get("x-admin-key")
-        .and_then(|v| v.to_str().ok())
-        .map(|key| key == expected_key)
-        .unwrap_or(false)
-}
+/// Every route of the API with its handler and documentation. The router and
+/// the OpenAPI document are both built from this table.
+#[rustfmt::skip]
+pub const OPERATIONS: &[Operation] = &[
+    // Meta
+    op("GET /api/health", "meta", "Liveness check", |m| on(m, || async { "OK" }))
+        .returns(200, Body::Media("text/plain")),
+    op("GET /api/openapi.json", "meta", "This OpenAPI document", |m| on(m, openapi::spec))
+        .returns(200, Body::Media("application/json")),
+    op("GET /api/docs", "meta", "API reference page", |m| on(m, openapi::docs))
+        .returns(200, Body::Media("text/html")),
+    // Events
+    op("GET /api/events.geojson", "events", "Approved events as a GeoJSON FeatureCollection", |m| on(m, events_geojson))
+        .query::<EventFilters>()
+        .returns(200, Body::Media("application/geo+json")),
+    op("GET /api/search", "events", "Full-text search over approved events and organizers", |m| on(m, search))
+        .query::<SearchQuery>()
+        .json::<SearchResponse>(),
+    op("GET /api/events", "events", "List approved events (filterable, paginated)", |m| on(m, list_events))
+        .query::<EventFilters>()
+        .json::<EventsResponse>(),
+    op("POST /api/events", "events", "Submit an event; verified organizer accounts publish directly", |m| on(m, create_event))
+        .auth(Auth::OptionalOrganizer)
+        .request::<CreateEventRequest>()
+        .returns(201, json::<CreatedEventResponse>()),
+    op("GET /api/events/calendar.ics", "calendars", "Feed of upcoming approved events", |m| on(m, upcoming_calendar))
+        .query::<EventFilters>()
+        .returns(200, CALENDAR),
+    op("GET /api/events/feed.rss", "feeds", "RSS feed of newly approved events", |m| on(m, recent_rss))
+        .returns(200, RSS),
+    op("GET /api/events/feed.atom", "feeds", "Atom feed of newly approved events", |m| on(m, recent_atom))
+        .returns(200, ATOM),
+    op("GET /api/events/stream", "events", "Server-sent events for changes to public events; resumes from Last-Event-ID", |m| on(m, event_stream))
+        .returns(200, EVENT_STREAM),
+    op("GET /api/events/{id}", "events", "Get a single event with its planned route", |m| on(m, get_event))
+        .json::<EventDetail>(),
+    op("GET /api/events/{id}/calendar.ics", "calendars", "Download a single approved event", |m| on(m, event_calendar))
+        .returns(200, CALENDAR),
+    op("GET /api/events/{id}/event.jsonld", "sharing", "schema.org Event (JSON-LD) for an approved event", |m| on(m, event_jsonld))
+        .returns(200, Body::Media("application/ld+json")),
+    op("GET /api/events/{id}/share", "sharing", "Share page with OpenGraph and Twitter card tags", |m| on(m, event_share_page))
+        .returns(200, Body::Media("text/html")),
+    op("GET /api/events/{id}/occurrences", "events", "Occurrences of one approved event within a window", |m| on(m, list_event_occurrences))
+        .query::<OccurrenceWindow>()
+        .json::<OccurrencesResponse>(),
+    op("GET /api/events/upcoming", "events", "Upcoming approved occurrences", |m| on(m, list_upcoming))
+        .query::<EventFilters>()
+        .json::<OccurrencesResponse>(),
+    op("GET /api/events/past", "events", "Past approved occurrences", |m| on(m, list_past))
+        .query::<EventFilters>()
+        .json::<OccurrencesResponse>(),
+    op("GET /api/events/within", "events", "Approved events inside a bounding box", |m| on(m, list_within))
+        .query::<BoundingBoxQuery>()
+        .json::<EventsResponse>(),
+    op("GET /api/events/nearby", "events", "Approved events near a point, nearest first", |m| on(m, list_nearby))
+        .query::<NearbyQuery>()
+        .json::<NearbyEventsResponse>(),
+    op("GET /api/events/by-organizer/{slug}", "events", "Approved events of one organizer", |m| on(m, list_events_by_organizer))
+        .query::<EventFilters>()
+        .json::<EventsResponse>(),
+    op("GET /api/events/by-organizer/{slug}/calendar.ics", "calendars", "Feed of one organizer's upcoming events", |m| on(m, organizer_calendar))
+        .returns(200, CALENDAR),
+    op("GET /api/events/by-country/{country}/calendar.ics", "calendars", "Feed of upcoming events in one country", |m| on(m, country_calendar))
+        .returns(200, CALENDAR),
+    op("GET /api/events/by-organizer/{slug}/feed.rss", "feeds", "RSS feed of one organizer's new events", |m| on(m, organizer_rss))
+        .returns(200, RSS),
+    op("GET /api/events/by-organizer/{slug}/feed.atom", "feeds", "Atom feed of one organizer's new events", |m| on(m, organizer_atom))
+        .returns(200, ATOM),
+    op("GET /api/events/by-country/{country}/feed.rss", "feeds", "RSS feed of new events in one country", |m| on(m, country_rss))
+        .returns(200, RSS),
+    op("GET /api/events/by-country/{country}/feed.atom", "feeds", "Atom feed of new events in one country", |m| on(m, country_atom))
+        .returns(200, ATOM),
+    // Organizers
+    op("GET /api/organizers", "organizers", "List organizers", |m| on(m, list_organizers))
+        .json::<OrganizersResponse>(),
+    op("GET /api/organizers/{slug}", "organizers", "Organizer profile with ride stats", |m| on(m, get_organizer))
+        .json::<OrganizerProfile>(),
+    // Admin auth
+    op("POST /api/admin/login", "admin auth", "Exchange username and password for a session token", |m| on(m, admin_login))
+        .request::<LoginRequest>()
+        .json::<LoginResponse>(),
+    op("POST /api/admin/logout", "admin auth", "Revoke the current session token", |m| on(m, admin_logout))
+        .auth(Auth::Session)
+        .returns(204, Body::Empty),
+    op("GET /api/admin/me", "admin auth", "Identity behind the current credentials", |m| on(m, admin_me))
+        .admin(Role::Viewer)
+        .json::<AdminIdentity>(),
+    // Admin events
+    op("GET /api/admin/events", "admin events", "List all events with moderation context", |m| on(m, list_all_events))
+        .admin(Role::Viewer)
+        .json::<AdminEventsResponse>(),
+    op("GET /api/admin/events/pending", "admin events", "List pending events", |m| on(m, list_pending_events))
+        .admin(Role::Viewer)
+        .json::<AdminEventsResponse>(),
+    op("GET /api/admin/events/stream", "admin events", "Server-sent events for changes to all events, including submissions", |m| on(m, admin_event_stream))
+        .admin(Role::Viewer)
+        .returns(200, EVENT_STREAM),
+    op("PUT /api/admin/events/{id}", "admin events", "Update an event", |m| on(m, update_event))
+        .admin(Role::Editor)
+        .request::<UpdateEventRequest>()
+        .json::<Event>(),
+    op("DELETE /api/admin/events/{id}", "admin events", "Delete an event", |m| on(m, delete_event))
+        .admin(Role::Owner)
+        .query::<DeleteEventQuery>()
+        .returns(204, Body::Empty),
+    op("PATCH /api/admin/events/{id}/approve", "admin events", "Approve an event", |m| on(m, approve_event))
+        .admin(Role::Moderator)
+        .optional_request::<ModerationRequest>()
+        .json::<Event>(),
+    op("PATCH /api/admin/events/{id}/reject", "admin events", "Reject an event", |m| on(m, reject_event))
+        .admin(Role::Moderator)
+        .optional_request::<ModerationRequest>()
+        .json::<Event>(),
+    op("GET /api/admin/events/{id}/moderation", "admin events", "Moderation history of an event", |m| on(m, list_moderation_history))
+        .admin(Role::Viewer)
+        .json::<ModerationHistoryResponse>(),
+    op("POST /api/admin/events/{id}/merge", "admin events", "Merge a duplicate into another event", |m| on(m, merge_event))
+        .admin(Role::Moderator)
+        .request::<MergeEventRequest>()
+        .json::<Event>(),
+    op("PUT /api/admin/events/{id}/organizer", "admin events", "Link an event to an organizer, or unlink it", |m| on(m, link_event_organizer))
+        .admin(Role::Editor)
+        .request::<LinkOrganizerRequest>()
+        .json::<Event>(),
+    op("GET /api/admin/events/{id}/lifecycle", "admin events", "Lifecycle history of an event", |m| on(m, list_lifecycle_history))
+        .admin(Role::Viewer)
+        .json::<LifecycleHistoryResponse>(),
+    op("PATCH /api/admin/events/{id}/lifecycle", "admin events", "Cancel, postpone, complete or reschedule an event", |m| on(m, set_event_lifecycle))
+        .admin(Role::Editor)
+        .request::<LifecycleRequest>()
+        .json::<Event>(),
+    op("PUT /api/admin/events/{id}/occurrences/{start}", "admin events", "Cancel or modify one occurrence", |m| on(m, override_occurrence))
+        .admin(Role::Editor)
+        .request::<OccurrenceOverrideRequest>()
+        .json::<OccurrenceOverride>(),
+    op("DELETE /api/admin/events/{id}/occurrences/{start}", "admin events", "Restore an occurrence to the series defaults", |m| on(m, restore_occurrence))
+        .admin(Role::Editor)
+        .returns(204, Body::Empty),
+    // Suggestions
+    op("POST /api/suggestions/video", "suggestions", "Suggest a video for a past event", |m| on(m, create_suggestion))
+        .request::<CreateSuggestionRequest>()
+        .returns(201, Body::Empty),
+    op("GET /api/admin/suggestions", "admin suggestions", "List pending video suggestions", |m| on(m, list_suggestions))
+        .admin(Role::Viewer)
+        .json::<SuggestionsResponse>(),
+    op("PATCH /api/admin/suggestions/{id}/approve", "admin suggestions", "Approve a suggestion", |m| on(m, approve_suggestion))
+        .admin(Role::Moderator)
+        .returns(200, Body::Empty),
+    op("PATCH /api/admin/suggestions/{id}/reject", "admin suggestions", "Reject a suggestion", |m| on(m, reject_suggestion))
+        .admin(Role::Moderator)
+        .returns(200, Body::Empty),
+    // Revisions
+    op("GET /api/admin/revisions", "admin revisions", "List submitter edits waiting for review", |m| on(m, list_revisions))
+        .admin(Role::Viewer)
+        .json::<RevisionsResponse>(),
+    op("PATCH /api/admin/revisions/{id}/approve", "admin revisions", "Apply a submitter edit", |m| on(m, approve_revision))
+        .admin(Role::Moderator)
+        .json::<Event>(),
+    op("PATCH /api/admin/revisions/{id}/reject", "admin revisions", "Discard a submitter edit", |m| on(m, reject_revision))
+        .admin(Role::Moderator)
+        .returns(200, Body::Empty),
+    // Admin organizers
+    op("POST /api/admin/organizers", "admin organizers", "Create an organizer", |m| on(m, create_organizer))
+        .admin(Role::Editor)
+        .request::<CreateOrganizerRequest>()
+        .returns(201, json::<Organizer>()),
+    op("POST /api/admin/organizers/backfill", "admin organizers", "Link unlinked events to organizers", |m| on(m, backfill_organizers))
+        .admin(Role::Editor)
+        .json::<OrganizerBackfillResponse>(),
+    op("PUT /api/admin/organizers/{id}", "admin organizers", "Update an organizer", |m| on(m, update_organizer))
+        .admin(Role::Editor)
+        .request::<UpdateOrganizerRequest>()
+        .json::<Organizer>(),
+    op("DELETE /api/admin/organizers/{id}", "admin organizers", "Delete an organizer", |m| on(m, delete_organizer))
+        .admin(Role::Owner)
+        .query::<DeleteOrganizerQuery>()
+        .returns(204, Body::Empty),
+    op("POST /api/admin/organizers/{id}/merge", "admin organizers", "Merge a duplicate organizer into another", |m| on(m, merge_organizer))
+        .admin(Role::Editor)
+        .request::<MergeOrganizerRequest>()
+        .json::<Organizer>(),
+    op("GET /api/admin/organizers/{id}/aliases", "admin organizers", "Alternative spellings of an organizer", |m| on(m, list_aliases))
+        .admin(Role::Viewer)
+        .json::<AliasesResponse>(),
+    op("POST /api/admin/organizers/{id}/aliases", "admin organizers", "Add an alternative spelling", |m| on(m, create_alias))
+        .admin(Role::Editor)
+        .request::<CreateAliasRequest>()
+        .returns(201, json::<OrganizerAlias>()),
+    op("DELETE /api/admin/organizers/{id}/aliases/{alias_id}", "admin organizers", "Remove an alternative spelling", |m| on(m, delete_alias))
+        .admin(Role::Editor)
+        .returns(204, Body::Empty),
+    // Accounts
+    op("GET /api/admin/organizer-accounts", "admin accounts", "List organizer accounts", |m| on(m, list_organizer_accounts))
+        .admin(Role::Owner)
+        .json::<OrganizerAccountsResponse>(),
+    op("POST /api/admin/organizer-accounts", "admin accounts", "Create an organizer account", |m| on(m, create_organizer_account))
+        .admin(Role::Owner)
+        .request::<CreateOrganizerAccountRequest>()
+        .returns(201, json::<OrganizerAccount>()),
+    op("PUT /api/admin/organizer-accounts/{id}", "admin accounts", "Change an organizer account", |m| on(m, update_organizer_account))
+        .admin(Role::Owner)
+        .request::<UpdateOrganizerAccountRequest>()
+        .json::<OrganizerAccount>(),
+    op("DELETE /api/admin/organizer-accounts/{id}", "admin accounts", "Delete an organizer account", |m| on(m, delete_organizer_account))
+        .admin(Role::Owner)
+        .returns(204, Body::Empty),
+    op("GET /api/admin/users", "admin accounts", "List admin accounts", |m| on(m, list_admins))
+        .admin(Role::Owner)
+        .json::<AdminsResponse>(),
+    op("POST /api/admin/users", "admin accounts", "Create an admin account", |m| on(m, create_admin_user))
+        .admin(Role::Owner)
+        .request::<CreateAdminRequest>()
+        .returns(201, json::<Admin>()),
+    op("PUT /api/admin/users/{id}", "admin accounts", "Change an admin's role or password", |m| on(m, update_admin_user))
+        .admin(Role::Owner)
+        .request::<UpdateAdminRequest>()
+        .json::<Admin>(),
+    op("DELETE /api/admin/users/{id}", "admin accounts", "Delete an admin account", |m| on(m, delete_admin_user))
+        .admin(Role::Owner)
+        .returns(204, Body::Empty),
+    op("GET /api/admin/audit", "admin audit", "Query the audit log", |m| on(m, list_audit_log))
+        .admin(Role::Viewer)
+        .query::<AuditLogQuery>()
+        .json::<AuditLogResponse>(),
+    // Organizer self-service
+    op("POST /api/organizer/login", "organizer accounts", "Exchange account credentials for a session token", |m| on(m, organizer_login))
+        .request::<LoginRequest>()
+        .json::<OrganizerLoginResponse>(),
+    op("POST /api/organizer/logout", "organizer accounts", "Revoke the current session token", |m| on(m, organizer_logout))
+        .auth(Auth::Session)
+        .returns(204, Body::Empty),
+    op("GET /api/organizer/me", "organizer accounts", "The account and the organizers it manages", |m| on(m, organizer_me))
+        .auth(Auth::Organizer)
+        .json::<OrganizerIdentity>(),
+    op("GET /api/organizer/events", "organizer accounts", "Events of the account's organizers, in any status", |m| on(m, list_own_events))
+        .auth(Auth::Organizer)
+        .json::<EventsResponse>(),
+    op("PUT /api/organizer/events/{id}", "organizer accounts", "Edit one of the account's events (202 when queued for review)", |m| on(m, update_own_event))
+        .auth(Auth::Organizer)
+        .request::<UpdateEventRequest>()
+        .json::<SubmissionResponse>(),
+    // Submitters
+    op("GET /api/submissions/{id}", "submissions", "Status of your submission", |m| on(m, get_submission))
+        .auth(Auth::EditToken)
+        .json::<SubmissionResponse>(),
+    op("PUT /api/submissions/{id}", "submissions", "Edit your submission (202 when queued for review)", |m| on(m, update_submission))
+        .auth(Auth::EditToken)
+        .request::<UpdateEventRequest>()
+        .json::<SubmissionResponse>(),
+    op("DELETE /api/submissions/{id}", "submissions", "Withdraw a pending or rejected submission", |m| on(m, withdraw_submission))
+        .auth(Auth::EditToken)
+        .returns(204, Body::Empty),
+    // Planned routes
+    op("PUT /api/events/{id}/route", "routes", "Attach a planned route from GPX or a GeoJSON LineString", |m| on(m, put_event_route).layer(DefaultBodyLimit::max(MAX_ROUTE_UPLOAD_BYTES)))
+        .auth(Auth::Session)
+        .upload(&["application/gpx+xml", "application/geo+json"])
+        .json::<EventRoute>(),
+    op("DELETE /api/events/{id}/route", "routes", "Remove the planned route", |m| on(m, delete_event_route))
+        .auth(Auth::Session)
+        .returns(204, Body::Empty),
+    op("GET /api/events/{id}/route.gpx", "routes", "Planned route of an approved event as GPX", |m| on(m, event_route_gpx))
+        .returns(200, Body::Media("application/gpx+xml")),
+    // Live tracking
+    op("POST /api/events/{id}/live", "live", "Start sharing a ride's position; returns the device token once", |m| on(m, start_live_session))
+        .auth(Auth::Session)
+        .returns(201, json::<StartLiveResponse>()),
+    op("GET /api/events/{id}/live", "live", "Current or latest live session of a ride, with the recent trail", |m| on(m, get_live_snapshot))
+        .json::<LiveSnapshot>(),
+    op("GET /api/events/{id}/live/stream", "live", "Server-sent events following a ride's live position", |m| on(m, live_stream))
+        .returns(200, EVENT_STREAM),
+    op("GET /api/events/{id}/live/sessions", "live", "Every live session of a ride, newest first", |m| on(m, list_live_sessions))
+        .json::<LiveSessionsResponse>(),
+    op("GET /api/live", "live", "Rides being tracked right now", |m| on(m, list_live_now))
+        .json::<LiveNowResponse>(),
+    op("POST /api/live/{session_id}/positions", "live", "Report a GPS fix", |m| on(m, post_live_position))
+        .auth(Auth::LiveToken)
+        .request::<LiveFixRequest>()
+        .returns(201, json::<LivePosition>()),
+    op("POST /api/live/{session_id}/end", "live", "Stop sharing a ride's position", |m| on(m, end_live_session))
+        .auth(Auth::LiveTokenOrSession)
+        .json::<LiveSession>(),
+    op("GET /api/live/{session_id}/track", "live", "Every recorded position of a session, for replay", |m| on(m, get_live_track))
+        .json::<LiveTrackResponse>(),
+];
 
-pub fn events_router() -> Router<Arc<AppState>> {
-    Router::new()
-        .route("/", get(list_events).post(create_event))
-        .route("/:id", get(get_event))
-        .route("/upcoming", get(list_upcoming))
-        .route("/past", get(list_past))
-        .route("/by-organizer/:slug", get(list_events_by_organizer))
+/// GET /api/events - List approved events (filterable, paginated)
+async fn list_events(
+    State(state): State<Arc<AppState>>,
+    AppQuery(filters): AppQuery<EventFilters>,
+) -> Result<Json<EventsResponse>, ApiError> {
+    query_approved_events(&state.db, &filters, SortOrder::Asc)
+        .await
+        .map(Json)
 }
 
-/// Organizers router
-pub fn organizers_router() -> Router<Arc<AppState>> {
-    Router::new()
-        .route("/", get(list_organizers))
-        .route("/:slug", get(get_organizer))
-}
+/// Run a filtered, paginated query over approved events
+async fn query_approved_events(
+    db: &sqlx::SqlitePool,
+    filters: &EventFilters,
+    default_sort: SortOrder,
+) -> Result<EventsResponse, ApiError> {
+    filters.validate()?;
+    let offset = filters
+        .start_offset()
+        .ok_or_else(|| ApiError::field("cursor", "cursor", "is not a valid cursor"))?;
+    let sort = filters.sort.unwrap_or(default_sort).as_sql();
+    let now = Utc::now();
 
-/// Admin router - requires X-Admin-Key header
-pub fn admin_router() -> Router<Arc<AppState>> {
-    Router::new()
-        .route("/events", get(list_all_events))
-        .route("/events/pending", get(list_pending_events))
-        .nest("/suggestions", admin_suggestions_router())
-        .route("/events/:id", put(update_event))
-        .route("/events/:id", delete(delete_event))
-        .route("/events/:id/approve", patch(approve_event))
-        .route("/events/:id/reject", patch(reject_event))
-}
+    let mut count_query: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT COUNT(*) FROM events WHERE status = 'approved'");
+    db::push_event_filters(&mut count_query, filters, now);
+    let (total,): (i64,) = count_query.build_query_as().fetch_one(db).await?;
 
-/// GET /api/events - List all approved events
-async fn list_events(
-    State(state): State<Arc<AppState>>,
-) -> Result<Json<EventsResponse>, StatusCode> {
-    let events: Vec<Event> = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link, 
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events 
-        WHERE status = 'approved'
-        ORDER BY event_date ASC
-        "#,
-    )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .into_iter()
-    .map(|e: Event| e.with_parsed_status())
-    .collect();
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status = 'approved'",
+        EVENT_COLUMNS
+    ));
+    db::push_event_filters(&mut query, filters, now);
+    query.push(format!(" ORDER BY event_date {sort}, id {sort}"));
+    // SQLite requires a LIMIT before OFFSET; -1 means no limit
+    query
+        .push(" LIMIT ")
+        .push_bind(filters.limit.unwrap_or(-1))
+        .push(" OFFSET ")
+        .push_bind(offset);
 
-    let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+    let events: Vec<Event> = query
+        .build_query_as()
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|e: Event| e.with_parsed_status())
+        .collect();
+
+    let next_cursor = filters.next_cursor(offset, events.len(), total);
+    Ok(EventsResponse {
+        events,
+        total: total as usize,
+        next_cursor,
+    })
 }
 
-/// GET /api/events/:id - Get single event by ID
+/// GET /api/events/:id - Get single event by ID, with its planned route
 async fn get_event(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i64>,
-) -> Result<Json<Event>, StatusCode> {
-    let event: Event = sqlx::query_as(
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<EventDetail>, ApiError> {
+    let event: Event = sqlx::query_as(&format!(
         r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
+        SELECT {}
         FROM events 
         WHERE id = ?
         "#,
-    )
+        EVENT_COLUMNS
+    ))
     .bind(id)
     .fetch_optional(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    let route = db::fetch_event_route(&state.db, id).await?;
+    Ok(Json(EventDetail {
+        event: event.with_parsed_status(),
+        route,
+    }))
+}
+
+/// Reason code of the latest decision, only for rejected events
+async fn rejection_reason(
+    db: &sqlx::SqlitePool,
+    id: i64,
+    status: &EventStatus,
+) -> Result<Option<String>, ApiError> {
+    if *status != EventStatus::Rejected {
+        return Ok(None);
+    }
+
+    let reason: Option<(Option<String>,)> = sqlx::query_as(
+        "SELECT reason FROM moderation_decisions WHERE event_id = ? AND merged_from IS NULL ORDER BY id DESC LIMIT 1",
+    )
+    .bind(id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(reason.and_then(|(reason,)| reason))
+}
+
+/// Approved events as occurrences in one time bucket. One-off events are filtered and
+/// paginated in SQL; recurring series are expanded within `from`/`to`, defaulting to a
+/// year from now, and merged into the page.
+async fn query_approved_occurrences(
+    db: &sqlx::SqlitePool,
+    filters: &EventFilters,
+    time: TimeFilter,
+    default_sort: SortOrder,
+) -> Result<OccurrencesResponse, ApiError> {
+    filters.validate()?;
+    let offset = filters
+        .start_offset()
+        .ok_or_else(|| ApiError::field("cursor", "cursor", "is not a valid cursor"))?;
+    let now = Utc::now();
+    let horizon = Duration::days(recurrence::DEFAULT_WINDOW_DAYS);
+    let (from, to) = match time {
+        TimeFilter::Past => {
+            let to = filters.to.unwrap_or(now);
+            (filters.from.unwrap_or(to - horizon), to)
+        }
+        _ => {
+            let from = filters.from.unwrap_or(now);
+            (from, filters.to.unwrap_or(from + horizon))
+        }
+    };
+
+    let sort = filters.sort.unwrap_or(default_sort);
+
+    // Only country/organizer/lifecycle apply to the series itself; dates apply to occurrences
+    let series_filters = EventFilters {
+        country: filters.country.clone(),
+        organizer: filters.organizer.clone(),
+        lifecycle: filters.lifecycle,
+        ..Default::default()
+    };
+    let mut series_query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status = 'approved' AND rrule IS NOT NULL",
+        EVENT_COLUMNS
+    ));
+    db::push_event_filters(&mut series_query, &series_filters, now);
+    series_query
+        .push(" AND event_date <= ")
+        .push_bind(to)
+        .push(" AND (recurrence_end IS NULL OR recurrence_end >= ")
+        .push_bind(from)
+        .push(")");
+    let series: Vec<Event> = series_query.build_query_as().fetch_all(db).await?;
+
+    let ids: Vec<i64> = series.iter().map(|e| e.id).collect();
+    let overrides = db::fetch_occurrence_overrides(db, &ids).await?;
+
+    let mut expanded: Vec<EventOccurrence> = Vec::new();
+    for event in series.into_iter().map(Event::with_parsed_status) {
+        expanded.extend(
+            recurrence::expand(&event, &overrides, from, to)
+                .into_iter()
+                .filter(|o| {
+                    let end = o.event.end_date.unwrap_or(o.event.event_date);
+                    match time {
+                        TimeFilter::Past => end <= now,
+                        TimeFilter::Upcoming => end > now,
+                        TimeFilter::All => true,
+                    }
+                }),
+        );
+    }
+    expanded.sort_by_key(|o| (o.event.event_date, o.event.id));
+    if sort == SortOrder::Desc {
+        expanded.reverse();
+    }
+
+    // One-off events stay in SQL and are paginated there
+    let one_off_filters = EventFilters {
+        time,
+        ..filters.clone()
+    };
+    let one_offs = |select: &str| {
+        let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+            "SELECT {} FROM events WHERE status = 'approved' AND rrule IS NULL",
+            select
+        ));
+        db::push_event_filters(&mut query, &one_off_filters, now);
+        query
+    };
+    let (one_off_total,): (i64,) = one_offs("COUNT(*)").build_query_as().fetch_one(db).await?;
+
+    // An occurrence's place in the merged list is its index among the occurrences plus
+    // the one-off events sorted ahead of it, so find how many occurrences precede the page
+    let (mut before, mut after) = (0, expanded.len());
+    while before < after {
+        let mid = (before + after) / 2;
+        let occurrence = &expanded[mid].event;
+        let ahead = if sort == SortOrder::Desc { ">" } else { "<" };
+        let mut query = one_offs("COUNT(*)");
+        query
+            .push(format!(" AND (event_date {} ", ahead))
+            .push_bind(occurrence.event_date)
+            .push(" OR (event_date = ")
+            .push_bind(occurrence.event_date)
+            .push(format!(" AND id {} ", ahead))
+            .push_bind(occurrence.id)
+            .push("))");
+        let (one_offs_ahead,): (i64,) = query.build_query_as().fetch_one(db).await?;
+        if mid as i64 + one_offs_ahead < offset {
+            before = mid + 1;
+        } else {
+            after = mid;
+        }
+    }
+
+    let expanded_len = expanded.len();
+    let limit = filters.limit.map_or(usize::MAX, |l| l as usize);
+    let mut page_query = one_offs(EVENT_COLUMNS);
+    let sort_sql = sort.as_sql();
+    page_query.push(format!(" ORDER BY event_date {sort_sql}, id {sort_sql}"));
+    // SQLite requires a LIMIT before OFFSET; -1 means no limit
+    page_query
+        .push(" LIMIT ")
+        .push_bind(filters.limit.unwrap_or(-1))
+        .push(" OFFSET ")
+        .push_bind(offset - before as i64);
+    let page: Vec<Event> = page_query.build_query_as().fetch_all(db).await?;
+
+    let mut events: Vec<EventOccurrence> = page
+        .into_iter()
+        .map(|e| {
+            let event = e.with_parsed_status();
+            recurrence::occurrence(&event, event.event_date, None)
+        })
+        .chain(expanded.into_iter().skip(before).take(limit))
+        .collect();
+    events.sort_by_key(|o| (o.event.event_date, o.event.id));
+    if sort == SortOrder::Desc {
+        events.reverse();
+    }
+    events.truncate(limit);
+
+    let total = one_off_total as usize + expanded_len;
+    let next_cursor = filters.next_cursor(offset, events.len(), total as i64);
+    Ok(OccurrencesResponse {
+        events,
+        total,
+        next_cursor,
+    })
+}
 
-    Ok(Json(event.with_parsed_status()))
+/// An approved event, for the public downloads and live endpoints; pending and
+/// rejected submissions are not found
+async fn approved_event(db: &sqlx::SqlitePool, id: i64) -> Result<Event, ApiError> {
+    db::fetch_event(db, id)
+        .await?
+        .filter(|event| event.status == EventStatus::Approved)
+        .ok_or(ApiError::NotFound)
 }
 
-/// GET /api/events/upcoming - List upcoming approved events
+/// GET /api/events/upcoming - List upcoming approved occurrences (recurring events expanded)
 async fn list_upcoming(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<EventsResponse>, StatusCode> {
-    let now = Utc::now();
+    AppQuery(filters): AppQuery<EventFilters>,
+) -> Result<Json<OccurrencesResponse>, ApiError> {
+    query_approved_occurrences(&state.db, &filters, TimeFilter::Upcoming, SortOrder::Asc)
+        .await
+        .map(Json)
+}
 
-    let events: Vec<Event> = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events 
-        WHERE status = 'approved' AND event_date > ?
-        ORDER BY event_date ASC
-        "#,
-    )
-    .bind(now)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .into_iter()
-    .map(|e: Event| e.with_parsed_status())
-    .collect();
+/// GET /api/events/past - List past approved occurrences (recurring events expanded)
+async fn list_past(
+    State(state): State<Arc<AppState>>,
+    AppQuery(filters): AppQuery<EventFilters>,
+) -> Result<Json<OccurrencesResponse>, ApiError> {
+    query_approved_occurrences(&state.db, &filters, TimeFilter::Past, SortOrder::Desc)
+        .await
+        .map(Json)
+}
+
+/// GET /api/events/:id/occurrences - Occurrences of one event within a window (default: the next year)
+async fn list_event_occurrences(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+    AppQuery(window): AppQuery<OccurrenceWindow>,
+) -> Result<Json<OccurrencesResponse>, ApiError> {
+    let event = approved_event(&state.db, id).await?;
+    let from = window.from.unwrap_or_else(Utc::now);
+    let to = window
+        .to
+        .unwrap_or(from + Duration::days(recurrence::DEFAULT_WINDOW_DAYS));
+    if to < from {
+        return Err(ApiError::field("to", "range", "must not be before `from`"));
+    }
 
+    let overrides = db::fetch_occurrence_overrides(&state.db, &[id]).await?;
+    let events = recurrence::expand(&event, &overrides, from, to);
     let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+    Ok(Json(OccurrencesResponse {
+        events,
+        total,
+        next_cursor: None,
+    }))
 }
 
-/// GET /api/events/past - List past approved events
-async fn list_past(State(state): State<Arc<AppState>>) -> Result<Json<EventsResponse>, StatusCode> {
+/// GET /api/events/within - List approved events inside a lat/lng bounding box
+async fn list_within(
+    State(state): State<Arc<AppState>>,
+    AppQuery(params): AppQuery<BoundingBoxQuery>,
+) -> Result<Json<EventsResponse>, ApiError> {
+    params.validate()?;
+    if params.min_lat > params.max_lat {
+        return Err(ApiError::field(
+            "min_lat",
+            "range",
+            "must not be greater than max_lat",
+        ));
+    }
+
+    let bbox = BoundingBox {
+        min_lat: params.min_lat,
+        min_lng: params.min_lng,
+        max_lat: params.max_lat,
+        max_lng: params.max_lng,
+    };
+    let filters = EventFilters {
+        time: params.time,
+        ..Default::default()
+    };
+
     let now = Utc::now();
 
-    let events: Vec<Event> = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events 
-        WHERE status = 'approved' AND event_date <= ?
-        ORDER BY event_date DESC
-        "#,
-    )
-    .bind(now)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .into_iter()
-    .map(|e: Event| e.with_parsed_status())
-    .collect();
+    let mut count_query: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT COUNT(*) FROM events WHERE status = 'approved'");
+    db::push_bbox_filter(&mut count_query, &bbox);
+    db::push_event_filters(&mut count_query, &filters, now);
+    let (total,): (i64,) = count_query.build_query_as().fetch_one(&state.db).await?;
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status = 'approved'",
+        EVENT_COLUMNS
+    ));
+    db::push_bbox_filter(&mut query, &bbox);
+    db::push_event_filters(&mut query, &filters, now);
+    query
+        .push(" ORDER BY event_date ASC, id ASC LIMIT ")
+        .push_bind(params.limit.unwrap_or(-1));
+
+    let events: Vec<Event> = query
+        .build_query_as()
+        .fetch_all(&state.db)
+        .await?
+        .into_iter()
+        .map(|e: Event| e.with_parsed_status())
+        .collect();
+
+    Ok(Json(EventsResponse {
+        events,
+        total: total as usize,
+        next_cursor: None,
+    }))
+}
+
+/// GET /api/events/nearby - List approved events within N km of a point, nearest first
+async fn list_nearby(
+    State(state): State<Arc<AppState>>,
+    AppQuery(params): AppQuery<NearbyQuery>,
+) -> Result<Json<NearbyEventsResponse>, ApiError> {
+    params.validate()?;
+
+    let radius_km = params.radius_km.unwrap_or(DEFAULT_RADIUS_KM);
+    let bbox = BoundingBox::around(params.lat, params.lng, radius_km);
+    let filters = EventFilters {
+        time: params.time,
+        ..Default::default()
+    };
+
+    // Prefilter on the coordinate index, then compute exact distances
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status = 'approved'",
+        EVENT_COLUMNS
+    ));
+    db::push_bbox_filter(&mut query, &bbox);
+    db::push_event_filters(&mut query, &filters, Utc::now());
+
+    let candidates: Vec<Event> = query.build_query_as().fetch_all(&state.db).await?;
 
+    let mut events: Vec<EventWithDistance> = candidates
+        .into_iter()
+        .map(|e: Event| {
+            let distance_km = geo::haversine_km(params.lat, params.lng, e.latitude, e.longitude);
+            EventWithDistance {
+                event: e.with_parsed_status(),
+                distance_km,
+            }
+        })
+        .filter(|e| e.distance_km <= radius_km)
+        .collect();
+    events.sort_by(|a, b| a.distance_km.total_cmp(&b.distance_km));
     let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+    if let Some(limit) = params.limit {
+        events.truncate(limit as usize);
+    }
+
+    Ok(Json(NearbyEventsResponse { events, total }))
 }
 
-/// POST /api/events - Create new event (status: pending)
+/// POST /api/events - Create new event (status: pending), returning a submitter edit token.
+/// Organizer accounts submit linked events, published directly when verified.
 async fn create_event(
     State(state): State<Arc<AppState>>,
-    Json(payload): Json<CreateEventRequest>,
-) -> Result<(StatusCode, Json<Event>), StatusCode> {
-    if payload.validate().is_err() {
-        return Err(StatusCode::BAD_REQUEST);
+    headers: HeaderMap,
+    AppJson(mut payload): AppJson<CreateEventRequest>,
+) -> Result<(StatusCode, Json<CreatedEventResponse>), ApiError> {
+    let principal = auth::authenticate_optional(&state.db, &headers).await?;
+
+    // Accounts managing a single organizer need not name it
+    let organizer_id = match (&principal, payload.organizer_id) {
+        (Some(Principal::Organizer(account)), None) => match account.organizer_ids[..] {
+            [only] => Some(only),
+            _ => return Err(ApiError::field("organizer_id", "required", "is required")),
+        },
+        (None, Some(_)) => return Err(ApiError::Unauthorized),
+        (_, organizer_id) => organizer_id,
+    };
+    let mut publish = false;
+    if let (Some(principal), Some(organizer_id)) = (&principal, organizer_id) {
+        principal.require(Permission::ManageEvents(Some(organizer_id)))?;
+        let organizer = organizers::fetch_organizer(&state.db, organizer_id)
+            .await?
+            .ok_or_else(|| ApiError::field("organizer_id", "exists", "no such organizer"))?;
+        payload.organizer = organizer.name;
+        publish = principal.allows(Permission::Publish(Some(organizer_id)));
     }
 
+    payload.validate()?;
+
+    // Local times are read in the given timezone, else the one at the coordinates
+    let tz = match payload.timezone {
+        Some(ref name) => timezone::parse(name).ok_or_else(|| {
+            ApiError::field("timezone", "timezone", "is not a known IANA timezone")
+        })?,
+        None => timezone::resolve(payload.latitude, payload.longitude),
+    };
+    let start = payload
+        .event_date
+        .resolve(tz)
+        .map_err(ApiError::bad_request)?;
+    let end = payload
+        .end_date
+        .map(|end| end.resolve(tz))
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    let schedule =
+        Schedule::new(start, end, payload.rrule.as_deref(), tz).map_err(ApiError::bad_request)?;
+    let exdates = payload
+        .exdates
+        .iter()
+        .map(|d| d.resolve(tz))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ApiError::bad_request)?;
+    if exdates
+        .iter()
+        .any(|d| schedule.rule.is_none() || !schedule.is_occurrence(*d))
+    {
+        return Err(ApiError::field(
+            "exdates",
+            "occurrence",
+            "must be occurrences of the recurrence rule",
+        ));
+    }
+
+    // The plaintext token goes back to the submitter once; only its hash is kept
+    let edit_token = auth::generate_token();
+
+    let mut tx = state.db.begin().await?;
+
+    let now = Utc::now();
     let result = sqlx::query(
         r#"
-        INSERT INTO events (title, description, organizer, location_name, country, latitude, longitude, event_date, image_url, video_url, event_link, status)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'pending')
+        INSERT INTO events (title, description, organizer, organizer_id, location_name, country, latitude, longitude, event_date, end_date, timezone, rrule, recurrence_end, image_url, video_url, event_link, status, edit_token_hash, approved_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(&payload.title)
     .bind(&payload.description)
     .bind(&payload.organizer)
+    .bind(organizer_id)
     .bind(&payload.location_name)
     .bind(&payload.country)
     .bind(payload.latitude)
     .bind(payload.longitude)
-    .bind(payload.event_date)
+    .bind(schedule.start)
+    .bind(schedule.end)
+    .bind(schedule.tz.name())
+    .bind(schedule.rrule())
+    .bind(db::recurrence_end(&schedule))
     .bind(&payload.image_url)
     .bind(&payload.video_url)
     .bind(&payload.event_link)
-    .execute(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .bind(if publish { "approved" } else { "pending" })
+    .bind(auth::hash_token(&edit_token))
+    .bind(publish.then_some(now))
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
 
     let id = result.last_insert_rowid();
 
+    for exdate in &exdates {
+        sqlx::query(
+            "INSERT OR IGNORE INTO event_occurrence_overrides (event_id, occurrence_start, cancelled, updated_at) VALUES (?, ?, 1, ?)",
+        )
+        .bind(id)
+        .bind(exdate)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+    }
+
     // Fetch the created event
-    let event: Event = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events WHERE id = ?
-        "#,
-    )
-    .bind(id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    // Signed-in submissions are attributable, so they are audited like admin changes
+    if let Some(ref principal) = principal {
+        let actor = principal.audit_actor();
+        if publish {
+            sqlx::query(
+                "INSERT INTO moderation_decisions (event_id, decision, notes, actor_id, actor, created_at) VALUES (?, 'approved', ?, ?, ?, ?)",
+            )
+            .bind(id)
+            .bind("Published without review by a verified account")
+            .bind(actor.id)
+            .bind(&actor.username)
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        audit::record(
+            &mut *tx,
+            AuditRecord {
+                actor: &actor,
+                action: "event.create",
+                entity_type: audit::ENTITY_EVENT,
+                entity_id: id,
+                before: None,
+                after: audit::snapshot(&event),
+                reason: None,
+            },
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    // Duplicate detection is advisory; never fail the submission over it
+    match dedup::flag_duplicates(&state.db, &event).await {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Event {} flagged with {} duplicate candidate(s)", id, n),
+        Err(e) => tracing::warn!("Duplicate detection failed for event {}: {}", id, e),
+    }
+
+    // Likewise for organizer linking; the response shows the event as submitted
+    if organizer_id.is_none() {
+        match organizers::link_event(&state.db, id, &event.organizer).await {
+            Ok(OrganizerMatch::Linked(organizer_id)) => {
+                tracing::info!("Event {} linked to organizer {}", id, organizer_id)
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Organizer linking failed for event {}: {}", id, e),
+        }
+    }
+    publish_change(&state, id, None).await;
 
-    Ok((StatusCode::CREATED, Json(event.with_parsed_status())))
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedEventResponse { event, edit_token }),
+    ))
 }
 
-// ===== Admin Endpoints =====
+/// GET /api/events.geojson - Approved events as a GeoJSON FeatureCollection (accepts list filters)
+pub async fn events_geojson(
+    State(state): State<Arc<AppState>>,
+    AppQuery(filters): AppQuery<EventFilters>,
+) -> Result<impl IntoResponse, ApiError> {
+    let response = query_approved_events(&state.db, &filters, SortOrder::Asc).await?;
+    Ok((
+        [(header::CONTENT_TYPE, geojson::CONTENT_TYPE)],
+        Json(geojson::feature_collection(&response.events)),
+    ))
+}
 
-/// GET /api/admin/events/pending - List pending events (admin only)
-async fn list_pending_events(
+/// GET /api/search?q= - Full-text search over approved events and organizers
+pub async fn search(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-) -> Result<Json<EventsResponse>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    AppQuery(params): AppQuery<SearchQuery>,
+) -> Result<Json<SearchResponse>, ApiError> {
+    params.validate()?;
+    let expression = search::match_expression(&params.q)
+        .ok_or_else(|| ApiError::field("q", "terms", "has no searchable words"))?;
+    let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
 
-    let events: Vec<Event> = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events 
-        WHERE status = 'pending'
-        ORDER BY created_at DESC
-        "#,
+    let events = search::search_events(&state.db, &expression, limit).await?;
+    let organizers = search::search_organizers(&state.db, &expression, limit).await?;
+
+    Ok(Json(SearchResponse { events, organizers }))
+}
+
+// ===== iCalendar Feeds =====
+
+fn calendar_response(body: String) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        body,
     )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .into_iter()
-    .map(|e: Event| e.with_parsed_status())
-    .collect();
+}
 
-    let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+/// Render a calendar, loading occurrence overrides of any recurring events
+async fn render_calendar(
+    db: &sqlx::SqlitePool,
+    name: &str,
+    events: &[Event],
+) -> Result<String, ApiError> {
+    let recurring: Vec<i64> = events
+        .iter()
+        .filter(|e| e.rrule.is_some())
+        .map(|e| e.id)
+        .collect();
+    let overrides = db::fetch_occurrence_overrides(db, &recurring).await?;
+    Ok(ics::calendar(name, events, &overrides))
 }
 
-/// GET /api/admin/events - List ALL events (admin only)
-async fn list_all_events(
+/// GET /api/events/calendar.ics - Feed of all upcoming approved events (accepts list filters)
+async fn upcoming_calendar(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-) -> Result<Json<EventsResponse>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    AppQuery(mut filters): AppQuery<EventFilters>,
+) -> Result<impl IntoResponse, ApiError> {
+    filters.time = TimeFilter::Upcoming;
+    let response = query_approved_events(&state.db, &filters, SortOrder::Asc).await?;
+    render_calendar(&state.db, "DNB RIDE - Upcoming rides", &response.events)
+        .await
+        .map(calendar_response)
+}
 
-    let events: Vec<Event> = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events 
-        ORDER BY event_date DESC
-        "#,
+/// GET /api/events/by-organizer/:slug/calendar.ics - Upcoming events of one organizer
+async fn organizer_calendar(
+    State(state): State<Arc<AppState>>,
+    AppPath(slug): AppPath<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let organizer: (String,) = sqlx::query_as("SELECT name FROM organizers WHERE slug = ?")
+        .bind(&slug)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let filters = EventFilters {
+        organizer: Some(slug),
+        time: TimeFilter::Upcoming,
+        ..Default::default()
+    };
+    let response = query_approved_events(&state.db, &filters, SortOrder::Asc).await?;
+    render_calendar(
+        &state.db,
+        &format!("DNB RIDE - {}", organizer.0),
+        &response.events,
     )
-    .fetch_all(&state.db)
     .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .into_iter()
-    .map(|e: Event| e.with_parsed_status())
-    .collect();
+    .map(calendar_response)
+}
 
-    let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+/// GET /api/events/by-country/:country/calendar.ics - Upcoming events in one country
+async fn country_calendar(
+    State(state): State<Arc<AppState>>,
+    AppPath(country): AppPath<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let name = format!("DNB RIDE - {}", country);
+    let filters = EventFilters {
+        country: Some(country),
+        time: TimeFilter::Upcoming,
+        ..Default::default()
+    };
+    let response = query_approved_events(&state.db, &filters, SortOrder::Asc).await?;
+    render_calendar(&state.db, &name, &response.events)
+        .await
+        .map(calendar_response)
 }
 
-/// PUT /api/admin/events/:id - Update event (admin only)
-async fn update_event(
+/// GET /api/events/:id/calendar.ics - Download a single event
+async fn event_calendar(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-    Path(id): Path<i64>,
-    Json(payload): Json<UpdateEventRequest>,
-) -> Result<Json<Event>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    AppPath(id): AppPath<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    let event = approved_event(&state.db, id).await?;
+    let body = render_calendar(&state.db, &event.title, std::slice::from_ref(&event)).await?;
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/calendar; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"dnb-ride-{}.ics\"", id),
+            ),
+        ],
+        body,
+    ))
+}
 
-    if payload.validate().is_err() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+/// An approved event as it should be shared - the next occurrence of a
+/// recurring ride - with its linked organizer
+async fn shared_event(
+    db: &sqlx::SqlitePool,
+    id: i64,
+) -> Result<(Event, Option<Organizer>), ApiError> {
+    let event: Event = sqlx::query_as(&format!(
+        "SELECT {} FROM events WHERE id = ? AND status = 'approved'",
+        EVENT_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+    let mut event = event.with_parsed_status();
 
-    // Build dynamic update query
-    let mut updates = Vec::new();
-    if payload.title.is_some() {
-        updates.push("title = ?");
-    }
-    if payload.description.is_some() {
-        updates.push("description = ?");
-    }
-    if payload.organizer.is_some() {
-        updates.push("organizer = ?");
-    }
-    if payload.location_name.is_some() {
-        updates.push("location_name = ?");
-    }
-    if payload.country.is_some() {
-        updates.push("country = ?");
-    }
-    if payload.latitude.is_some() {
-        updates.push("latitude = ?");
-    }
-    if payload.longitude.is_some() {
-        updates.push("longitude = ?");
-    }
-    if payload.event_date.is_some() {
-        updates.push("event_date = ?");
-    }
-    if payload.image_url.is_some() {
-        updates.push("image_url = ?");
-    }
-    if payload.video_url.is_some() {
-        updates.push("video_url = ?");
-    }
-    if payload.event_link.is_some() {
-        updates.push("event_link = ?");
-    }
-    if payload.status.is_some() {
-        updates.push("status = ?");
+    if event.rrule.is_some() {
+        let now = Utc::now();
+        let overrides = db::fetch_occurrence_overrides(db, &[id]).await?;
+        let to = now + Duration::days(recurrence::DEFAULT_WINDOW_DAYS);
+        if let Some(next) = recurrence::expand(&event, &overrides, now, to)
+            .into_iter()
+            .find(|occurrence| !occurrence.cancelled)
+        {
+            event = next.event;
+        }
     }
 
-    if updates.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+    let organizer = match event.organizer_id {
+        Some(organizer_id) => organizers::fetch_organizer(db, organizer_id).await?,
+        None => None,
+    };
+    Ok((event, organizer))
+}
 
-    let query = format!("UPDATE events SET {} WHERE id = ?", updates.join(", "));
-    let mut q = sqlx::query(&query);
+/// GET /api/events/:id/event.jsonld - schema.org Event for an approved event
+async fn event_jsonld(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (event, organizer) = shared_event(&state.db, id).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "application/ld+json")],
+        jsonld::event(&event, organizer.as_ref()).to_string(),
+    ))
+}
 
-    if let Some(ref v) = payload.title {
-        q = q.bind(v);
-    }
-    if let Some(ref v) = payload.description {
-        q = q.bind(if v.is_empty() { None::<String> } else { Some(v.clone()) });
-    }
-    if let Some(ref v) = payload.organizer {
-        q = q.bind(v);
-    }
-    if let Some(ref v) = payload.location_name {
-        q = q.bind(v);
-    }
-    if let Some(ref v) = payload.country {
-        q = q.bind(if v.is_empty() { None::<String> } else { Some(v.clone()) });
-    }
-    if let Some(v) = payload.latitude {
-        q = q.bind(v);
-    }
-    if let Some(v) = payload.longitude {
-        q = q.bind(v);
-    }
-    if let Some(v) = payload.event_date {
-        q = q.bind(v);
-    }
-    if let Some(ref v) = payload.image_url {
-        q = q.bind(if v.is_empty() { None::<String> } else { Some(v.clone()) });
-    }
-    if let Some(ref v) = payload.video_url {
-        q = q.bind(if v.is_empty() { None::<String> } else { Some(v.clone()) });
-    }
-    if let Some(ref v) = payload.event_link {
-        q = q.bind(if v.is_empty() { None::<String> } else { Some(v.clone()) });
+/// GET /api/events/:id/share - Share page with OpenGraph/Twitter card tags and JSON-LD
+async fn event_share_page(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Html<String>, ApiError> {
+    let (event, organizer) = shared_event(&state.db, id).await?;
+    Ok(Html(share::page(&event, organizer.as_ref())))
+}
+
+/// Tell the live streams how event `id` changed from `before` (`None` for a
+/// new event). Runs after the change is committed, so failures are only logged.
+async fn publish_change(state: &AppState, id: i64, before: Option<&Event>) {
+    match db::fetch_event(&state.db, id).await {
+        Ok(after) => state.updates.publish(before, after),
+        Err(e) => tracing::warn!("Could not publish change to event {}: {}", id, e),
     }
-    if let Some(ref v) = payload.status {
-        q = q.bind(v);
+}
+
+/// Publish the changes to several events, e.g. after an organizer change
+async fn publish_changes(state: &AppState, before: &[Event]) {
+    for event in before {
+        publish_change(state, event.id, Some(event)).await;
     }
+}
 
-    q = q.bind(id);
-    q.execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+/// Events linked to an organizer, for publishing changes made through it
+async fn linked_events(
+    conn: &mut sqlx::SqliteConnection,
+    organizer_id: i64,
+) -> Result<Vec<Event>, ApiError> {
+    let events: Vec<Event> = sqlx::query_as(&format!(
+        "SELECT {} FROM events WHERE organizer_id = ?",
+        EVENT_COLUMNS
+    ))
+    .bind(organizer_id)
+    .fetch_all(conn)
+    .await?;
+    Ok(events.into_iter().map(Event::with_parsed_status).collect())
+}
 
-    // Fetch updated event
-    let event: Event = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events WHERE id = ?
-        "#,
-    )
-    .bind(id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+/// The `Last-Event-ID` a reconnecting `EventSource` sends
+fn last_event_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+}
 
-    tracing::info!("Event {} updated", id);
-    Ok(Json(event.with_parsed_status()))
+/// GET /api/events/stream - Server-sent events for changes to public events
+async fn event_stream(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
+    state
+        .updates
+        .stream(Audience::Public, last_event_id(&headers))
 }
 
-/// PATCH /api/admin/events/:id/approve - Approve event (admin only)
-async fn approve_event(
+/// GET /api/admin/events/stream - Server-sent events for changes to all events, including submissions
+async fn admin_event_stream(
     State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
     headers: HeaderMap,
-    Path(id): Path<i64>,
-) -> Result<Json<Event>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+) -> impl IntoResponse {
+    state
+        .updates
+        .stream(Audience::Admin, last_event_id(&headers))
+}
 
-    sqlx::query("UPDATE events SET status = 'approved' WHERE id = ?")
-        .bind(id)
-        .execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+const RECENT_FEED_TITLE: &str = "DNB RIDE - New rides";
 
-    let event: Event = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events WHERE id = ?
-        "#,
-    )
-    .bind(id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+/// The most recently approved events matching `filters`, newest approval first
+async fn query_recently_approved(
+    db: &sqlx::SqlitePool,
+    filters: &EventFilters,
+) -> Result<Vec<Event>, ApiError> {
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE status = 'approved' AND approved_at IS NOT NULL",
+        EVENT_COLUMNS
+    ));
+    db::push_event_filters(&mut query, filters, Utc::now());
+    query
+        .push(" ORDER BY approved_at DESC, id DESC LIMIT ")
+        .push_bind(feed::FEED_LIMIT);
 
-    tracing::info!("Event {} approved", id);
-    Ok(Json(event.with_parsed_status()))
+    let events: Vec<Event> = query.build_query_as().fetch_all(db).await?;
+    Ok(events.into_iter().map(Event::with_parsed_status).collect())
 }
 
-/// PATCH /api/admin/events/:id/reject - Reject event (admin only)
-async fn reject_event(
-    State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-    Path(id): Path<i64>,
-) -> Result<Json<Event>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+/// Render a feed, answering conditional GETs with 304 Not Modified
+async fn serve_feed(
+    db: &sqlx::SqlitePool,
+    headers: &HeaderMap,
+    uri: &Uri,
+    format: feed::Format,
+    title: &str,
+    filters: &EventFilters,
+) -> Result<Response, ApiError> {
+    let events = query_recently_approved(db, filters).await?;
+    let self_url = format!("{}{}", share::api_url(), uri.path());
+    let channel = feed::Channel {
+        title,
+        description: "Newly approved drum and bass bike rides",
+        self_url: &self_url,
+    };
+    let body = match format {
+        feed::Format::Rss => feed::rss(&channel, &events),
+        feed::Format::Atom => feed::atom(&channel, &events),
+    };
 
-    sqlx::query("UPDATE events SET status = 'rejected' WHERE id = ?")
-        .bind(id)
-        .execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let etag = feed::etag(&body);
+    let last_modified = feed::last_modified(&events);
+    let mut response_headers = vec![(header::ETAG, etag.clone())];
+    if let Some(modified) = last_modified {
+        response_headers.push((header::LAST_MODIFIED, feed::http_date(modified)));
+    }
+    let response_headers = AppendHeaders(response_headers);
 
-    let event: Event = sqlx::query_as(
-        r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events WHERE id = ?
-        "#,
+    if feed::not_modified(headers, &etag, last_modified) {
+        return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response());
+    }
+    Ok((
+        [(header::CONTENT_TYPE, format.content_type())],
+        response_headers,
+        body,
     )
-    .bind(id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+        .into_response())
+}
 
-    tracing::info!("Event {} rejected", id);
-    Ok(Json(event.with_parsed_status()))
+/// Feed of an organizer's newly approved events
+async fn organizer_feed(
+    state: &AppState,
+    headers: &HeaderMap,
+    uri: &Uri,
+    format: feed::Format,
+    slug: String,
+) -> Result<Response, ApiError> {
+    let organizer: (String,) = sqlx::query_as("SELECT name FROM organizers WHERE slug = ?")
+        .bind(&slug)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let filters = EventFilters {
+        organizer: Some(slug),
+        ..Default::default()
+    };
+    let title = format!("DNB RIDE - New rides by {}", organizer.0);
+    serve_feed(&state.db, headers, uri, format, &title, &filters).await
 }
 
-/// DELETE /api/admin/events/:id - Delete event (admin only)
-async fn delete_event(
+/// Feed of newly approved events in one country
+async fn country_feed(
+    state: &AppState,
+    headers: &HeaderMap,
+    uri: &Uri,
+    format: feed::Format,
+    country: String,
+) -> Result<Response, ApiError> {
+    let title = format!("DNB RIDE - New rides in {}", country);
+    let filters = EventFilters {
+        country: Some(country),
+        ..Default::default()
+    };
+    serve_feed(&state.db, headers, uri, format, &title, &filters).await
+}
+
+/// GET /api/events/feed.rss - RSS feed of newly approved events
+async fn recent_rss(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Path(id): Path<i64>,
-) -> Result<StatusCode, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    uri: OriginalUri,
+) -> Result<Response, ApiError> {
+    let filters = EventFilters::default();
+    serve_feed(
+        &state.db,
+        &headers,
+        &uri,
+        feed::Format::Rss,
+        RECENT_FEED_TITLE,
+        &filters,
+    )
+    .await
+}
 
-    let result = sqlx::query("DELETE FROM events WHERE id = ?")
-        .bind(id)
+/// GET /api/events/feed.atom - Atom feed of newly approved events
+async fn recent_atom(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    uri: OriginalUri,
+) -> Result<Response, ApiError> {
+    let filters = EventFilters::default();
+    serve_feed(
+        &state.db,
+        &headers,
+        &uri,
+        feed::Format::Atom,
+        RECENT_FEED_TITLE,
+        &filters,
+    )
+    .await
+}
+
+/// GET /api/events/by-organizer/:slug/feed.rss - RSS feed of one organizer's new events
+async fn organizer_rss(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    uri: OriginalUri,
+    AppPath(slug): AppPath<String>,
+) -> Result<Response, ApiError> {
+    organizer_feed(&state, &headers, &uri, feed::Format::Rss, slug).await
+}
+
+/// GET /api/events/by-organizer/:slug/feed.atom - Atom feed of one organizer's new events
+async fn organizer_atom(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    uri: OriginalUri,
+    AppPath(slug): AppPath<String>,
+) -> Result<Response, ApiError> {
+    organizer_feed(&state, &headers, &uri, feed::Format::Atom, slug).await
+}
+
+/// GET /api/events/by-country/:country/feed.rss - RSS feed of new events in one country
+async fn country_rss(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    uri: OriginalUri,
+    AppPath(country): AppPath<String>,
+) -> Result<Response, ApiError> {
+    country_feed(&state, &headers, &uri, feed::Format::Rss, country).await
+}
+
+/// GET /api/events/by-country/:country/feed.atom - Atom feed of new events in one country
+async fn country_atom(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    uri: OriginalUri,
+    AppPath(country): AppPath<String>,
+) -> Result<Response, ApiError> {
+    country_feed(&state, &headers, &uri, feed::Format::Atom, country).await
+}
+
+// ===== Admin Endpoints =====
+
+/// POST /api/admin/login - Exchange username/password for a session token
+async fn admin_login(
+    State(state): State<Arc<AppState>>,
+    AppJson(payload): AppJson<LoginRequest>,
+) -> Result<Json<LoginResponse>, ApiError> {
+    payload.validate()?;
+
+    let row: Option<(i64, String)> =
+        sqlx::query_as("SELECT id, password_hash FROM admins WHERE username = ?")
+            .bind(&payload.username)
+            .fetch_optional(&state.db)
+            .await?;
+
+    let admin_id = match row {
+        Some((id, ref hash)) if auth::verify_password(&payload.password, hash) => id,
+        _ => {
+            tracing::warn!("Failed admin login for {}", payload.username);
+            return Err(ApiError::Unauthorized);
+        }
+    };
+
+    let (token, expires_at) = auth::create_session(&state.db, admin_id).await?;
+
+    sqlx::query("UPDATE admins SET last_login_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(admin_id)
         .execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .await?;
+
+    let admin: Admin = sqlx::query_as(&format!(
+        "SELECT {} FROM admins WHERE id = ?",
+        ADMIN_COLUMNS
+    ))
+    .bind(admin_id)
+    .fetch_one(&state.db)
+    .await?;
+
+    tracing::info!("Admin {} logged in", admin.username);
+    Ok(Json(LoginResponse {
+        token,
+        expires_at,
+        admin,
+    }))
+}
+
+/// POST /api/admin/logout - Revoke the current session token
+async fn admin_logout(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let token = auth::bearer_token(&headers).ok_or(ApiError::Unauthorized)?;
+
+    let result = sqlx::query(
+        "UPDATE admin_sessions SET revoked_at = ? WHERE token_hash = ? AND revoked_at IS NULL",
+    )
+    .bind(Utc::now())
+    .bind(auth::hash_token(token))
+    .execute(&state.db)
+    .await?;
 
     if result.rows_affected() == 0 {
-        return Err(StatusCode::NOT_FOUND);
+        return Err(ApiError::Unauthorized);
     }
 
-    tracing::info!("Event {} deleted", id);
     Ok(StatusCode::NO_CONTENT)
 }
 
-// ===== Organizer Endpoints =====
+/// GET /api/admin/me - Identity behind the current credentials
+async fn admin_me(
+    RequireRole { admin, .. }: RequireRole<Viewer>,
+) -> Result<Json<AdminIdentity>, ApiError> {
+    Ok(Json(admin))
+}
 
-/// GET /api/organizers - List all organizers
-async fn list_organizers(
+/// Column list matching the `Admin` model
+const ADMIN_COLUMNS: &str = "id, username, role, created_at, last_login_at";
+
+/// True if no owner other than `id` would remain
+async fn is_last_owner(conn: &mut sqlx::SqliteConnection, id: i64) -> Result<bool, ApiError> {
+    let (others,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM admins WHERE role = 'owner' AND id != ?")
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await?;
+    Ok(others == 0)
+}
+
+/// GET /api/admin/users - List admin accounts and their roles
+async fn list_admins(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<OrganizersResponse>, StatusCode> {
-    let organizers: Vec<Organizer> = sqlx::query_as(
-        r#"
-        SELECT id, name, slug, description, website, created_at
-        FROM organizers
-        ORDER BY name ASC
-        "#,
-    )
+    _: RequireRole<Owner>,
+) -> Result<Json<AdminsResponse>, ApiError> {
+    let admins: Vec<Admin> = sqlx::query_as(&format!(
+        "SELECT {} FROM admins ORDER BY username",
+        ADMIN_COLUMNS
+    ))
     .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .await?;
 
-    let total = organizers.len();
-    Ok(Json(OrganizersResponse { organizers, total }))
+    let total = admins.len();
+    Ok(Json(AdminsResponse { admins, total }))
 }
 
-/// GET /api/organizers/:slug - Get organizer by slug
-async fn get_organizer(
+/// POST /api/admin/users - Create an admin account with a role
+async fn create_admin_user(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppJson(payload): AppJson<CreateAdminRequest>,
+) -> Result<(StatusCode, Json<Admin>), ApiError> {
+    payload.validate()?;
+    auth::check_password(&payload.password)?;
+    let password_hash = auth::hash_password(&payload.password)?;
+
+    let mut tx = state.db.begin().await?;
+
+    let taken: Option<(i64,)> = sqlx::query_as("SELECT id FROM admins WHERE username = ?")
+        .bind(&payload.username)
+        .fetch_optional(&mut *tx)
+        .await?;
+    if taken.is_some() {
+        return Err(ApiError::conflict(
+            "an admin with this username already exists",
+        ));
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO admins (username, password_hash, role, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(&payload.username)
+    .bind(&password_hash)
+    .bind(payload.role.to_string())
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+    let id = result.last_insert_rowid();
+
+    let created: Admin = sqlx::query_as(&format!(
+        "SELECT {} FROM admins WHERE id = ?",
+        ADMIN_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "admin.create",
+            entity_type: audit::ENTITY_ADMIN,
+            entity_id: id,
+            before: None,
+            after: audit::snapshot(&created),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Admin {} created as {} by {}",
+        created.username,
+        created.role,
+        admin.username
+    );
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// PUT /api/admin/users/:id - Change an admin's role or password
+async fn update_admin_user(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateAdminRequest>,
+) -> Result<Json<Admin>, ApiError> {
+    if let Some(ref password) = payload.password {
+        auth::check_password(password)?;
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let before: Admin = sqlx::query_as(&format!(
+        "SELECT {} FROM admins WHERE id = ?",
+        ADMIN_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    if let Some(role) = payload.role {
+        // Someone must always be able to manage accounts
+        if before.role == Role::Owner && role != Role::Owner && is_last_owner(&mut tx, id).await? {
+            return Err(ApiError::conflict("the last owner cannot be demoted"));
+        }
+        sqlx::query("UPDATE admins SET role = ? WHERE id = ?")
+            .bind(role.to_string())
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    if let Some(ref password) = payload.password {
+        let hash = auth::hash_password(password)?;
+        sqlx::query("UPDATE admins SET password_hash = ? WHERE id = ?")
+            .bind(hash)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        // A new password signs out every existing session
+        sqlx::query(
+            "UPDATE admin_sessions SET revoked_at = ? WHERE admin_id = ? AND revoked_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let updated: Admin = sqlx::query_as(&format!(
+        "SELECT {} FROM admins WHERE id = ?",
+        ADMIN_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "admin.update",
+            entity_type: audit::ENTITY_ADMIN,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&updated),
+            reason: payload
+                .password
+                .is_some()
+                .then(|| "password reset".to_string()),
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Admin {} updated by {}", updated.username, admin.username);
+    Ok(Json(updated))
+}
+
+/// DELETE /api/admin/users/:id - Delete an admin account and its sessions
+async fn delete_admin_user(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before: Admin = sqlx::query_as(&format!(
+        "SELECT {} FROM admins WHERE id = ?",
+        ADMIN_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    if before.role == Role::Owner && is_last_owner(&mut tx, id).await? {
+        return Err(ApiError::conflict("the last owner cannot be deleted"));
+    }
+
+    sqlx::query("DELETE FROM admins WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "admin.delete",
+            entity_type: audit::ENTITY_ADMIN,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Admin {} deleted by {}", before.username, admin.username);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Pair each event with its most recent moderation decision and duplicate candidates
+async fn with_moderation_context(
+    db: &sqlx::SqlitePool,
+    events: Vec<Event>,
+) -> Result<Vec<AdminEvent>, ApiError> {
+    if events.is_empty() {
+        return Ok(Vec::new());
+    }
+    let ids: Vec<i64> = events.iter().map(|e| e.id).collect();
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+        r#"
+        SELECT id, event_id, decision, reason, notes, actor, created_at, merged_from
+        FROM moderation_decisions
+        WHERE id IN (SELECT MAX(id) FROM moderation_decisions WHERE merged_from IS NULL AND event_id IN ("#,
+    );
+    push_ids(&mut query, &ids);
+    query.push(") GROUP BY event_id)");
+    let decisions: Vec<ModerationDecision> = query.build_query_as().fetch_all(db).await?;
+
+    let mut latest: HashMap<i64, ModerationDecision> =
+        decisions.into_iter().map(|d| (d.event_id, d)).collect();
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+        r#"
+        SELECT d.event_id, d.candidate_id, d.score, e.title, e.organizer, e.location_name,
+               e.event_date, e.status, '/api/events/' || e.id AS link
+        FROM event_duplicate_candidates d
+        JOIN events e ON e.id = d.candidate_id
+        WHERE d.event_id IN ("#,
+    );
+    push_ids(&mut query, &ids);
+    query.push(") ORDER BY d.score DESC");
+    let candidates: Vec<DuplicateCandidate> = query.build_query_as().fetch_all(db).await?;
+
+    let mut duplicates: HashMap<i64, Vec<DuplicateCandidate>> = HashMap::new();
+    for candidate in candidates {
+        duplicates
+            .entry(candidate.event_id)
+            .or_default()
+            .push(candidate);
+    }
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+        r#"
+        SELECT s.event_id, s.organizer_id, o.name, o.slug, s.score
+        FROM event_organizer_suggestions s
+        JOIN organizers o ON o.id = s.organizer_id
+        WHERE s.event_id IN ("#,
+    );
+    push_ids(&mut query, &ids);
+    query.push(") ORDER BY s.score DESC");
+    let suggestions: Vec<OrganizerSuggestion> = query.build_query_as().fetch_all(db).await?;
+
+    let mut organizer_suggestions: HashMap<i64, Vec<OrganizerSuggestion>> = HashMap::new();
+    for suggestion in suggestions {
+        organizer_suggestions
+            .entry(suggestion.event_id)
+            .or_default()
+            .push(suggestion);
+    }
+
+    Ok(events
+        .into_iter()
+        .map(|event| AdminEvent {
+            latest_decision: latest.remove(&event.id),
+            duplicate_candidates: duplicates.remove(&event.id).unwrap_or_default(),
+            organizer_suggestions: organizer_suggestions.remove(&event.id).unwrap_or_default(),
+            event,
+        })
+        .collect())
+}
+
+/// Append `ids` as a comma-separated list of binds, for an `IN (...)` clause
+fn push_ids(query: &mut QueryBuilder<'_, Sqlite>, ids: &[i64]) {
+    let mut list = query.separated(", ");
+    for id in ids {
+        list.push_bind(*id);
+    }
+}
+
+/// GET /api/admin/events/:id/moderation - Moderation history of an event
+async fn list_moderation_history(
     State(state): State<Arc<AppState>>,
-    Path(slug): Path<String>,
-) -> Result<Json<Organizer>, StatusCode> {
-    let organizer: Organizer = sqlx::query_as(
+    _: RequireRole<Viewer>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<ModerationHistoryResponse>, ApiError> {
+    let decisions: Vec<ModerationDecision> = sqlx::query_as(
         r#"
-        SELECT id, name, slug, description, website, created_at
-        FROM organizers
-        WHERE slug = ?
+        SELECT id, event_id, decision, reason, notes, actor, created_at, merged_from
+        FROM moderation_decisions
+        WHERE event_id = ?
+        ORDER BY id ASC
         "#,
     )
-    .bind(&slug)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+    .bind(id)
+    .fetch_all(&state.db)
+    .await?;
 
-    Ok(Json(organizer))
+    let total = decisions.len();
+    Ok(Json(ModerationHistoryResponse { decisions, total }))
 }
 
-/// GET /api/events/by-organizer/:slug - List approved events by organizer slug
-async fn list_events_by_organizer(
+/// GET /api/admin/events/pending - List pending events (admin only)
+async fn list_pending_events(
     State(state): State<Arc<AppState>>,
-    Path(slug): Path<String>,
-) -> Result<Json<EventsResponse>, StatusCode> {
-    // First get organizer ID from slug
-    let organizer: Option<(i64,)> = sqlx::query_as("SELECT id FROM organizers WHERE slug = ?")
-        .bind(&slug)
-        .fetch_optional(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    _: RequireRole<Viewer>,
+) -> Result<Json<AdminEventsResponse>, ApiError> {
+    let events: Vec<Event> = sqlx::query_as(&format!(
+        r#"
+        SELECT {}
+        FROM events 
+        WHERE status = 'pending'
+        ORDER BY created_at DESC
+        "#,
+        EVENT_COLUMNS
+    ))
+    .fetch_all(&state.db)
+    .await?
+    .into_iter()
+    .map(|e: Event| e.with_parsed_status())
+    .collect();
 
-    let organizer_id = organizer.ok_or(StatusCode::NOT_FOUND)?.0;
+    let events = with_moderation_context(&state.db, events).await?;
+    let total = events.len();
+    Ok(Json(AdminEventsResponse { events, total }))
+}
 
-    let events: Vec<Event> = sqlx::query_as(
+/// GET /api/admin/events - List ALL events (admin only)
+async fn list_all_events(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+) -> Result<Json<AdminEventsResponse>, ApiError> {
+    let events: Vec<Event> = sqlx::query_as(&format!(
         r#"
-        SELECT id, title, description, organizer, organizer_id, location_name, country, event_link,
-               latitude, longitude, event_date, image_url, video_url, status, created_at
-        FROM events
-        WHERE status = 'approved' AND organizer_id = ?
+        SELECT {}
+        FROM events 
         ORDER BY event_date DESC
         "#,
-    )
-    .bind(organizer_id)
+        EVENT_COLUMNS
+    ))
     .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .await?
     .into_iter()
     .map(|e: Event| e.with_parsed_status())
     .collect();
 
+    let events = with_moderation_context(&state.db, events).await?;
     let total = events.len();
-    Ok(Json(EventsResponse { events, total }))
+    Ok(Json(AdminEventsResponse { events, total }))
 }
 
-/// POST /api/suggestions/video - Submit a video suggestion
-async fn create_suggestion(
+/// PUT /api/admin/events/:id - Update event (admin only)
+async fn update_event(
     State(state): State<Arc<AppState>>,
-    Json(payload): Json<CreateSuggestionRequest>,
-) -> Result<StatusCode, StatusCode> {
-    if payload.validate().is_err() {
-        return Err(StatusCode::BAD_REQUEST);
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateEventRequest>,
+) -> Result<Json<Event>, ApiError> {
+    payload.validate()?;
+    if !payload.has_changes() {
+        return Err(ApiError::bad_request("no changes given"));
     }
 
-    sqlx::query("INSERT INTO video_suggestions (event_id, video_url) VALUES (?, ?)")
-        .bind(payload.event_id)
-        .bind(&payload.video_url)
-        .execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let mut tx = state.db.begin().await?;
 
-    Ok(StatusCode::CREATED)
-}
+    let before = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
 
-/// GET /api/admin/suggestions - List pending suggestions
-async fn list_suggestions(
-    State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-) -> Result<Json<SuggestionsResponse>, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    let schedule = Schedule::updated(&before, &payload).map_err(ApiError::bad_request)?;
+    db::apply_event_update(&mut *tx, id, &payload, &schedule, None).await?;
 
-    let suggestions: Vec<VideoSuggestion> = sqlx::query_as(
-        r#"
-        SELECT vs.id, vs.event_id, vs.video_url, vs.status, vs.created_at, IFNULL(e.title, 'Unknown Event') as event_title
-        FROM video_suggestions vs
-        LEFT JOIN events e ON vs.event_id = e.id
-        WHERE vs.status = 'pending'
-        ORDER BY vs.created_at DESC
-        "#
+    // Fetch updated event
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.update",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&event),
+            reason: None,
+        },
     )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .await?;
 
-    Ok(Json(SuggestionsResponse {
-        total: suggestions.len(),
-        suggestions,
-    }))
+    tx.commit().await?;
+    state.updates.publish(Some(&before), Some(event.clone()));
+
+    tracing::info!("Event {} updated by {}", id, admin.username);
+    Ok(Json(event))
 }
 
-/// PATCH /api/admin/suggestions/:id/approve - Approve suggestion
-async fn approve_suggestion(
-    State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-    Path(id): Path<i64>,
-) -> Result<StatusCode, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+/// Change an event's moderation status, recording the decision and an audit entry
+async fn set_event_status(
+    state: &AppState,
+    admin: &AdminIdentity,
+    id: i64,
+    status: &str,
+    action: &str,
+    moderation: &ModerationRequest,
+) -> Result<Event, ApiError> {
+    let mut tx = state.db.begin().await?;
 
-    // Begin transaction
-    let mut tx = state
-        .db
-        .begin()
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
 
-    // Get suggestion details
-    let suggestion: VideoSuggestion = sqlx::query_as(
-        "SELECT id, event_id, video_url, status, created_at, '' as event_title FROM video_suggestions WHERE id = ?"
-    )
+    let now = Utc::now();
+    sqlx::query(&format!(
+        "UPDATE events SET {}, status = ?, updated_at = ? WHERE id = ?",
+        db::APPROVED_AT_SQL
+    ))
+    .bind(status)
+    .bind(now)
+    .bind(status)
+    .bind(now)
     .bind(id)
-    .fetch_optional(&mut *tx)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-    .ok_or(StatusCode::NOT_FOUND)?;
+    .execute(&mut *tx)
+    .await?;
 
-    // Update event video_url
-    sqlx::query("UPDATE events SET video_url = ? WHERE id = ?")
-        .bind(&suggestion.video_url)
-        .bind(suggestion.event_id)
-        .execute(&mut *tx)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
 
-    // Update suggestion status
-    sqlx::query("UPDATE video_suggestions SET status = 'approved' WHERE id = ?")
-        .bind(id)
-        .execute(&mut *tx)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let reason = moderation.reason.map(|r| r.to_string());
+    let notes = moderation.notes.clone().filter(|n| !n.trim().is_empty());
+    sqlx::query(
+        "INSERT INTO moderation_decisions (event_id, decision, reason, notes, actor_id, actor, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(status)
+    .bind(&reason)
+    .bind(&notes)
+    .bind(admin.id)
+    .bind(&admin.username)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
 
-    tx.commit()
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: admin,
+            action,
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&event),
+            // Keep the moderator's notes next to the reason code
+            reason: match (reason, notes) {
+                (Some(reason), Some(notes)) => Some(format!("{}: {}", reason, notes)),
+                (reason, notes) => reason.or(notes),
+            },
+        },
+    )
+    .await?;
 
-    Ok(StatusCode::OK)
+    tx.commit().await?;
+    state.updates.publish(Some(&before), Some(event.clone()));
+
+    Ok(event)
 }
 
-/// PATCH /api/admin/suggestions/:id/reject - Reject suggestion
-async fn reject_suggestion(
+/// PATCH /api/admin/events/:id/approve - Approve event, optionally with notes (admin only)
+async fn approve_event(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-    Path(id): Path<i64>,
-) -> Result<StatusCode, StatusCode> {
-    if !check_admin_auth(&headers) {
-        return Err(StatusCode::UNAUTHORIZED);
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+    AppJsonOrEmpty(moderation): AppJsonOrEmpty<ModerationRequest>,
+) -> Result<Json<Event>, ApiError> {
+    moderation.validate()?;
+    if moderation.reason.is_some() {
+        // Reason codes describe rejections only
+        return Err(ApiError::field(
+            "reason",
+            "rejection_only",
+            "only applies to rejections",
+        ));
     }
 
-    sqlx::query("UPDATE video_suggestions SET status = 'rejected' WHERE id = ?")
-        .bind(id)
-        .execute(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let event =
+        set_event_status(&state, &admin, id, "approved", "event.approve", &moderation).await?;
 
-    Ok(StatusCode::OK)
+    tracing::info!("Event {} approved by {}", id, admin.username);
+    Ok(Json(event))
 }
 
-/// Public router for suggestions
-pub fn suggestions_router() -> Router<Arc<AppState>> {
-    Router::new().route("/video", post(create_suggestion))
+/// PATCH /api/admin/events/:id/reject - Reject event with optional reason code and notes (admin only)
+async fn reject_event(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+    AppJsonOrEmpty(moderation): AppJsonOrEmpty<ModerationRequest>,
+) -> Result<Json<Event>, ApiError> {
+    moderation.validate()?;
+
+    let event =
+        set_event_status(&state, &admin, id, "rejected", "event.reject", &moderation).await?;
+
+    tracing::info!("Event {} rejected by {}", id, admin.username);
+    Ok(Json(event))
 }
 
-/// Admin router for suggestions
-pub fn admin_suggestions_router() -> Router<Arc<AppState>> {
-    Router::new()
-        .route("/", get(list_suggestions))
-        .route("/:id/approve", patch(approve_suggestion))
-        .route("/:id/reject", patch(reject_suggestion))
+/// PATCH /api/admin/events/:id/lifecycle - Mark an event cancelled, postponed, completed or scheduled again
+async fn set_event_lifecycle(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<LifecycleRequest>,
+) -> Result<Json<Event>, ApiError> {
+    payload.validate()?;
+    if payload.postponed_to.is_some() && payload.state != EventLifecycle::Postponed {
+        return Err(ApiError::field(
+            "postponed_to",
+            "postponed_only",
+            "only applies to postponed events",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let before = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if payload
+        .postponed_to
+        .is_some_and(|date| date <= before.event_date)
+    {
+        return Err(ApiError::field(
+            "postponed_to",
+            "range",
+            "must be after the current event date",
+        ));
+    }
+    if !before.lifecycle.can_transition_to(payload.state)
+        || (payload.state == EventLifecycle::Completed && before.event_date > Utc::now())
+    {
+        return Err(ApiError::conflict(format!(
+            "cannot change a {} event to {}",
+            before.lifecycle, payload.state
+        )));
+    }
+
+    sqlx::query("UPDATE events SET lifecycle = ?, postponed_to = ?, updated_at = ? WHERE id = ?")
+        .bind(payload.state.to_string())
+        .bind(payload.postponed_to)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let note = payload.note.clone().filter(|n| !n.trim().is_empty());
+    sqlx::query(
+        "INSERT INTO event_lifecycle_changes (event_id, from_state, to_state, postponed_to, note, actor_id, actor, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(before.lifecycle.to_string())
+    .bind(payload.state.to_string())
+    .bind(payload.postponed_to)
+    .bind(&note)
+    .bind(admin.id)
+    .bind(&admin.username)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.lifecycle",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&event),
+            reason: note,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&before), Some(event.clone()));
+
+    tracing::info!(
+        "Event {} marked {} by {}",
+        id,
+        payload.state,
+        admin.username
+    );
+    Ok(Json(event))
+}
+
+/// GET /api/admin/events/:id/lifecycle - Lifecycle history of an event
+async fn list_lifecycle_history(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<LifecycleHistoryResponse>, ApiError> {
+    let changes: Vec<LifecycleChange> = sqlx::query_as(
+        r#"
+        SELECT id, event_id, from_state, to_state, postponed_to, note, actor, created_at, merged_from
+        FROM event_lifecycle_changes
+        WHERE event_id = ?
+        ORDER BY id ASC
+        "#,
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await?;
+
+    let total = changes.len();
+    Ok(Json(LifecycleHistoryResponse { changes, total }))
+}
+
+/// DELETE /api/admin/events/:id - Delete event (admin only)
+async fn delete_event(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+    AppQuery(params): AppQuery<DeleteEventQuery>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // Suggestions predate the cascading foreign keys
+    sqlx::query("DELETE FROM video_suggestions WHERE event_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM events WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.delete",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: params.reason,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&before), None);
+
+    tracing::info!("Event {} deleted by {}", id, admin.username);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/admin/events/:id/merge - Merge a duplicate event into another, carrying over its suggestions and history
+async fn merge_event(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<MergeEventRequest>,
+) -> Result<Json<Event>, ApiError> {
+    if payload.into == id {
+        return Err(ApiError::field(
+            "into",
+            "self",
+            "cannot merge an event into itself",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let duplicate = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let survivor = db::fetch_event(&mut *tx, payload.into)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // Its recorded tracks move to the survivor, but a running session can't
+    let (live,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM live_sessions WHERE event_id = ? AND ended_at IS NULL",
+    )
+    .bind(duplicate.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if live > 0 {
+        return Err(ApiError::conflict(
+            "the duplicate is being tracked live; end its session before merging",
+        ));
+    }
+
+    sqlx::query("UPDATE video_suggestions SET event_id = ? WHERE event_id = ?")
+        .bind(survivor.id)
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Fill gaps in the survivor from the duplicate; existing values always win
+    sqlx::query(
+        r#"
+        UPDATE events SET
+            description = COALESCE(description, ?),
+            organizer_id = COALESCE(organizer_id, ?),
+            country = COALESCE(country, ?),
+            image_url = COALESCE(image_url, ?),
+            video_url = COALESCE(video_url, ?),
+            event_link = COALESCE(event_link, ?),
+            updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&duplicate.description)
+    .bind(duplicate.organizer_id)
+    .bind(&duplicate.country)
+    .bind(&duplicate.image_url)
+    .bind(&duplicate.video_url)
+    .bind(&duplicate.event_link)
+    .bind(Utc::now())
+    .bind(survivor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    // Likewise the planned route, unless the survivor has its own
+    sqlx::query("UPDATE OR IGNORE event_routes SET event_id = ? WHERE event_id = ?")
+        .bind(survivor.id)
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Keep the duplicate's history instead of letting it cascade away. Its open
+    // revisions were written against the duplicate, so they don't carry over.
+    sqlx::query(
+        "UPDATE event_revisions SET status = 'superseded' WHERE event_id = ? AND status = 'pending'",
+    )
+    .bind(duplicate.id)
+    .execute(&mut *tx)
+    .await?;
+    for table in ["moderation_decisions", "event_lifecycle_changes"] {
+        sqlx::query(&format!(
+            "UPDATE {} SET event_id = ?, merged_from = COALESCE(merged_from, event_id) WHERE event_id = ?",
+            table
+        ))
+        .bind(survivor.id)
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+    }
+    for table in ["event_revisions", "live_sessions"] {
+        sqlx::query(&format!(
+            "UPDATE {} SET event_id = ? WHERE event_id = ?",
+            table
+        ))
+        .bind(survivor.id)
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query("DELETE FROM events WHERE id = ?")
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let merged = db::fetch_event(&mut *tx, survivor.id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.merge",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: duplicate.id,
+            before: audit::snapshot(&duplicate),
+            after: None,
+            reason: Some(match payload.reason {
+                Some(reason) => format!("merged into event {}: {}", survivor.id, reason),
+                None => format!("merged into event {}", survivor.id),
+            }),
+        },
+    )
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.update",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: survivor.id,
+            before: audit::snapshot(&survivor),
+            after: audit::snapshot(&merged),
+            reason: Some(format!("absorbed duplicate event {}", duplicate.id)),
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&duplicate), None);
+    state.updates.publish(Some(&survivor), Some(merged.clone()));
+
+    tracing::info!(
+        "Event {} merged into {} by {}",
+        duplicate.id,
+        survivor.id,
+        admin.username
+    );
+    Ok(Json(merged))
+}
+
+/// Load one occurrence override
+async fn fetch_occurrence_override<'e, E>(
+    executor: E,
+    event_id: i64,
+    start: DateTime<Utc>,
+) -> Result<Option<OccurrenceOverride>, ApiError>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    sqlx::query_as(
+        r#"
+        SELECT event_id, occurrence_start, cancelled, start_date, end_date, title, description,
+               location_name, latitude, longitude, updated_at
+        FROM event_occurrence_overrides
+        WHERE event_id = ? AND occurrence_start = ?
+        "#,
+    )
+    .bind(event_id)
+    .bind(start)
+    .fetch_optional(executor)
+    .await
+    .map_err(ApiError::from)
+}
+
+/// PUT /api/admin/events/:id/occurrences/:start - Cancel or modify one occurrence of a recurring event
+async fn override_occurrence(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath((id, start)): AppPath<(i64, DateTime<Utc>)>,
+    AppJson(payload): AppJson<OccurrenceOverrideRequest>,
+) -> Result<Json<OccurrenceOverride>, ApiError> {
+    payload.validate()?;
+    if payload.latitude.is_some() != payload.longitude.is_some() {
+        return Err(ApiError::bad_request(
+            "latitude and longitude must be given together",
+        ));
+    }
+    if payload
+        .end_date
+        .is_some_and(|end| end <= payload.start_date.unwrap_or(start))
+    {
+        return Err(ApiError::field(
+            "end_date",
+            "range",
+            "must be after the start",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let schedule = Schedule::of(&event);
+    if schedule.rule.is_none() || !schedule.is_occurrence(start) {
+        return Err(ApiError::NotFound);
+    }
+
+    let before = fetch_occurrence_override(&mut *tx, id, start).await?;
+
+    sqlx::query(
+        r#"
+        INSERT OR REPLACE INTO event_occurrence_overrides
+            (event_id, occurrence_start, cancelled, start_date, end_date, title, description, location_name, latitude, longitude, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id)
+    .bind(start)
+    .bind(payload.cancelled)
+    .bind(payload.start_date)
+    .bind(payload.end_date)
+    .bind(&payload.title)
+    .bind(&payload.description)
+    .bind(&payload.location_name)
+    .bind(payload.latitude)
+    .bind(payload.longitude)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+
+    let after = fetch_occurrence_override(&mut *tx, id, start)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: if payload.cancelled {
+                "event.occurrence_cancel"
+            } else {
+                "event.occurrence_update"
+            },
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: before.as_ref().and_then(audit::snapshot),
+            after: audit::snapshot(&after),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    // The series changed even though its row didn't
+    state.updates.publish(Some(&event), Some(event.clone()));
+
+    tracing::info!(
+        "Occurrence {} of event {} overridden by {}",
+        start,
+        id,
+        admin.username
+    );
+    Ok(Json(after))
+}
+
+/// DELETE /api/admin/events/:id/occurrences/:start - Restore an occurrence to the series defaults
+async fn restore_occurrence(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath((id, start)): AppPath<(i64, DateTime<Utc>)>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let before = fetch_occurrence_override(&mut *tx, id, start)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    sqlx::query(
+        "DELETE FROM event_occurrence_overrides WHERE event_id = ? AND occurrence_start = ?",
+    )
+    .bind(id)
+    .bind(start)
+    .execute(&mut *tx)
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.occurrence_restore",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&event), Some(event.clone()));
+
+    tracing::info!(
+        "Occurrence {} of event {} restored by {}",
+        start,
+        id,
+        admin.username
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/admin/audit - Query the audit log by entity, actor, action and time range
+async fn list_audit_log(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+    AppQuery(params): AppQuery<AuditLogQuery>,
+) -> Result<Json<AuditLogResponse>, ApiError> {
+    params.validate()?;
+
+    let mut count_query: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT COUNT(*) FROM audit_log WHERE 1 = 1");
+    db::push_audit_filters(&mut count_query, &params);
+    let (total,): (i64,) = count_query.build_query_as().fetch_one(&state.db).await?;
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(
+        r#"
+        SELECT id, actor_id, actor, action, entity_type, entity_id, before_json, after_json, diff_json, reason, created_at
+        FROM audit_log WHERE 1 = 1
+        "#,
+    );
+    db::push_audit_filters(&mut query, &params);
+    query
+        .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+        .push_bind(params.limit.unwrap_or(100))
+        .push(" OFFSET ")
+        .push_bind(params.offset.unwrap_or(0));
+
+    let entries: Vec<AuditLogEntry> = query.build_query_as().fetch_all(&state.db).await?;
+
+    Ok(Json(AuditLogResponse {
+        entries,
+        total: total as usize,
+    }))
+}
+
+// ===== Organizer Endpoints =====
+
+/// GET /api/organizers - List all organizers
+async fn list_organizers(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<OrganizersResponse>, ApiError> {
+    let organizers: Vec<Organizer> = sqlx::query_as(&format!(
+        "SELECT {} FROM organizers ORDER BY name ASC",
+        organizers::ORGANIZER_COLUMNS
+    ))
+    .fetch_all(&state.db)
+    .await?;
+
+    let total = organizers.len();
+    Ok(Json(OrganizersResponse { organizers, total }))
+}
+
+/// GET /api/organizers/:slug - Get organizer profile and ride stats by slug
+async fn get_organizer(
+    State(state): State<Arc<AppState>>,
+    AppPath(slug): AppPath<String>,
+) -> Result<Json<OrganizerProfile>, ApiError> {
+    let organizer: Organizer = sqlx::query_as(&format!(
+        "SELECT {} FROM organizers WHERE slug = ?",
+        organizers::ORGANIZER_COLUMNS
+    ))
+    .bind(&slug)
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    let stats = organizers::stats(&state.db, organizer.id, Utc::now()).await?;
+
+    Ok(Json(OrganizerProfile { organizer, stats }))
+}
+
+/// GET /api/events/by-organizer/:slug - List approved events by organizer slug
+async fn list_events_by_organizer(
+    State(state): State<Arc<AppState>>,
+    AppPath(slug): AppPath<String>,
+    AppQuery(mut filters): AppQuery<EventFilters>,
+) -> Result<Json<EventsResponse>, ApiError> {
+    // 404 for unknown organizers rather than an empty list
+    let organizer: Option<(i64,)> = sqlx::query_as("SELECT id FROM organizers WHERE slug = ?")
+        .bind(&slug)
+        .fetch_optional(&state.db)
+        .await?;
+    organizer.ok_or(ApiError::NotFound)?;
+
+    filters.organizer = Some(slug);
+    query_approved_events(&state.db, &filters, SortOrder::Desc)
+        .await
+        .map(Json)
+}
+
+/// POST /api/suggestions/video - Submit a video suggestion
+async fn create_suggestion(
+    State(state): State<Arc<AppState>>,
+    AppJson(payload): AppJson<CreateSuggestionRequest>,
+) -> Result<StatusCode, ApiError> {
+    payload.validate()?;
+
+    sqlx::query("INSERT INTO video_suggestions (event_id, video_url) VALUES (?, ?)")
+        .bind(payload.event_id)
+        .bind(&payload.video_url)
+        .execute(&state.db)
+        .await?;
+
+    Ok(StatusCode::CREATED)
+}
+
+/// GET /api/admin/suggestions - List pending suggestions
+async fn list_suggestions(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+) -> Result<Json<SuggestionsResponse>, ApiError> {
+    let suggestions: Vec<VideoSuggestion> = sqlx::query_as(
+        r#"
+        SELECT vs.id, vs.event_id, vs.video_url, vs.status, vs.created_at, IFNULL(e.title, 'Unknown Event') as event_title
+        FROM video_suggestions vs
+        LEFT JOIN events e ON vs.event_id = e.id
+        WHERE vs.status = 'pending'
+        ORDER BY vs.created_at DESC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(SuggestionsResponse {
+        total: suggestions.len(),
+        suggestions,
+    }))
+}
+
+/// PATCH /api/admin/suggestions/:id/approve - Approve suggestion
+async fn approve_suggestion(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    // Begin transaction
+    let mut tx = state.db.begin().await?;
+
+    // Get suggestion details
+    let suggestion: VideoSuggestion = sqlx::query_as(
+        "SELECT id, event_id, video_url, status, created_at, '' as event_title FROM video_suggestions WHERE id = ?"
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    let event_before = db::fetch_event(&mut *tx, suggestion.event_id).await?;
+
+    // Update event video_url
+    sqlx::query("UPDATE events SET video_url = ?, updated_at = ? WHERE id = ?")
+        .bind(&suggestion.video_url)
+        .bind(Utc::now())
+        .bind(suggestion.event_id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Update suggestion status
+    sqlx::query("UPDATE video_suggestions SET status = 'approved' WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    let approved = VideoSuggestion {
+        status: "approved".to_string(),
+        ..suggestion.clone()
+    };
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "suggestion.approve",
+            entity_type: audit::ENTITY_SUGGESTION,
+            entity_id: id,
+            before: audit::snapshot(&suggestion),
+            after: audit::snapshot(&approved),
+            reason: None,
+        },
+    )
+    .await?;
+
+    // The event's video_url changed too, so record it against the event as well
+    if let Some(ref event_before) = event_before {
+        let event_after = db::fetch_event(&mut *tx, suggestion.event_id).await?;
+        audit::record(
+            &mut *tx,
+            AuditRecord {
+                actor: &admin,
+                action: "event.update",
+                entity_type: audit::ENTITY_EVENT,
+                entity_id: suggestion.event_id,
+                before: audit::snapshot(&event_before),
+                after: event_after.as_ref().and_then(audit::snapshot),
+                reason: Some(format!("Video suggestion {} approved", id)),
+            },
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    if let Some(ref event_before) = event_before {
+        publish_change(&state, suggestion.event_id, Some(event_before)).await;
+    }
+
+    tracing::info!("Suggestion {} approved by {}", id, admin.username);
+    Ok(StatusCode::OK)
+}
+
+/// PATCH /api/admin/suggestions/:id/reject - Reject suggestion
+async fn reject_suggestion(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let suggestion: VideoSuggestion = sqlx::query_as(
+        "SELECT id, event_id, video_url, status, created_at, '' as event_title FROM video_suggestions WHERE id = ?"
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    sqlx::query("UPDATE video_suggestions SET status = 'rejected' WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    let rejected = VideoSuggestion {
+        status: "rejected".to_string(),
+        ..suggestion.clone()
+    };
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "suggestion.reject",
+            entity_type: audit::ENTITY_SUGGESTION,
+            entity_id: id,
+            before: audit::snapshot(&suggestion),
+            after: audit::snapshot(&rejected),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Suggestion {} rejected by {}", id, admin.username);
+    Ok(StatusCode::OK)
+}
+
+// ===== Organizer Admin Endpoints =====
+
+/// Social profile links must point at their own network (empty strings clear them)
+fn check_social_links(links: &[(&'static str, &Option<String>, &[&str])]) -> Result<(), ApiError> {
+    let mut errors = validator::ValidationErrors::new();
+    for (field, url, hosts) in links {
+        if url
+            .as_deref()
+            .is_some_and(|u| !u.is_empty() && !organizers::is_profile_link(u, hosts))
+        {
+            errors.add(
+                field,
+                validator::ValidationError::new("profile_link")
+                    .with_message("must link to a profile on this network".into()),
+            );
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into())
+    }
+}
+
+/// POST /api/admin/organizers - Create an organizer (slug generated from the name if absent)
+async fn create_organizer(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppJson(payload): AppJson<CreateOrganizerRequest>,
+) -> Result<(StatusCode, Json<Organizer>), ApiError> {
+    payload.validate()?;
+    check_social_links(&[
+        (
+            "instagram_url",
+            &payload.instagram_url,
+            organizers::INSTAGRAM_HOSTS,
+        ),
+        (
+            "facebook_url",
+            &payload.facebook_url,
+            organizers::FACEBOOK_HOSTS,
+        ),
+        (
+            "youtube_url",
+            &payload.youtube_url,
+            organizers::YOUTUBE_HOSTS,
+        ),
+        (
+            "soundcloud_url",
+            &payload.soundcloud_url,
+            organizers::SOUNDCLOUD_HOSTS,
+        ),
+    ])?;
+    let name = payload.name.trim();
+
+    let mut tx = state.db.begin().await?;
+
+    if organizers::name_taken(&mut *tx, name, None).await? {
+        return Err(ApiError::conflict(
+            "an organizer with this name already exists",
+        ));
+    }
+
+    // An explicit slug must be free; a generated one gets a numeric suffix instead
+    let slug = match payload.slug {
+        Some(ref slug) => {
+            if !organizers::is_valid_slug(slug) {
+                return Err(ApiError::field(
+                    "slug",
+                    "slug",
+                    "may only contain lowercase letters, digits and hyphens",
+                ));
+            }
+            if organizers::slug_taken(&mut *tx, slug, None).await? {
+                return Err(ApiError::conflict("this slug is already in use"));
+            }
+            slug.clone()
+        }
+        None => {
+            let base = organizers::slugify(name);
+            if base.is_empty() {
+                return Err(ApiError::field(
+                    "name",
+                    "slug",
+                    "must contain letters or digits to build a slug from",
+                ));
+            }
+            organizers::unique_slug(&mut tx, &base, None).await?
+        }
+    };
+
+    let non_empty = |value: &Option<String>| value.clone().filter(|v| !v.is_empty());
+    let result = sqlx::query(
+        r#"
+        INSERT INTO organizers (name, slug, description, website, logo_url, city, country,
+            instagram_url, facebook_url, youtube_url, soundcloud_url, contact_email, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(name)
+    .bind(&slug)
+    .bind(non_empty(&payload.description))
+    .bind(non_empty(&payload.website))
+    .bind(non_empty(&payload.logo_url))
+    .bind(non_empty(&payload.city))
+    .bind(non_empty(&payload.country))
+    .bind(non_empty(&payload.instagram_url))
+    .bind(non_empty(&payload.facebook_url))
+    .bind(non_empty(&payload.youtube_url))
+    .bind(non_empty(&payload.soundcloud_url))
+    .bind(non_empty(&payload.contact_email))
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+    let id = result.last_insert_rowid();
+
+    let organizer = organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.create",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: id,
+            before: None,
+            after: audit::snapshot(&organizer),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Organizer {} ({}) created by {}", id, slug, admin.username);
+    Ok((StatusCode::CREATED, Json(organizer)))
+}
+
+/// PUT /api/admin/organizers/:id - Update an organizer; renames carry over to its linked events
+async fn update_organizer(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateOrganizerRequest>,
+) -> Result<Json<Organizer>, ApiError> {
+    payload.validate()?;
+    // Empty strings clear a field, so they skip the format checks
+    let set = |value: &Option<String>| value.clone().filter(|v| !v.is_empty());
+    for (field, url) in [
+        ("website", &payload.website),
+        ("logo_url", &payload.logo_url),
+    ] {
+        if set(url).is_some_and(|u| !u.validate_url()) {
+            return Err(ApiError::field(field, "url", "must be a valid URL"));
+        }
+    }
+    if set(&payload.contact_email).is_some_and(|e| !e.validate_email()) {
+        return Err(ApiError::field(
+            "contact_email",
+            "email",
+            "must be a valid email address",
+        ));
+    }
+    check_social_links(&[
+        (
+            "instagram_url",
+            &payload.instagram_url,
+            organizers::INSTAGRAM_HOSTS,
+        ),
+        (
+            "facebook_url",
+            &payload.facebook_url,
+            organizers::FACEBOOK_HOSTS,
+        ),
+        (
+            "youtube_url",
+            &payload.youtube_url,
+            organizers::YOUTUBE_HOSTS,
+        ),
+        (
+            "soundcloud_url",
+            &payload.soundcloud_url,
+            organizers::SOUNDCLOUD_HOSTS,
+        ),
+    ])?;
+
+    let mut tx = state.db.begin().await?;
+
+    let before = organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let name = payload
+        .name
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or(&before.name)
+        .to_string();
+    if name != before.name && organizers::name_taken(&mut *tx, &name, Some(id)).await? {
+        return Err(ApiError::conflict(
+            "an organizer with this name already exists",
+        ));
+    }
+
+    // An empty slug is regenerated from the (new) name
+    let slug = match payload.slug.as_deref() {
+        None => before.slug.clone(),
+        Some("") => {
+            let base = organizers::slugify(&name);
+            if base.is_empty() {
+                return Err(ApiError::field(
+                    "name",
+                    "slug",
+                    "must contain letters or digits to build a slug from",
+                ));
+            }
+            organizers::unique_slug(&mut tx, &base, Some(id)).await?
+        }
+        Some(slug) => {
+            if !organizers::is_valid_slug(slug) {
+                return Err(ApiError::field(
+                    "slug",
+                    "slug",
+                    "may only contain lowercase letters, digits and hyphens",
+                ));
+            }
+            if organizers::slug_taken(&mut *tx, slug, Some(id)).await? {
+                return Err(ApiError::conflict("this slug is already in use"));
+            }
+            slug.to_string()
+        }
+    };
+    // Empty strings clear, absent fields keep their value
+    let updated = |value: &Option<String>, current: &Option<String>| match value.as_deref() {
+        Some("") => None,
+        Some(v) => Some(v.to_string()),
+        None => current.clone(),
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE organizers SET name = ?, slug = ?, description = ?, website = ?, logo_url = ?,
+            city = ?, country = ?, instagram_url = ?, facebook_url = ?, youtube_url = ?,
+            soundcloud_url = ?, contact_email = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&name)
+    .bind(&slug)
+    .bind(updated(&payload.description, &before.description))
+    .bind(updated(&payload.website, &before.website))
+    .bind(updated(&payload.logo_url, &before.logo_url))
+    .bind(updated(&payload.city, &before.city))
+    .bind(updated(&payload.country, &before.country))
+    .bind(updated(&payload.instagram_url, &before.instagram_url))
+    .bind(updated(&payload.facebook_url, &before.facebook_url))
+    .bind(updated(&payload.youtube_url, &before.youtube_url))
+    .bind(updated(&payload.soundcloud_url, &before.soundcloud_url))
+    .bind(updated(&payload.contact_email, &before.contact_email))
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    let renamed = if name != before.name {
+        linked_events(&mut tx, id).await?
+    } else {
+        Vec::new()
+    };
+    if name != before.name {
+        sqlx::query("UPDATE events SET organizer = ?, updated_at = ? WHERE organizer_id = ?")
+            .bind(&name)
+            .bind(Utc::now())
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let organizer = organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.update",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&organizer),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    publish_changes(&state, &renamed).await;
+
+    tracing::info!("Organizer {} updated by {}", id, admin.username);
+    Ok(Json(organizer))
+}
+
+/// DELETE /api/admin/organizers/:id - Delete an organizer; its events keep the free-text name but are unlinked
+async fn delete_organizer(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+    AppQuery(params): AppQuery<DeleteOrganizerQuery>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before = organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let events = linked_events(&mut tx, id).await?;
+    let unlinked = sqlx::query("UPDATE events SET organizer_id = NULL WHERE organizer_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+    sqlx::query("DELETE FROM organizers WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.delete",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: params.reason,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    publish_changes(&state, &events).await;
+
+    tracing::info!(
+        "Organizer {} deleted by {} ({} events unlinked)",
+        id,
+        admin.username,
+        unlinked
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/admin/organizers/:id/merge - Merge a duplicate organizer into another, relinking its events
+async fn merge_organizer(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<MergeOrganizerRequest>,
+) -> Result<Json<Organizer>, ApiError> {
+    if payload.into == id {
+        return Err(ApiError::field(
+            "into",
+            "self",
+            "cannot merge an organizer into itself",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let duplicate = organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let survivor = organizers::fetch_organizer(&mut *tx, payload.into)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let events = linked_events(&mut tx, duplicate.id).await?;
+    let moved = sqlx::query(
+        "UPDATE events SET organizer_id = ?, organizer = ?, updated_at = ? WHERE organizer_id = ?",
+    )
+    .bind(survivor.id)
+    .bind(&survivor.name)
+    .bind(Utc::now())
+    .bind(duplicate.id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    // Keep matching the duplicate's spellings, now to the survivor
+    sqlx::query("UPDATE organizer_aliases SET organizer_id = ? WHERE organizer_id = ?")
+        .bind(survivor.id)
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT OR IGNORE INTO organizer_aliases (organizer_id, alias, normalized, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(survivor.id)
+    .bind(&duplicate.name)
+    .bind(dedup::normalize(&duplicate.name))
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query(
+        "UPDATE OR IGNORE event_organizer_suggestions SET organizer_id = ? WHERE organizer_id = ?",
+    )
+    .bind(survivor.id)
+    .bind(duplicate.id)
+    .execute(&mut *tx)
+    .await?;
+
+    // Fill gaps in the survivor from the duplicate; existing values always win
+    sqlx::query(
+        r#"
+        UPDATE organizers SET
+            description = COALESCE(description, ?),
+            website = COALESCE(website, ?),
+            logo_url = COALESCE(logo_url, ?),
+            city = COALESCE(city, ?),
+            country = COALESCE(country, ?),
+            instagram_url = COALESCE(instagram_url, ?),
+            facebook_url = COALESCE(facebook_url, ?),
+            youtube_url = COALESCE(youtube_url, ?),
+            soundcloud_url = COALESCE(soundcloud_url, ?),
+            contact_email = COALESCE(contact_email, ?)
+        WHERE id = ?
+        "#,
+    )
+    .bind(&duplicate.description)
+    .bind(&duplicate.website)
+    .bind(&duplicate.logo_url)
+    .bind(&duplicate.city)
+    .bind(&duplicate.country)
+    .bind(&duplicate.instagram_url)
+    .bind(&duplicate.facebook_url)
+    .bind(&duplicate.youtube_url)
+    .bind(&duplicate.soundcloud_url)
+    .bind(&duplicate.contact_email)
+    .bind(survivor.id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM organizers WHERE id = ?")
+        .bind(duplicate.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let merged = organizers::fetch_organizer(&mut *tx, survivor.id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.merge",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: duplicate.id,
+            before: audit::snapshot(&duplicate),
+            after: None,
+            reason: Some(match payload.reason {
+                Some(reason) => format!("merged into organizer {}: {}", survivor.id, reason),
+                None => format!("merged into organizer {}", survivor.id),
+            }),
+        },
+    )
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.update",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: survivor.id,
+            before: audit::snapshot(&survivor),
+            after: audit::snapshot(&merged),
+            reason: Some(format!(
+                "absorbed duplicate organizer {} ({} events relinked)",
+                duplicate.id, moved
+            )),
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    publish_changes(&state, &events).await;
+
+    tracing::info!(
+        "Organizer {} merged into {} by {} ({} events relinked)",
+        duplicate.id,
+        survivor.id,
+        admin.username,
+        moved
+    );
+    Ok(Json(merged))
+}
+
+/// GET /api/admin/organizers/:id/aliases - Alternative spellings used for auto-linking
+async fn list_aliases(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<AliasesResponse>, ApiError> {
+    organizers::fetch_organizer(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let aliases: Vec<OrganizerAlias> = sqlx::query_as(
+        "SELECT id, organizer_id, alias, created_at FROM organizer_aliases WHERE organizer_id = ? ORDER BY alias",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await?;
+
+    let total = aliases.len();
+    Ok(Json(AliasesResponse { aliases, total }))
+}
+
+/// POST /api/admin/organizers/:id/aliases - Add an alternative spelling
+async fn create_alias(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<CreateAliasRequest>,
+) -> Result<(StatusCode, Json<OrganizerAlias>), ApiError> {
+    payload.validate()?;
+    let alias = payload.alias.trim();
+    let normalized = dedup::normalize(alias);
+    if normalized.is_empty() {
+        return Err(ApiError::field(
+            "alias",
+            "terms",
+            "must contain letters or digits",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    organizers::fetch_organizer(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // Each spelling can only point at one organizer
+    let existing: Option<(i64,)> =
+        sqlx::query_as("SELECT id FROM organizer_aliases WHERE normalized = ?")
+            .bind(&normalized)
+            .fetch_optional(&mut *tx)
+            .await?;
+    if existing.is_some() {
+        return Err(ApiError::conflict(
+            "this alias already points at an organizer",
+        ));
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO organizer_aliases (organizer_id, alias, normalized, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(alias)
+    .bind(&normalized)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+
+    let created: OrganizerAlias = sqlx::query_as(
+        "SELECT id, organizer_id, alias, created_at FROM organizer_aliases WHERE id = ?",
+    )
+    .bind(result.last_insert_rowid())
+    .fetch_one(&mut *tx)
+    .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.alias_add",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: id,
+            before: None,
+            after: audit::snapshot(&created),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// DELETE /api/admin/organizers/:id/aliases/:alias_id - Remove an alternative spelling
+async fn delete_alias(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath((id, alias_id)): AppPath<(i64, i64)>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before: OrganizerAlias = sqlx::query_as(
+        "SELECT id, organizer_id, alias, created_at FROM organizer_aliases WHERE id = ? AND organizer_id = ?",
+    )
+    .bind(alias_id)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    sqlx::query("DELETE FROM organizer_aliases WHERE id = ?")
+        .bind(alias_id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer.alias_remove",
+            entity_type: audit::ENTITY_ORGANIZER,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/admin/organizers/backfill - Link existing unlinked events to organizers
+async fn backfill_organizers(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+) -> Result<Json<OrganizerBackfillResponse>, ApiError> {
+    let mut tx = state.db.begin().await?;
+    let (summary, linked) = organizers::backfill(&mut tx).await?;
+
+    // Backfill only fills in the organizer link, so that is all the entries record
+    for id in linked {
+        let event = db::fetch_event(&mut *tx, id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+        let before = Event {
+            organizer_id: None,
+            ..event.clone()
+        };
+        audit::record(
+            &mut *tx,
+            AuditRecord {
+                actor: &admin,
+                action: "event.update",
+                entity_type: audit::ENTITY_EVENT,
+                entity_id: id,
+                before: audit::snapshot(&before),
+                after: audit::snapshot(&event),
+                reason: Some("organizer backfill".to_string()),
+            },
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Organizer backfill by {}: {} linked, {} ambiguous, {} unmatched",
+        admin.username,
+        summary.linked,
+        summary.ambiguous,
+        summary.unmatched
+    );
+    Ok(Json(summary))
+}
+
+/// PUT /api/admin/events/:id/organizer - Link an event to an organizer (or unlink with `null`)
+async fn link_event_organizer(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Editor>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<LinkOrganizerRequest>,
+) -> Result<Json<Event>, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if let Some(organizer_id) = payload.organizer_id {
+        organizers::fetch_organizer(&mut *tx, organizer_id)
+            .await?
+            .ok_or(ApiError::NotFound)?;
+
+        // Teach the matcher this spelling unless it already knows it
+        let normalized = dedup::normalize(&before.organizer);
+        if payload.remember_alias && !normalized.is_empty() {
+            sqlx::query(
+                "INSERT OR IGNORE INTO organizer_aliases (organizer_id, alias, normalized, created_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(organizer_id)
+            .bind(&before.organizer)
+            .bind(&normalized)
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    sqlx::query("UPDATE events SET organizer_id = ? WHERE id = ?")
+        .bind(payload.organizer_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM event_organizer_suggestions WHERE event_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    let event = db::fetch_event(&mut *tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.update",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&event),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&before), Some(event.clone()));
+
+    tracing::info!(
+        "Event {} linked to organizer {:?} by {}",
+        id,
+        payload.organizer_id,
+        admin.username
+    );
+    Ok(Json(event))
+}
+
+// ===== Organizer Account Endpoints =====
+
+/// The organizer account behind a request; admins and anonymous callers are refused
+async fn organizer_account(
+    db: &sqlx::SqlitePool,
+    headers: &HeaderMap,
+) -> Result<OrganizerIdentity, ApiError> {
+    match auth::authenticate(db, headers).await? {
+        Principal::Organizer(account) => Ok(account),
+        Principal::Admin(_) => Err(ApiError::Forbidden),
+    }
+}
+
+/// POST /api/organizer/login - Exchange organizer account credentials for a session token
+async fn organizer_login(
+    State(state): State<Arc<AppState>>,
+    AppJson(payload): AppJson<LoginRequest>,
+) -> Result<Json<OrganizerLoginResponse>, ApiError> {
+    payload.validate()?;
+
+    let row: Option<(i64, String)> =
+        sqlx::query_as("SELECT id, password_hash FROM organizer_accounts WHERE username = ?")
+            .bind(&payload.username)
+            .fetch_optional(&state.db)
+            .await?;
+
+    let account_id = match row {
+        Some((id, ref hash)) if auth::verify_password(&payload.password, hash) => id,
+        _ => {
+            tracing::warn!("Failed organizer login for {}", payload.username);
+            return Err(ApiError::Unauthorized);
+        }
+    };
+
+    let (token, expires_at) = auth::create_organizer_session(&state.db, account_id).await?;
+
+    sqlx::query("UPDATE organizer_accounts SET last_login_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(account_id)
+        .execute(&state.db)
+        .await?;
+
+    let mut conn = state.db.acquire().await?;
+    let account = auth::fetch_organizer_account(&mut conn, account_id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    tracing::info!("Organizer account {} logged in", account.username);
+    Ok(Json(OrganizerLoginResponse {
+        token,
+        expires_at,
+        account,
+    }))
+}
+
+/// POST /api/organizer/logout - Revoke the current organizer session token
+async fn organizer_logout(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let token = auth::bearer_token(&headers).ok_or(ApiError::Unauthorized)?;
+
+    let result = sqlx::query(
+        "UPDATE organizer_sessions SET revoked_at = ? WHERE token_hash = ? AND revoked_at IS NULL",
+    )
+    .bind(Utc::now())
+    .bind(auth::hash_token(token))
+    .execute(&state.db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::Unauthorized);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/organizer/me - Account behind the current token and the organizers it manages
+async fn organizer_me(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<OrganizerIdentity>, ApiError> {
+    organizer_account(&state.db, &headers).await.map(Json)
+}
+
+/// GET /api/organizer/events - All events of the account's organizers, in any status
+async fn list_own_events(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<EventsResponse>, ApiError> {
+    let account = organizer_account(&state.db, &headers).await?;
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {} FROM events WHERE organizer_id IN (",
+        EVENT_COLUMNS
+    ));
+    let mut ids = query.separated(", ");
+    // Accounts always manage at least one organizer; -1 keeps the SQL valid regardless
+    ids.push_bind(-1);
+    for id in &account.organizer_ids {
+        ids.push_bind(*id);
+    }
+    ids.push_unseparated(") ORDER BY event_date DESC");
+
+    let events: Vec<Event> = query.build_query_as().fetch_all(&state.db).await?;
+    let events: Vec<Event> = events.into_iter().map(Event::with_parsed_status).collect();
+
+    let total = events.len();
+    Ok(Json(EventsResponse {
+        events,
+        total,
+        next_cursor: None,
+    }))
+}
+
+/// PUT /api/organizer/events/:id - Edit one of your organizers' events. Pending events change
+/// immediately; edits to published events wait for review unless the account is verified.
+async fn update_own_event(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateEventRequest>,
+) -> Result<(StatusCode, Json<SubmissionResponse>), ApiError> {
+    let principal = auth::authenticate(&state.db, &headers).await?;
+
+    // Moderation and the organizer link stay with admins
+    if payload.status.is_some() {
+        return Err(ApiError::field(
+            "status",
+            "admin_only",
+            "can only be changed by moderators",
+        ));
+    }
+    if payload.organizer.is_some() {
+        return Err(ApiError::field(
+            "organizer",
+            "admin_only",
+            "can only be changed by admins",
+        ));
+    }
+    payload.validate()?;
+    if !payload.has_changes() {
+        return Err(ApiError::bad_request("no changes given"));
+    }
+
+    let event = db::fetch_event(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    principal.require(Permission::ManageEvents(event.organizer_id))?;
+
+    let schedule = Schedule::updated(&event, &payload).map_err(ApiError::bad_request)?;
+
+    match event.status {
+        EventStatus::Rejected => Err(ApiError::conflict("rejected events cannot be edited")),
+        EventStatus::Approved if !principal.allows(Permission::Publish(event.organizer_id)) => {
+            queue_revision(&state.db, id, &payload).await?;
+            let response = submission_response(&state.db, event).await?;
+            Ok((StatusCode::ACCEPTED, Json(response)))
+        }
+        _ => {
+            let actor = principal.audit_actor();
+            let mut tx = state.db.begin().await?;
+
+            // Moderation may have run since the event was read
+            if !db::apply_event_update(
+                &mut *tx,
+                id,
+                &payload,
+                &schedule,
+                Some(event.status.clone()),
+            )
+            .await?
+            {
+                return Err(ApiError::conflict(
+                    "the event was moderated meanwhile; reload it and try again",
+                ));
+            }
+            let updated = db::fetch_event(&mut *tx, id)
+                .await?
+                .ok_or(ApiError::NotFound)?;
+
+            audit::record(
+                &mut *tx,
+                AuditRecord {
+                    actor: &actor,
+                    action: "event.update",
+                    entity_type: audit::ENTITY_EVENT,
+                    entity_id: id,
+                    before: audit::snapshot(&event),
+                    after: audit::snapshot(&updated),
+                    reason: None,
+                },
+            )
+            .await?;
+
+            tx.commit().await?;
+            state.updates.publish(Some(&event), Some(updated.clone()));
+
+            tracing::info!("Event {} updated by {}", id, actor.username);
+            let response = submission_response(&state.db, updated).await?;
+            Ok((StatusCode::OK, Json(response)))
+        }
+    }
+}
+
+/// Check organizer ids exist and make them the only organizers of an account
+async fn set_account_organizers(
+    conn: &mut sqlx::SqliteConnection,
+    account_id: i64,
+    organizer_ids: &[i64],
+) -> Result<(), ApiError> {
+    for organizer_id in organizer_ids {
+        organizers::fetch_organizer(&mut *conn, *organizer_id)
+            .await?
+            .ok_or_else(|| ApiError::field("organizer_ids", "exists", "no such organizer"))?;
+    }
+
+    sqlx::query("DELETE FROM organizer_account_organizers WHERE account_id = ?")
+        .bind(account_id)
+        .execute(&mut *conn)
+        .await?;
+    for organizer_id in organizer_ids {
+        sqlx::query(
+            "INSERT OR IGNORE INTO organizer_account_organizers (account_id, organizer_id) VALUES (?, ?)",
+        )
+        .bind(account_id)
+        .bind(organizer_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// GET /api/admin/organizer-accounts - List organizer accounts
+async fn list_organizer_accounts(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Owner>,
+) -> Result<Json<OrganizerAccountsResponse>, ApiError> {
+    let mut accounts: Vec<OrganizerAccount> = sqlx::query_as(
+        "SELECT id, username, verified, created_at, last_login_at FROM organizer_accounts ORDER BY username",
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let links: Vec<(i64, i64)> = sqlx::query_as(
+        "SELECT account_id, organizer_id FROM organizer_account_organizers ORDER BY organizer_id",
+    )
+    .fetch_all(&state.db)
+    .await?;
+    let mut organizer_ids: HashMap<i64, Vec<i64>> = HashMap::new();
+    for (account_id, organizer_id) in links {
+        organizer_ids
+            .entry(account_id)
+            .or_default()
+            .push(organizer_id);
+    }
+    for account in &mut accounts {
+        account.organizer_ids = organizer_ids.remove(&account.id).unwrap_or_default();
+    }
+
+    let total = accounts.len();
+    Ok(Json(OrganizerAccountsResponse { accounts, total }))
+}
+
+/// POST /api/admin/organizer-accounts - Create an organizer account
+async fn create_organizer_account(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppJson(payload): AppJson<CreateOrganizerAccountRequest>,
+) -> Result<(StatusCode, Json<OrganizerAccount>), ApiError> {
+    payload.validate()?;
+    auth::check_password(&payload.password)?;
+    let password_hash = auth::hash_password(&payload.password)?;
+
+    let mut tx = state.db.begin().await?;
+
+    let taken: Option<(i64,)> =
+        sqlx::query_as("SELECT id FROM organizer_accounts WHERE username = ?")
+            .bind(&payload.username)
+            .fetch_optional(&mut *tx)
+            .await?;
+    if taken.is_some() {
+        return Err(ApiError::conflict(
+            "an account with this username already exists",
+        ));
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO organizer_accounts (username, password_hash, verified, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(&payload.username)
+    .bind(&password_hash)
+    .bind(payload.verified)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+    let id = result.last_insert_rowid();
+
+    set_account_organizers(&mut tx, id, &payload.organizer_ids).await?;
+
+    let account = auth::fetch_organizer_account(&mut tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer_account.create",
+            entity_type: audit::ENTITY_ORGANIZER_ACCOUNT,
+            entity_id: id,
+            before: None,
+            after: audit::snapshot(&account),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Organizer account {} ({}) created by {}",
+        id,
+        account.username,
+        admin.username
+    );
+    Ok((StatusCode::CREATED, Json(account)))
+}
+
+/// PUT /api/admin/organizer-accounts/:id - Change an account's organizers, verification or password
+async fn update_organizer_account(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateOrganizerAccountRequest>,
+) -> Result<Json<OrganizerAccount>, ApiError> {
+    payload.validate()?;
+    if let Some(ref password) = payload.password {
+        auth::check_password(password)?;
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let before = auth::fetch_organizer_account(&mut tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if let Some(verified) = payload.verified {
+        sqlx::query("UPDATE organizer_accounts SET verified = ? WHERE id = ?")
+            .bind(verified)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    if let Some(ref organizer_ids) = payload.organizer_ids {
+        set_account_organizers(&mut tx, id, organizer_ids).await?;
+    }
+    if let Some(ref password) = payload.password {
+        let hash = auth::hash_password(password)?;
+        sqlx::query("UPDATE organizer_accounts SET password_hash = ? WHERE id = ?")
+            .bind(hash)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        // A new password signs out every existing session
+        sqlx::query(
+            "UPDATE organizer_sessions SET revoked_at = ? WHERE account_id = ? AND revoked_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let account = auth::fetch_organizer_account(&mut tx, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer_account.update",
+            entity_type: audit::ENTITY_ORGANIZER_ACCOUNT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&account),
+            reason: payload
+                .password
+                .is_some()
+                .then(|| "password reset".to_string()),
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Organizer account {} updated by {}", id, admin.username);
+    Ok(Json(account))
+}
+
+/// DELETE /api/admin/organizer-accounts/:id - Delete an organizer account and its sessions
+async fn delete_organizer_account(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Owner>,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let before = auth::fetch_organizer_account(&mut tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    sqlx::query("DELETE FROM organizer_accounts WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "organizer_account.delete",
+            entity_type: audit::ENTITY_ORGANIZER_ACCOUNT,
+            entity_id: id,
+            before: audit::snapshot(&before),
+            after: None,
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Organizer account {} ({}) deleted by {}",
+        id,
+        before.username,
+        admin.username
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ===== Submitter Endpoints =====
+
+/// Load a submission, checking the `X-Edit-Token` header against the stored hash
+async fn authorize_submitter(
+    db: &sqlx::SqlitePool,
+    id: i64,
+    headers: &HeaderMap,
+) -> Result<Event, ApiError> {
+    let token = headers
+        .get("x-edit-token")
+        .and_then(|v| v.to_str().ok())
+        .ok_or(ApiError::Unauthorized)?;
+
+    let (stored_hash,): (Option<String>,) =
+        sqlx::query_as("SELECT edit_token_hash FROM events WHERE id = ?")
+            .bind(id)
+            .fetch_optional(db)
+            .await?
+            .ok_or(ApiError::NotFound)?;
+
+    if stored_hash.as_deref() != Some(auth::hash_token(token).as_str()) {
+        return Err(ApiError::Unauthorized);
+    }
+
+    db::fetch_event(db, id).await?.ok_or(ApiError::NotFound)
+}
+
+async fn pending_revision(
+    db: &sqlx::SqlitePool,
+    event_id: i64,
+) -> Result<Option<EventRevision>, ApiError> {
+    sqlx::query_as(
+        r#"
+        SELECT id, event_id, changes_json, status, reviewer, created_at, reviewed_at
+        FROM event_revisions
+        WHERE event_id = ? AND status = 'pending'
+        ORDER BY id DESC LIMIT 1
+        "#,
+    )
+    .bind(event_id)
+    .fetch_optional(db)
+    .await
+    .map_err(ApiError::from)
+}
+
+async fn submission_response(
+    db: &sqlx::SqlitePool,
+    event: Event,
+) -> Result<SubmissionResponse, ApiError> {
+    let reason = rejection_reason(db, event.id, &event.status).await?;
+    let pending_revision = pending_revision(db, event.id).await?;
+    Ok(SubmissionResponse {
+        event,
+        reason,
+        pending_revision,
+    })
+}
+
+/// GET /api/submissions/:id - Moderation status of your own submission (requires X-Edit-Token)
+async fn get_submission(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<SubmissionResponse>, ApiError> {
+    let event = authorize_submitter(&state.db, id, &headers).await?;
+    submission_response(&state.db, event).await.map(Json)
+}
+
+/// PUT /api/submissions/:id - Edit your own submission (requires X-Edit-Token).
+/// Pending submissions change immediately; edits to approved events are queued for review.
+async fn update_submission(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+    AppJson(payload): AppJson<UpdateEventRequest>,
+) -> Result<(StatusCode, Json<SubmissionResponse>), ApiError> {
+    let event = authorize_submitter(&state.db, id, &headers).await?;
+
+    // Submitters cannot moderate their own events
+    if payload.status.is_some() {
+        return Err(ApiError::field(
+            "status",
+            "admin_only",
+            "can only be changed by moderators",
+        ));
+    }
+    payload.validate()?;
+    if !payload.has_changes() {
+        return Err(ApiError::bad_request("no changes given"));
+    }
+    let schedule = Schedule::updated(&event, &payload).map_err(ApiError::bad_request)?;
+
+    match event.status {
+        EventStatus::Pending => {
+            // Moderation may have run since the submission was read
+            let mut tx = state.db.begin().await?;
+            if !db::apply_event_update(
+                &mut *tx,
+                id,
+                &payload,
+                &schedule,
+                Some(EventStatus::Pending),
+            )
+            .await?
+            {
+                return Err(ApiError::conflict(
+                    "the submission was moderated meanwhile; reload it and try again",
+                ));
+            }
+            let updated = db::fetch_event(&mut *tx, id)
+                .await?
+                .ok_or(ApiError::NotFound)?;
+            tx.commit().await?;
+            state.updates.publish(Some(&event), Some(updated.clone()));
+
+            tracing::info!("Submission {} edited by submitter", id);
+            let response = submission_response(&state.db, updated).await?;
+            Ok((StatusCode::OK, Json(response)))
+        }
+        EventStatus::Approved => {
+            queue_revision(&state.db, id, &payload).await?;
+            let response = submission_response(&state.db, event).await?;
+            Ok((StatusCode::ACCEPTED, Json(response)))
+        }
+        EventStatus::Rejected => Err(ApiError::conflict("rejected events cannot be edited")),
+    }
+}
+
+/// Hold an edit to an approved event for review
+async fn queue_revision(
+    db: &sqlx::SqlitePool,
+    id: i64,
+    payload: &UpdateEventRequest,
+) -> Result<(), ApiError> {
+    let mut tx = db.begin().await?;
+
+    // A newer edit replaces any revision still waiting for review
+    sqlx::query(
+        "UPDATE event_revisions SET status = 'superseded' WHERE event_id = ? AND status = 'pending'",
+    )
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    // Only while the event is still published; it may have been moderated since it was read
+    let queued = sqlx::query(
+        "INSERT INTO event_revisions (event_id, changes_json, created_at) \
+         SELECT id, ?, ? FROM events WHERE id = ? AND status = 'approved'",
+    )
+    .bind(SqlJson(payload))
+    .bind(Utc::now())
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+    if queued.rows_affected() == 0 {
+        return Err(ApiError::conflict(
+            "the event was moderated meanwhile; reload it and try again",
+        ));
+    }
+
+    tx.commit().await?;
+
+    tracing::info!("Revision queued for approved event {}", id);
+    Ok(())
+}
+
+/// DELETE /api/submissions/:id - Withdraw your own pending or rejected submission (requires X-Edit-Token)
+async fn withdraw_submission(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let event = authorize_submitter(&state.db, id, &headers).await?;
+
+    // Published rides can only be removed by moderators
+    if event.status == EventStatus::Approved {
+        return Err(ApiError::conflict(
+            "published rides can only be removed by moderators",
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    sqlx::query("DELETE FROM video_suggestions WHERE event_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM events WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&event), None);
+
+    tracing::info!("Submission {} withdrawn by submitter", id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ===== Planned Routes =====
+
+/// Uploaded tracks are simplified to within this many metres of the original
+const ROUTE_TOLERANCE_M: f64 = 10.0;
+
+/// Most points kept after simplification; longer routes are simplified further
+const MAX_ROUTE_POINTS: usize = 5000;
+
+/// Most points accepted in an upload
+const MAX_UPLOAD_POINTS: usize = 200_000;
+
+/// Largest route upload in bytes; GPX spends ~60 bytes per point
+const MAX_ROUTE_UPLOAD_BYTES: usize = 16 * 1024 * 1024;
+
+/// Read an uploaded route: GPX when sent as XML (or when it looks like XML),
+/// otherwise GeoJSON
+fn parse_route_upload(headers: &HeaderMap, body: &[u8]) -> Result<Vec<[f64; 2]>, ApiError> {
+    let text = std::str::from_utf8(body)
+        .map_err(|_| ApiError::bad_request("the route must be UTF-8 text"))?
+        .trim_start_matches('\u{feff}')
+        .trim();
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let points = if content_type.contains("xml") || text.starts_with('<') {
+        gpx::parse(text)
+    } else {
+        serde_json::from_str(text)
+            .map_err(|e| format!("invalid JSON: {}", e))
+            .and_then(|value| geojson::line_coordinates(&value))
+    }
+    .map_err(ApiError::bad_request)?;
+
+    if points.len() < 2 {
+        return Err(ApiError::bad_request("a route needs at least two points"));
+    }
+    if points.len() > MAX_UPLOAD_POINTS {
+        return Err(ApiError::bad_request(format!(
+            "a route can have at most {} points",
+            MAX_UPLOAD_POINTS
+        )));
+    }
+    if let Some([lng, lat]) = points
+        .iter()
+        .find(|[lng, lat]| !(-180.0..=180.0).contains(lng) || !(-90.0..=90.0).contains(lat))
+    {
+        return Err(ApiError::bad_request(format!(
+            "point ({}, {}) is not a valid coordinate",
+            lat, lng
+        )));
+    }
+    Ok(points)
+}
+
+/// Douglas–Peucker at the usual tolerance, coarser until the route fits
+fn simplify_route(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    // Repeated points (a device standing still) add nothing
+    let mut points = points.to_vec();
+    points.dedup();
+    let mut tolerance = ROUTE_TOLERANCE_M;
+    loop {
+        let simple = geo::simplify(&points, tolerance);
+        if simple.len() <= MAX_ROUTE_POINTS {
+            return simple;
+        }
+        tolerance *= 2.0;
+    }
+}
+
+/// Summary of a route for the audit log; the coordinates would swamp it
+fn route_summary(route: &EventRoute) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+        "distance_km": route.distance_km,
+        "points": route.coordinates.len(),
+        "source_points": route.source_points,
+    }))
+}
+
+/// PUT /api/events/:id/route - Attach a planned route, as GPX or a GeoJSON LineString
+/// (requires an account managing the event)
+async fn put_event_route(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+    body: Bytes,
+) -> Result<Json<EventRoute>, ApiError> {
+    let principal = auth::authenticate(&state.db, &headers).await?;
+    let event = db::fetch_event(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    principal.require(Permission::ManageEvents(event.organizer_id))?;
+
+    let points = parse_route_upload(&headers, &body)?;
+    let distance_km = geo::path_length_km(&points);
+    let coordinates = simplify_route(&points);
+
+    let actor = principal.audit_actor();
+    let now = Utc::now();
+    let mut tx = state.db.begin().await?;
+    let before = db::fetch_event_route(&mut *tx, id).await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO event_routes (event_id, coordinates_json, distance_km, source_points, uploaded_by, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(event_id) DO UPDATE SET
+            coordinates_json = excluded.coordinates_json,
+            distance_km = excluded.distance_km,
+            source_points = excluded.source_points,
+            uploaded_by = excluded.uploaded_by,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(SqlJson(&coordinates))
+    .bind(distance_km)
+    .bind(points.len() as i64)
+    .bind(&actor.username)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("UPDATE events SET updated_at = ? WHERE id = ?")
+        .bind(now)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    let route = db::fetch_event_route(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &actor,
+            action: "event.route_upload",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: before.as_ref().and_then(route_summary),
+            after: route_summary(&route),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    publish_change(&state, id, Some(&event)).await;
+
+    tracing::info!(
+        "Route for event {} uploaded by {}: {} points simplified to {}",
+        id,
+        actor.username,
+        points.len(),
+        route.coordinates.len()
+    );
+    Ok(Json(route))
+}
+
+/// DELETE /api/events/:id/route - Remove the planned route (requires an account managing the event)
+async fn delete_event_route(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let principal = auth::authenticate(&state.db, &headers).await?;
+    let event = db::fetch_event(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    principal.require(Permission::ManageEvents(event.organizer_id))?;
+
+    let actor = principal.audit_actor();
+    let mut tx = state.db.begin().await?;
+    let route = db::fetch_event_route(&mut *tx, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    sqlx::query("DELETE FROM event_routes WHERE event_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE events SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &actor,
+            action: "event.route_remove",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: id,
+            before: route_summary(&route),
+            after: None,
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    publish_change(&state, id, Some(&event)).await;
+
+    tracing::info!("Route for event {} removed by {}", id, actor.username);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/events/:id/route.gpx - Planned route of an approved event as GPX, for Komoot, Strava
+/// and GPS devices
+async fn event_route_gpx(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    let event = approved_event(&state.db, id).await?;
+    let route = db::fetch_event_route(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let filename = format!("dnbride-{}.gpx", id);
+    Ok((
+        [
+            (header::CONTENT_TYPE, gpx::CONTENT_TYPE.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        gpx::document(&event, &route),
+    ))
+}
+
+// ===== Live Tracking =====
+
+/// The `X-Live-Token` a tracking device sends
+fn live_token(headers: &HeaderMap) -> Option<&str> {
+    headers.get("x-live-token").and_then(|v| v.to_str().ok())
+}
+
+/// POST /api/events/:id/live - Start sharing the ride's position (requires an account managing
+/// the event). The device token is returned only once.
+async fn start_live_session(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(id): AppPath<i64>,
+) -> Result<(StatusCode, Json<StartLiveResponse>), ApiError> {
+    let principal = auth::authenticate(&state.db, &headers).await?;
+    let event = db::fetch_event(&state.db, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    principal.require(Permission::ManageEvents(event.organizer_id))?;
+
+    if event.status != EventStatus::Approved {
+        return Err(ApiError::conflict("only published rides can be tracked"));
+    }
+    if event.lifecycle == EventLifecycle::Cancelled {
+        return Err(ApiError::conflict("cancelled rides cannot be tracked"));
+    }
+    live::end_idle_sessions(&state.db, &state.live).await?;
+    if live::active_session(&state.db, id).await?.is_some() {
+        return Err(ApiError::conflict("this ride is already being tracked"));
+    }
+
+    let started_by = principal.audit_actor().username;
+    let (session, token) = live::start_session(&state.db, id, &started_by).await?;
+    state
+        .live
+        .publish(id, LiveMessage::Started(session.clone()));
+
+    tracing::info!(
+        "Live session {} started for event {} by {}",
+        session.id,
+        id,
+        started_by
+    );
+    Ok((
+        StatusCode::CREATED,
+        Json(StartLiveResponse { session, token }),
+    ))
+}
+
+/// GET /api/events/:id/live - Current or latest live session of a ride, with the recent trail
+async fn get_live_snapshot(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<LiveSnapshot>, ApiError> {
+    approved_event(&state.db, id).await?;
+    live::end_idle_sessions(&state.db, &state.live).await?;
+    live::snapshot(&state.db, id)
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+/// GET /api/events/:id/live/stream - Server-sent events following the ride's live position
+async fn live_stream(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    approved_event(&state.db, id).await?;
+    live::end_idle_sessions(&state.db, &state.live).await?;
+    let receiver = state.live.subscribe(id);
+    let snapshot = live::snapshot(&state.db, id).await?;
+    Ok(live::stream(receiver, snapshot))
+}
+
+/// GET /api/events/:id/live/sessions - Every live session of a ride, newest first
+async fn list_live_sessions(
+    State(state): State<Arc<AppState>>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<LiveSessionsResponse>, ApiError> {
+    approved_event(&state.db, id).await?;
+    let sessions: Vec<LiveSession> = sqlx::query_as(&format!(
+        "SELECT {} FROM live_sessions WHERE event_id = ? ORDER BY id DESC",
+        live::SESSION_COLUMNS
+    ))
+    .bind(id)
+    .fetch_all(&state.db)
+    .await?;
+
+    let total = sessions.len();
+    Ok(Json(LiveSessionsResponse { sessions, total }))
+}
+
+/// GET /api/live - Rides being tracked right now
+async fn list_live_now(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<LiveNowResponse>, ApiError> {
+    live::end_idle_sessions(&state.db, &state.live).await?;
+    let active: Vec<LiveSession> = sqlx::query_as(
+        r#"
+        SELECT s.id, s.event_id, s.started_at, s.last_fix_at, s.ended_at, s.end_reason
+        FROM live_sessions s
+        JOIN events e ON e.id = s.event_id
+        WHERE s.ended_at IS NULL AND e.status = 'approved'
+        ORDER BY s.started_at
+        "#,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut sessions = Vec::with_capacity(active.len());
+    for session in active {
+        sessions.push(live::session_snapshot(&state.db, session).await?);
+    }
+    let total = sessions.len();
+    Ok(Json(LiveNowResponse { sessions, total }))
+}
+
+/// POST /api/live/:session_id/positions - Report a GPS fix (requires X-Live-Token)
+async fn post_live_position(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(session_id): AppPath<i64>,
+    AppJson(payload): AppJson<LiveFixRequest>,
+) -> Result<(StatusCode, Json<LivePosition>), ApiError> {
+    let token = live_token(&headers).ok_or(ApiError::Unauthorized)?;
+    payload.validate()?;
+
+    live::end_idle_sessions(&state.db, &state.live).await?;
+    let session = live::session_for_token(&state.db, session_id, token)
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+    if session.ended_at.is_some() {
+        return Err(ApiError::conflict("this live session has ended"));
+    }
+
+    // Devices may send a buffered fix late, but not one from the future
+    let now = Utc::now();
+    let recorded_at = payload.recorded_at.unwrap_or(now);
+    if recorded_at > now + Duration::minutes(5) {
+        return Err(ApiError::field(
+            "recorded_at",
+            "in_future",
+            "cannot be in the future",
+        ));
+    }
+    if recorded_at < session.started_at - Duration::minutes(5) {
+        return Err(ApiError::field(
+            "recorded_at",
+            "before_session",
+            "cannot be before the session started",
+        ));
+    }
+
+    let position = live::record_fix(&state.db, &session, &payload, recorded_at).await?;
+    state
+        .live
+        .publish(session.event_id, LiveMessage::Position(position.clone()));
+    Ok((StatusCode::CREATED, Json(position)))
+}
+
+/// POST /api/live/:session_id/end - Stop sharing the ride's position (requires X-Live-Token or
+/// an account managing the event)
+async fn end_live_session(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    AppPath(session_id): AppPath<i64>,
+) -> Result<Json<LiveSession>, ApiError> {
+    let session = match live_token(&headers) {
+        Some(token) => live::session_for_token(&state.db, session_id, token)
+            .await?
+            .ok_or(ApiError::Unauthorized)?,
+        None => {
+            let principal = auth::authenticate(&state.db, &headers).await?;
+            let session = live::fetch_session(&state.db, session_id)
+                .await?
+                .ok_or(ApiError::NotFound)?;
+            let event = db::fetch_event(&state.db, session.event_id)
+                .await?
+                .ok_or(ApiError::NotFound)?;
+            principal.require(Permission::ManageEvents(event.organizer_id))?;
+            session
+        }
+    };
+
+    let ended = live::end_session(&state.db, session.id, "stopped", Utc::now())
+        .await?
+        .ok_or_else(|| ApiError::conflict("this live session has already ended"))?;
+    state
+        .live
+        .publish(ended.event_id, LiveMessage::Ended(ended.clone()));
+
+    tracing::info!("Live session {} stopped", ended.id);
+    Ok(Json(ended))
+}
+
+/// GET /api/live/:session_id/track - Every recorded position of a session, for replay
+async fn get_live_track(
+    State(state): State<Arc<AppState>>,
+    AppPath(session_id): AppPath<i64>,
+) -> Result<Json<LiveTrackResponse>, ApiError> {
+    let session = live::fetch_session(&state.db, session_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    approved_event(&state.db, session.event_id).await?;
+
+    let positions = live::track(&state.db, session.id).await?;
+    let distance_km = live::distance_km(&positions);
+    Ok(Json(LiveTrackResponse {
+        session,
+        positions,
+        distance_km,
+    }))
+}
+
+// ===== Revision Review Endpoints =====
+
+/// GET /api/admin/revisions - List submitter edits waiting for review
+async fn list_revisions(
+    State(state): State<Arc<AppState>>,
+    _: RequireRole<Viewer>,
+) -> Result<Json<RevisionsResponse>, ApiError> {
+    let revisions: Vec<EventRevision> = sqlx::query_as(
+        r#"
+        SELECT id, event_id, changes_json, status, reviewer, created_at, reviewed_at
+        FROM event_revisions
+        WHERE status = 'pending'
+        ORDER BY created_at ASC
+        "#,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let total = revisions.len();
+    Ok(Json(RevisionsResponse { revisions, total }))
+}
+
+/// PATCH /api/admin/revisions/:id/approve - Apply a submitter edit to the live event
+async fn approve_revision(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+) -> Result<Json<Event>, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let revision: EventRevision = sqlx::query_as(
+        r#"
+        SELECT id, event_id, changes_json, status, reviewer, created_at, reviewed_at
+        FROM event_revisions
+        WHERE id = ? AND status = 'pending'
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    let before = db::fetch_event(&mut *tx, revision.event_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let changes = UpdateEventRequest {
+        status: None,
+        ..revision.changes.0.clone()
+    };
+    // The event may have changed since the revision was queued
+    let schedule = Schedule::updated(&before, &changes).map_err(ApiError::conflict)?;
+    db::apply_event_update(&mut *tx, revision.event_id, &changes, &schedule, None).await?;
+
+    sqlx::query(
+        "UPDATE event_revisions SET status = 'approved', reviewer = ?, reviewed_at = ? WHERE id = ?",
+    )
+    .bind(&admin.username)
+    .bind(Utc::now())
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    let event = db::fetch_event(&mut *tx, revision.event_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "event.update",
+            entity_type: audit::ENTITY_EVENT,
+            entity_id: revision.event_id,
+            before: audit::snapshot(&before),
+            after: audit::snapshot(&event),
+            reason: Some(format!("Submitter revision {} approved", id)),
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+    state.updates.publish(Some(&before), Some(event.clone()));
+
+    tracing::info!("Revision {} approved by {}", id, admin.username);
+    Ok(Json(event))
+}
+
+/// PATCH /api/admin/revisions/:id/reject - Discard a submitter edit
+async fn reject_revision(
+    State(state): State<Arc<AppState>>,
+    RequireRole { admin, .. }: RequireRole<Moderator>,
+    AppPath(id): AppPath<i64>,
+) -> Result<StatusCode, ApiError> {
+    let mut tx = state.db.begin().await?;
+
+    let revision: EventRevision = sqlx::query_as(
+        r#"
+        SELECT id, event_id, changes_json, status, reviewer, created_at, reviewed_at
+        FROM event_revisions
+        WHERE id = ? AND status = 'pending'
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::NotFound)?;
+
+    sqlx::query(
+        "UPDATE event_revisions SET status = 'rejected', reviewer = ?, reviewed_at = ? WHERE id = ?",
+    )
+    .bind(&admin.username)
+    .bind(Utc::now())
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    let rejected = EventRevision {
+        status: "rejected".to_string(),
+        reviewer: Some(admin.username.clone()),
+        ..revision.clone()
+    };
+    audit::record(
+        &mut *tx,
+        AuditRecord {
+            actor: &admin,
+            action: "revision.reject",
+            entity_type: audit::ENTITY_REVISION,
+            entity_id: id,
+            before: audit::snapshot(&revision),
+            after: audit::snapshot(&rejected),
+            reason: None,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Revision {} rejected by {}", id, admin.username);
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::live::Tracker;
+    use crate::updates::Updates;
+    use axum::body::Body;
+    use axum::http::{Method, Request};
+    use sqlx::sqlite::SqlitePoolOptions;
+    use tower::ServiceExt;
+
+    /// Every role-protected admin endpoint with the lowest role allowed to call it.
+    /// Ids refer to rows that do not exist, so allowed calls change nothing.
+    const ENDPOINTS: &[(Method, &str, Role)] = &[
+        (Method::GET, "/api/admin/me", Role::Viewer),
+        (Method::GET, "/api/admin/events", Role::Viewer),
+        (Method::GET, "/api/admin/events/pending", Role::Viewer),
+        (
+            Method::GET,
+            "/api/admin/events/999/moderation",
+            Role::Viewer,
+        ),
+        (Method::GET, "/api/admin/events/999/lifecycle", Role::Viewer),
+        (Method::GET, "/api/admin/suggestions", Role::Viewer),
+        (Method::GET, "/api/admin/revisions", Role::Viewer),
+        (
+            Method::GET,
+            "/api/admin/organizers/999/aliases",
+            Role::Viewer,
+        ),
+        (Method::GET, "/api/admin/audit", Role::Viewer),
+        (
+            Method::PATCH,
+            "/api/admin/events/999/approve",
+            Role::Moderator,
+        ),
+        (
+            Method::PATCH,
+            "/api/admin/events/999/reject",
+            Role::Moderator,
+        ),
+        (Method::POST, "/api/admin/events/999/merge", Role::Moderator),
+        (
+            Method::PATCH,
+            "/api/admin/suggestions/999/approve",
+            Role::Moderator,
+        ),
+        (
+            Method::PATCH,
+            "/api/admin/suggestions/999/reject",
+            Role::Moderator,
+        ),
+        (
+            Method::PATCH,
+            "/api/admin/revisions/999/approve",
+            Role::Moderator,
+        ),
+        (
+            Method::PATCH,
+            "/api/admin/revisions/999/reject",
+            Role::Moderator,
+        ),
+        (Method::PUT, "/api/admin/events/999", Role::Editor),
+        (
+            Method::PATCH,
+            "/api/admin/events/999/lifecycle",
+            Role::Editor,
+        ),
+        (Method::PUT, "/api/admin/events/999/organizer", Role::Editor),
+        (
+            Method::PUT,
+            "/api/admin/events/999/occurrences/2026-01-01T00:00:00Z",
+            Role::Editor,
+        ),
+        (
+            Method::DELETE,
+            "/api/admin/events/999/occurrences/2026-01-01T00:00:00Z",
+            Role::Editor,
+        ),
+        (Method::POST, "/api/admin/organizers", Role::Editor),
+        (Method::POST, "/api/admin/organizers/backfill", Role::Editor),
+        (Method::PUT, "/api/admin/organizers/999", Role::Editor),
+        (
+            Method::POST,
+            "/api/admin/organizers/999/merge",
+            Role::Editor,
+        ),
+        (
+            Method::POST,
+            "/api/admin/organizers/999/aliases",
+            Role::Editor,
+        ),
+        (
+            Method::DELETE,
+            "/api/admin/organizers/999/aliases/999",
+            Role::Editor,
+        ),
+        (Method::DELETE, "/api/admin/events/999", Role::Owner),
+        (Method::DELETE, "/api/admin/organizers/999", Role::Owner),
+        (Method::GET, "/api/admin/organizer-accounts", Role::Owner),
+        (Method::POST, "/api/admin/organizer-accounts", Role::Owner),
+        (
+            Method::PUT,
+            "/api/admin/organizer-accounts/999",
+            Role::Owner,
+        ),
+        (
+            Method::DELETE,
+            "/api/admin/organizer-accounts/999",
+            Role::Owner,
+        ),
+        (Method::GET, "/api/admin/users", Role::Owner),
+        (Method::POST, "/api/admin/users", Role::Owner),
+        (Method::PUT, "/api/admin/users/999", Role::Owner),
+        (Method::DELETE, "/api/admin/users/999", Role::Owner),
+    ];
+
+    const ROLES: [Role; 4] = [Role::Viewer, Role::Moderator, Role::Editor, Role::Owner];
+
+    pub(crate) async fn test_app() -> (Router, sqlx::SqlitePool) {
+        // One connection, so every query sees the same in-memory database
+        let db = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!().run(&db).await.unwrap();
+        let app = api_router().with_state(Arc::new(AppState {
+            db: db.clone(),
+            updates: Updates::new(),
+            live: Tracker::new(),
+        }));
+        (app, db)
+    }
+
+    /// Session token for a new admin with `role`
+    pub(crate) async fn admin_token(db: &sqlx::SqlitePool, role: Role) -> String {
+        let result =
+            sqlx::query("INSERT INTO admins (username, password_hash, role) VALUES (?, '', ?)")
+                .bind(role.to_string())
+                .bind(role.to_string())
+                .execute(db)
+                .await
+                .unwrap();
+        let (token, _) = auth::create_session(db, result.last_insert_rowid())
+            .await
+            .unwrap();
+        token
+    }
+
+    async fn status(app: &Router, method: &Method, uri: &str, token: Option<&str>) -> StatusCode {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::from("{}")).unwrap())
+            .await
+            .unwrap();
+        response.status()
+    }
+
+    #[tokio::test]
+    async fn test_role_endpoint_matrix() {
+        let (app, db) = test_app().await;
+        let mut tokens = Vec::new();
+        for role in ROLES {
+            tokens.push((role, admin_token(&db, role).await));
+        }
+
+        for (method, uri, minimum) in ENDPOINTS {
+            assert_eq!(
+                status(&app, method, uri, None).await,
+                StatusCode::UNAUTHORIZED,
+                "{} {} without credentials",
+                method,
+                uri
+            );
+            for (role, token) in &tokens {
+                let status = status(&app, method, uri, Some(token)).await;
+                if role >= minimum {
+                    assert!(
+                        status != StatusCode::FORBIDDEN && status != StatusCode::UNAUTHORIZED,
+                        "{} {} as {} got {}",
+                        method,
+                        uri,
+                        role,
+                        status
+                    );
+                } else {
+                    assert_eq!(
+                        status,
+                        StatusCode::FORBIDDEN,
+                        "{} {} as {}",
+                        method,
+                        uri,
+                        role
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_organizer_accounts_cannot_use_admin_api() {
+        let (app, db) = test_app().await;
+        let result =
+            sqlx::query("INSERT INTO organizer_accounts (username, password_hash, verified) VALUES ('crew', '', 1)")
+                .execute(&db)
+                .await
+                .unwrap();
+        let (token, _) = auth::create_organizer_session(&db, result.last_insert_rowid())
+            .await
+            .unwrap();
+
+        for (method, uri, _) in ENDPOINTS {
+            assert_eq!(
+                status(&app, method, uri, Some(&token)).await,
+                StatusCode::FORBIDDEN,
+                "{} {}",
+                method,
+                uri
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_organizer_edits_follow_moderation_and_validation() {
+        let (app, db) = test_app().await;
+        let moderator = admin_token(&db, Role::Moderator).await;
+        for (name, slug) in [("Crew", "crew"), ("Others", "others")] {
+            sqlx::query("INSERT INTO organizers (name, slug) VALUES (?, ?)")
+                .bind(name)
+                .bind(slug)
+                .execute(&db)
+                .await
+                .unwrap();
+        }
+        let result = sqlx::query(
+            "INSERT INTO organizer_accounts (username, password_hash) VALUES ('crew', '')",
+        )
+        .execute(&db)
+        .await
+        .unwrap();
+        let account = result.last_insert_rowid();
+        sqlx::query(
+            "INSERT INTO organizer_account_organizers (account_id, organizer_id) VALUES (?, 1)",
+        )
+        .bind(account)
+        .execute(&db)
+        .await
+        .unwrap();
+        let (token, _) = auth::create_organizer_session(&db, account).await.unwrap();
+
+        let send = |method: Method, uri: &str, bearer: &str, body: &'static str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", bearer))
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        let json = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+        let submission = r#"{"title":"Crew Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        for organizer_id in [1, 2] {
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/events")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(submission))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            let id = json(response).await["id"].as_i64().unwrap();
+            sqlx::query("UPDATE events SET organizer_id = ? WHERE id = ?")
+                .bind(organizer_id)
+                .bind(id)
+                .execute(&db)
+                .await
+                .unwrap();
+        }
+
+        // Links are checked like everywhere else
+        for body in [
+            r#"{"event_link":"javascript:alert(document.cookie)"}"#,
+            r#"{"image_url":"not a url"}"#,
+        ] {
+            let response = send(Method::PUT, "/api/organizer/events/1", &token, body)
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", body);
+            let fields = json(response).await["fields"].clone();
+            assert!(fields
+                .as_object()
+                .unwrap()
+                .values()
+                .all(|f| f[0]["code"] == "url"));
+        }
+
+        // Other organizers' events are off limits
+        let response = send(
+            Method::PUT,
+            "/api/organizer/events/2",
+            &token,
+            r#"{"title":"Not mine"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // Pending events change at once
+        let response = send(
+            Method::PUT,
+            "/api/organizer/events/1",
+            &token,
+            r#"{"title":"Crew Ride (fixed)","event_link":"https://ra.co/events/1"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Crew Ride (fixed)");
+        assert_eq!(event.event_link.as_deref(), Some("https://ra.co/events/1"));
+
+        // Published events wait for review until the account is verified
+        send(Method::PATCH, "/api/admin/events/1/approve", &moderator, "")
+            .await
+            .unwrap();
+        let response = send(
+            Method::PUT,
+            "/api/organizer/events/1",
+            &token,
+            r#"{"title":"Crew Ride (moved)"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            json(response).await["pending_revision"]["changes"]["title"],
+            "Crew Ride (moved)"
+        );
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Crew Ride (fixed)");
+
+        sqlx::query("UPDATE organizer_accounts SET verified = 1")
+            .execute(&db)
+            .await
+            .unwrap();
+        let response = send(
+            Method::PUT,
+            "/api/organizer/events/1",
+            &token,
+            r#"{"title":"Crew Ride (verified)"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Crew Ride (verified)");
+        assert_eq!(event.status, EventStatus::Approved);
+
+        // Moderation and the organizer link stay with admins
+        for body in [r#"{"status":"rejected"}"#, r#"{"organizer":"Others"}"#] {
+            let response = send(Method::PUT, "/api/organizer/events/1", &token, body)
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", body);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_organizer_backfill_is_audited() {
+        let (app, db) = test_app().await;
+        let editor = admin_token(&db, Role::Editor).await;
+        let send = |uri: &str, token: Option<&str>, body: &'static str| {
+            let mut request = Request::builder()
+                .method(Method::POST)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json");
+            if let Some(token) = token {
+                request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+
+        // Submitted before the organizer existed, so it stays unlinked
+        let response = send(
+            "/api/events",
+            None,
+            r#"{"title":"Backfill Ride","organizer":"Crew","location_name":"Rynek",
+                "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        sqlx::query("INSERT INTO organizers (name, slug) VALUES ('Crew', 'crew')")
+            .execute(&db)
+            .await
+            .unwrap();
+
+        let response = send("/api/admin/organizers/backfill", Some(&editor), "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let summary: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(summary["linked"], 1);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.organizer_id, Some(1));
+
+        let (actor, entity_id, diff, reason): (String, i64, String, Option<String>) =
+            sqlx::query_as(
+                "SELECT actor, entity_id, diff_json, reason FROM audit_log WHERE action = 'event.update'",
+            )
+            .fetch_one(&db)
+            .await
+            .unwrap();
+        assert_eq!(actor, "editor");
+        assert_eq!(entity_id, 1);
+        assert_eq!(reason.as_deref(), Some("organizer backfill"));
+        let diff: serde_json::Value = serde_json::from_str(&diff).unwrap();
+        assert_eq!(
+            diff,
+            serde_json::json!({ "organizer_id": { "before": null, "after": 1 } })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_owner_cannot_be_demoted() {
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Owner).await;
+
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri("/api/admin/users/1")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::from(r#"{"role":"editor"}"#))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            status(&app, &Method::DELETE, "/api/admin/users/1", Some(&token)).await,
+            StatusCode::CONFLICT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_json_with_field_detail() {
+        let (app, _) = test_app().await;
+        let post = |body: &'static str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/events")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+        let json = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"title":"DB","organizer":"Crew","location_name":"Rynek",
+                    "latitude":95,"longitude":19.9,"event_date":"2026-06-01T18:00:00Z",
+                    "video_url":"not a url"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = json(response).await;
+        assert_eq!(body["code"], "validation_failed");
+        assert_eq!(body["fields"]["title"][0]["code"], "length");
+        assert_eq!(body["fields"]["latitude"][0]["code"], "range");
+        assert_eq!(body["fields"]["video_url"][0]["code"], "url");
+        assert!(body["fields"].get("organizer").is_none());
+
+        // Bodies that don't deserialize get the same shape
+        let response = app.clone().oneshot(post(r#"{"title":"#)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(json(response).await["code"], "bad_request");
+
+        // Values of the wrong type are reported on their field
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"title":"Ride","organizer":"Crew","location_name":"Rynek",
+                    "latitude":"north","longitude":19.9,"event_date":"2026-06-01T18:00:00Z"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = json(response).await;
+        assert_eq!(body["code"], "validation_failed");
+        assert_eq!(body["fields"]["latitude"][0]["code"], "invalid_type");
+        assert!(body["fields"]["latitude"][0]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid type: string \"north\""));
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"title":"Ride","organizer":"Crew","location_name":"Rynek",
+                    "latitude":50.0,"longitude":19.9,"event_date":"2026-06-01T18:00:00Z",
+                    "exdates":["2026-06-08T18:00:00Z", 7]}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            json(response).await["fields"]["exdates[1]"][0]["code"],
+            "invalid_type"
+        );
+        let response = app
+            .clone()
+            .oneshot(post(r#"{"organizer":"Crew","location_name":"Rynek"}"#))
+            .await
+            .unwrap();
+        let body = json(response).await;
+        assert_eq!(body["fields"]["title"][0]["code"], "required");
+
+        // So are path parameters that don't parse
+        for uri in ["/api/events/abc", "/api/events/1.5/calendar.ics"] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+            assert_eq!(
+                response.headers()[header::CONTENT_TYPE],
+                "application/json",
+                "{}",
+                uri
+            );
+            let body = json(response).await;
+            assert_eq!(body["code"], "bad_request");
+            assert_eq!(body["message"], "path parameter must be an integer");
+        }
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/admin/events")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(json(response).await["code"], "unauthorized");
+    }
+
+    #[tokio::test]
+    async fn test_feeds_follow_approvals_and_support_conditional_get() {
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Owner).await;
+        let send = |method: Method, uri: &str, headers: &[(header::HeaderName, &str)], body| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", token));
+            for (name, value) in headers {
+                request = request.header(name, *value);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        let text = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+
+        let submission = r#"{"title":"Feed Ride","organizer":"Crew","location_name":"Rynek",
+            "country":"Poland","latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        send(Method::POST, "/api/events", &[], submission)
+            .await
+            .unwrap();
+
+        // Pending submissions stay out of the feed
+        let response = send(Method::GET, "/api/events/feed.rss", &[], "")
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/rss+xml; charset=utf-8"
+        );
+        assert!(response.headers().get(header::LAST_MODIFIED).is_none());
+        assert!(!text(response).await.contains("Feed Ride"));
+
+        send(Method::PATCH, "/api/admin/events/1/approve", &[], "")
+            .await
+            .unwrap();
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        let approved_at = event.approved_at.expect("approval is stamped");
+        assert_eq!(event.updated_at, approved_at);
+
+        let response = send(
+            Method::GET,
+            "/api/events/by-country/Poland/feed.atom",
+            &[],
+            "",
+        )
+        .await
+        .unwrap();
+        let etag = response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let last_modified = response.headers()[header::LAST_MODIFIED]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(text(response).await.contains("<title>Feed Ride</title>"));
+
+        let response = send(
+            Method::GET,
+            "/api/events/by-country/Poland/feed.atom",
+            &[(header::IF_NONE_MATCH, &etag)],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        let response = send(
+            Method::GET,
+            "/api/events/by-country/Poland/feed.atom",
+            &[(header::IF_MODIFIED_SINCE, &last_modified)],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        // An edit moves `updated_at` but keeps the approval time
+        send(
+            Method::PUT,
+            "/api/admin/events/1",
+            &[],
+            r#"{"title":"Feed Ride 2"}"#,
+        )
+        .await
+        .unwrap();
+        let edited = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(edited.approved_at, Some(approved_at));
+        assert!(edited.updated_at > approved_at);
+        let response = send(
+            Method::GET,
+            "/api/events/by-country/Poland/feed.atom",
+            &[(header::IF_NONE_MATCH, &etag)],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(text(response).await.contains("<title>Feed Ride 2</title>"));
+
+        assert_eq!(
+            send(
+                Method::GET,
+                "/api/events/by-organizer/nobody/feed.rss",
+                &[],
+                ""
+            )
+            .await
+            .unwrap()
+            .status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_share_pages_and_downloads_only_for_approved_events() {
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Owner).await;
+        let send = |method: Method, uri: &str, body: &'static str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+
+        let submission = r#"{"title":"Share <Ride>","organizer":"Crew","location_name":"Rynek",
+            "country":"Poland","latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z",
+            "video_url":"https://youtu.be/9k2CnY5rCzM"}"#;
+        send(Method::POST, "/api/events", submission).await.unwrap();
+        let public = [
+            "/api/events/1/share",
+            "/api/events/1/event.jsonld",
+            "/api/events/1/calendar.ics",
+            "/api/events/1/occurrences",
+        ];
+        for uri in public {
+            let response = send(Method::GET, uri, "").await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", uri);
+        }
+
+        send(Method::PATCH, "/api/admin/events/1/approve", "")
+            .await
+            .unwrap();
+        for uri in public {
+            let response = send(Method::GET, uri, "").await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+        }
+        let response = send(Method::GET, "/api/events/1/event.jsonld", "")
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/ld+json"
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let doc: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(doc["name"], "Share <Ride>");
+        assert_eq!(doc["startDate"], "2030-06-01T20:00:00+02:00");
+        assert_eq!(doc["recordedIn"]["@type"], "VideoObject");
+
+        let response = send(Method::GET, "/api/events/1/share", "").await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(html.contains(r#"<meta property="og:title" content="Share &lt;Ride&gt;" />"#));
+        assert!(html.contains(
+            r#"<meta property="og:image" content="https://i.ytimg.com/vi/9k2CnY5rCzM/hqdefault.jpg" />"#
+        ));
+        assert!(html.contains(r#"<meta name="twitter:card" content="summary_large_image" />"#));
+        // The title inside the JSON-LD can't close its script element
+        assert!(html.contains(r#""name":"Share <Ride>""#));
+        assert!(!html.contains("<Ride></script>"));
+    }
+
+    #[tokio::test]
+    async fn test_streams_publish_changes_by_audience() {
+        use tokio_stream::StreamExt;
+
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Owner).await;
+        let send = |method: Method, uri: &str, last_event_id: Option<&str>, body: &'static str| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", token));
+            if let Some(id) = last_event_id {
+                request = request.header("last-event-id", id);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        // The `event:` and `id:` of the next `count` messages
+        let read = |response: axum::response::Response, count: usize| async move {
+            let mut body = response.into_body().into_data_stream();
+            let mut text = String::new();
+            while text.matches("\n\n").count() < count {
+                let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+                    .await
+                    .expect("stream stalled")
+                    .unwrap()
+                    .unwrap();
+                text.push_str(std::str::from_utf8(&chunk).unwrap());
+            }
+            text.split("\n\n")
+                .filter(|message| !message.is_empty())
+                .map(|message| {
+                    let field = |name: &str| {
+                        message
+                            .lines()
+                            .find_map(|line| line.strip_prefix(name))
+                            .unwrap_or_default()
+                            .to_string()
+                    };
+                    (field("event: "), field("id: "), field("data: "))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let public = send(Method::GET, "/api/events/stream", None, "")
+            .await
+            .unwrap();
+        assert_eq!(public.headers()[header::CONTENT_TYPE], "text/event-stream");
+        let admin = send(Method::GET, "/api/admin/events/stream", None, "")
+            .await
+            .unwrap();
+
+        let submission = r#"{"title":"Live Ride","organizer":"Crew","location_name":"Rynek",
+            "country":"Poland","latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        send(Method::POST, "/api/events", None, submission)
+            .await
+            .unwrap();
+        send(Method::PATCH, "/api/admin/events/1/approve", None, "")
+            .await
+            .unwrap();
+        send(
+            Method::PATCH,
+            "/api/admin/events/1/lifecycle",
+            None,
+            r#"{"state":"cancelled"}"#,
+        )
+        .await
+        .unwrap();
+        send(Method::DELETE, "/api/admin/events/1", None, "")
+            .await
+            .unwrap();
+
+        // The pending submission is only visible to moderators
+        let kinds = |messages: &[(String, String, String)]| {
+            messages
+                .iter()
+                .map(|(kind, _, _)| kind.clone())
+                .collect::<Vec<_>>()
+        };
+        let admin = read(admin, 4).await;
+        assert_eq!(
+            kinds(&admin),
+            ["created", "approved", "cancelled", "deleted"]
+        );
+        let public = read(public, 3).await;
+        assert_eq!(kinds(&public), ["approved", "cancelled", "deleted"]);
+        assert!(public[0].2.contains(r#""title":"Live Ride""#));
+        assert_eq!(public[2].2, r#"{"id":1,"event":null}"#);
+
+        // Reconnecting resumes after the last id seen
+        let resumed = send(Method::GET, "/api/events/stream", Some(&public[0].1), "")
+            .await
+            .unwrap();
+        let resumed = read(resumed, 2).await;
+        assert_eq!(resumed[0].1, public[1].1);
+        assert_eq!(kinds(&resumed), ["cancelled", "deleted"]);
+
+        let stale = send(Method::GET, "/api/events/stream", Some("1-1"), "")
+            .await
+            .unwrap();
+        assert_eq!(kinds(&read(stale, 1).await), ["reset"]);
+
+        let anonymous = Request::builder()
+            .uri("/api/admin/events/stream")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            app.clone().oneshot(anonymous).await.unwrap().status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_live_session_from_start_to_replay() {
+        use tokio_stream::StreamExt;
+
+        let (app, db) = test_app().await;
+        let editor = admin_token(&db, Role::Editor).await;
+        let moderator = admin_token(&db, Role::Moderator).await;
+        let send = |method: Method, uri: &str, headers: &[(&str, &str)], body: &'static str| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json");
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        let json = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+        let bearer = |token: &str| format!("Bearer {}", token);
+        let editor = bearer(&editor);
+        let as_editor = [("authorization", editor.as_str())];
+
+        let submission = r#"{"title":"Tracked Ride","organizer":"Crew","location_name":"Rynek",
+            "country":"Poland","latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        send(Method::POST, "/api/events", &[], submission)
+            .await
+            .unwrap();
+
+        // Only published rides can be followed or tracked
+        let response = send(Method::GET, "/api/events/1/live/stream", &[], "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = send(Method::POST, "/api/events/1/live", &as_editor, "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        send(Method::PATCH, "/api/admin/events/1/approve", &as_editor, "")
+            .await
+            .unwrap();
+
+        let stream = send(Method::GET, "/api/events/1/live/stream", &[], "")
+            .await
+            .unwrap();
+        assert_eq!(stream.headers()[header::CONTENT_TYPE], "text/event-stream");
+        let response = send(Method::GET, "/api/events/1/live", &[], "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // Moderators can't manage events
+        let response = send(
+            Method::POST,
+            "/api/events/1/live",
+            &[("authorization", &bearer(&moderator))],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = send(Method::POST, "/api/events/1/live", &as_editor, "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let started = json(response).await;
+        let token = started["token"].as_str().unwrap().to_string();
+        let as_device = [("x-live-token", token.as_str())];
+        let response = send(Method::POST, "/api/events/1/live", &as_editor, "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let fix = r#"{"latitude":50.06,"longitude":19.94}"#;
+        for headers in [&[][..], &[("x-live-token", "wrong")][..]] {
+            let response = send(Method::POST, "/api/live/1/positions", headers, fix)
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        let response = send(
+            Method::POST,
+            "/api/live/1/positions",
+            &as_device,
+            r#"{"latitude":95,"longitude":19.94}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = send(Method::POST, "/api/live/1/positions", &as_device, fix)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        // Riding north: the heading comes from the previous fix
+        let response = send(
+            Method::POST,
+            "/api/live/1/positions",
+            &as_device,
+            r#"{"latitude":50.07,"longitude":19.94,"speed":5.5}"#,
+        )
+        .await
+        .unwrap();
+        let position = json(response).await;
+        assert!(position["heading"].as_f64().unwrap().abs() < 0.5);
+
+        // Followers see the snapshot, then everything as it happens
+        let mut body = stream.into_body().into_data_stream();
+        let mut text = String::new();
+        while text.matches("\n\n").count() < 4 {
+            let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+                .await
+                .expect("stream stalled")
+                .unwrap()
+                .unwrap();
+            text.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+        let kinds: Vec<&str> = text
+            .lines()
+            .filter_map(|line| line.strip_prefix("event: "))
+            .collect();
+        assert_eq!(kinds, ["snapshot", "started", "position", "position"]);
+        assert!(text.contains("data: null"));
+
+        let response = send(Method::GET, "/api/events/1/live", &[], "")
+            .await
+            .unwrap();
+        let snapshot = json(response).await;
+        assert_eq!(snapshot["position"]["latitude"], 50.07);
+        assert_eq!(snapshot["trail"].as_array().unwrap().len(), 2);
+        let response = send(Method::GET, "/api/live", &[], "").await.unwrap();
+        assert_eq!(json(response).await["total"], 1);
+
+        let response = send(Method::POST, "/api/live/1/end", &as_device, "")
+            .await
+            .unwrap();
+        assert_eq!(json(response).await["end_reason"], "stopped");
+        for (uri, body) in [("/api/live/1/end", ""), ("/api/live/1/positions", fix)] {
+            let response = send(Method::POST, uri, &as_device, body).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT, "{}", uri);
+        }
+
+        // The track stays for replay
+        let response = send(Method::GET, "/api/live/1/track", &[], "")
+            .await
+            .unwrap();
+        let track = json(response).await;
+        assert_eq!(track["positions"].as_array().unwrap().len(), 2);
+        assert!((track["distance_km"].as_f64().unwrap() - 1.11).abs() < 0.01);
+
+        // A session that goes quiet ends as of its last sign of life
+        let response = send(Method::POST, "/api/events/1/live", &as_editor, "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        sqlx::query("UPDATE live_sessions SET started_at = ? WHERE id = 2")
+            .bind(Utc::now() - Duration::minutes(live::IDLE_MINUTES + 1))
+            .execute(&db)
+            .await
+            .unwrap();
+        let response = send(Method::GET, "/api/events/1/live", &[], "")
+            .await
+            .unwrap();
+        let snapshot = json(response).await;
+        assert_eq!(snapshot["session"]["id"], 2);
+        assert_eq!(snapshot["session"]["end_reason"], "inactive");
+        assert_eq!(
+            snapshot["session"]["ended_at"],
+            snapshot["session"]["started_at"]
+        );
+        let response = send(Method::GET, "/api/events/1/live/sessions", &[], "")
+            .await
+            .unwrap();
+        assert_eq!(json(response).await["total"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_planned_route_upload_and_gpx_export() {
+        let (app, db) = test_app().await;
+        let editor = format!("Bearer {}", admin_token(&db, Role::Editor).await);
+        let moderator = format!("Bearer {}", admin_token(&db, Role::Moderator).await);
+        let send = |method: Method, uri: &str, token: Option<&str>, content_type: &str, body| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, content_type);
+            if let Some(token) = token {
+                request = request.header(header::AUTHORIZATION, token);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        let text = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+        const JSON: &str = "application/json";
+
+        let submission = r#"{"title":"Route <Ride>","organizer":"Crew","location_name":"Rynek",
+            "country":"Poland","latitude":52.2,"longitude":21.0,"event_date":"2030-06-01T18:00:00Z"}"#;
+        send(
+            Method::POST,
+            "/api/events",
+            None,
+            JSON,
+            submission.to_string(),
+        )
+        .await
+        .unwrap();
+        send(
+            Method::PATCH,
+            "/api/admin/events/1/approve",
+            Some(&editor),
+            JSON,
+            String::new(),
+        )
+        .await
+        .unwrap();
+
+        // ~2.1 km east then north, recorded every ~100 m with a little GPS jitter
+        let mut gpx = String::from(r#"<?xml version="1.0"?><gpx version="1.1"><trk><trkseg>"#);
+        for i in 0..=10 {
+            let jitter = if i % 2 == 0 { 0.00002 } else { -0.00002 };
+            gpx.push_str(&format!(
+                r#"<trkpt lat="{}" lon="{}"/>"#,
+                52.2 + jitter,
+                21.0 + i as f64 * 0.0015
+            ));
+        }
+        for i in 1..=10 {
+            gpx.push_str(&format!(
+                r#"<trkpt lat="{}" lon="21.015"/>"#,
+                52.2 + i as f64 * 0.001
+            ));
+        }
+        gpx.push_str("</trkseg></trk></gpx>");
+
+        for (token, status) in [
+            (None, StatusCode::UNAUTHORIZED),
+            (Some(moderator.as_str()), StatusCode::FORBIDDEN),
+        ] {
+            let response = send(
+                Method::PUT,
+                "/api/events/1/route",
+                token,
+                gpx::CONTENT_TYPE,
+                gpx.clone(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.status(), status);
+        }
+        let response = send(
+            Method::PUT,
+            "/api/events/1/route",
+            Some(&editor),
+            gpx::CONTENT_TYPE,
+            gpx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let route: serde_json::Value = serde_json::from_str(&text(response).await).unwrap();
+        assert_eq!(route["source_points"], 21);
+        // The jitter is simplified away, the corner stays
+        assert_eq!(
+            route["coordinates"],
+            serde_json::json!([[21.0, 52.20002], [21.015, 52.20002], [21.015, 52.21]])
+        );
+        assert!((route["distance_km"].as_f64().unwrap() - 2.14).abs() < 0.05);
+        assert_eq!(
+            route["bbox"],
+            serde_json::json!([21.0, 52.20002, 21.015, 52.21])
+        );
+
+        let response = send(Method::GET, "/api/events/1", None, JSON, String::new())
+            .await
+            .unwrap();
+        let detail: serde_json::Value = serde_json::from_str(&text(response).await).unwrap();
+        assert_eq!(detail["title"], "Route <Ride>");
+        assert_eq!(detail["route"]["coordinates"], route["coordinates"]);
+
+        let response = send(
+            Method::GET,
+            "/api/events/1/route.gpx",
+            None,
+            JSON,
+            String::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], gpx::CONTENT_TYPE);
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            r#"attachment; filename="dnbride-1.gpx""#
+        );
+        let exported = text(response).await;
+        assert!(exported.contains("<name>Route &lt;Ride&gt;</name>"));
+        let points = gpx::parse(&exported).unwrap();
+        assert_eq!(serde_json::json!(points), route["coordinates"]);
+
+        // GeoJSON replaces the route; broken uploads leave it alone
+        let line = r#"{"type":"Feature","geometry":{"type":"LineString","coordinates":[[21.0,52.2],[21.1,52.3]]}}"#;
+        let response = send(
+            Method::PUT,
+            "/api/events/1/route",
+            Some(&editor),
+            "application/geo+json",
+            line.to_string(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        for (content_type, body) in [
+            (JSON, r#"{"type":"LineString","coordinates":[[21.0,52.2]]}"#),
+            (
+                JSON,
+                r#"{"type":"LineString","coordinates":[[21.0,52.2],[200.0,52.3]]}"#,
+            ),
+            (JSON, r#"{"type":"Point","coordinates":[21.0,52.2]}"#),
+            ("application/xml", "<kml><Placemark/></kml>"),
+        ] {
+            let response = send(
+                Method::PUT,
+                "/api/events/1/route",
+                Some(&editor),
+                content_type,
+                body.to_string(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", body);
+        }
+        let (points,): (i64,) =
+            sqlx::query_as("SELECT source_points FROM event_routes WHERE event_id = 1")
+                .fetch_one(&db)
+                .await
+                .unwrap();
+        assert_eq!(points, 2);
+
+        let response = send(
+            Method::DELETE,
+            "/api/events/1/route",
+            Some(&editor),
+            JSON,
+            String::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        for (method, uri) in [
+            (Method::GET, "/api/events/1/route.gpx"),
+            (Method::DELETE, "/api/events/1/route"),
+        ] {
+            let response = send(method, uri, Some(&editor), JSON, String::new())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_area_queries_count_matches_before_limit() {
+        let (app, db) = test_app().await;
+        for (title, lng) in [
+            ("West", 21.0),
+            ("Middle", 21.01),
+            ("East", 21.02),
+            ("Far", 25.0),
+        ] {
+            let submission = format!(
+                r#"{{"title":"{}","organizer":"Crew","location_name":"Rynek",
+                    "latitude":52.2,"longitude":{},"event_date":"2030-06-01T18:00:00Z"}}"#,
+                title, lng
+            );
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/events")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(submission))
+                .unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+        sqlx::query("UPDATE events SET status = 'approved'")
+            .execute(&db)
+            .await
+            .unwrap();
+
+        let get = |uri: &str| {
+            app.clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        };
+        let json = |response: axum::response::Response| async move {
+            let status = response.status();
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert_eq!(
+                status,
+                StatusCode::OK,
+                "{}",
+                String::from_utf8_lossy(&bytes)
+            );
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let within = "/api/events/within?min_lat=52&min_lng=20.9&max_lat=52.5&max_lng=21.1&limit=2";
+        let body = json(get(within).await.unwrap()).await;
+        assert_eq!(body["events"].as_array().unwrap().len(), 2);
+        assert_eq!(body["total"], 3);
+
+        let nearby = "/api/events/nearby?lat=52.2&lng=21.0&radius_km=10&limit=1";
+        let body = json(get(nearby).await.unwrap()).await;
+        assert_eq!(body["events"].as_array().unwrap().len(), 1);
+        assert_eq!(body["events"][0]["title"], "West");
+        assert_eq!(body["total"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_deleting_event_removes_its_video_suggestions() {
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Owner).await;
+        let submission = r#"{"title":"Gone Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/events")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(submission))
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap();
+        sqlx::query("INSERT INTO video_suggestions (event_id, video_url) VALUES (1, 'https://youtu.be/9k2CnY5rCzM')")
+            .execute(&db)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            status(&app, &Method::DELETE, "/api/admin/events/1", Some(&token)).await,
+            StatusCode::NO_CONTENT
+        );
+        let (suggestions,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM video_suggestions")
+            .fetch_one(&db)
+            .await
+            .unwrap();
+        assert_eq!(suggestions, 0);
+        assert!(db::fetch_event(&db, 1).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_moderation_bodies_are_optional_but_must_parse() {
+        let (app, db) = test_app().await;
+        let token = admin_token(&db, Role::Moderator).await;
+        let send = |uri: &str, content_type: &str, body: &'static str| {
+            let request = Request::builder()
+                .method(Method::PATCH)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, content_type)
+                .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        let submission = r#"{"title":"Moderated Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/events")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(submission))
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap();
+
+        for (content_type, body, code) in [
+            (
+                "application/json",
+                r#"{"reason":"boring"}"#,
+                "validation_failed",
+            ),
+            ("application/json", r#"{"reason":"#, "bad_request"),
+            ("text/plain", r#"{"reason":"spam"}"#, "bad_request"),
+        ] {
+            let response = send("/api/admin/events/1/reject", content_type, body)
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", body);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(error["code"], code);
+            if code == "validation_failed" {
+                assert_eq!(error["fields"]["reason"][0]["code"], "invalid_type");
+            }
+        }
+        assert_eq!(
+            db::fetch_event(&db, 1).await.unwrap().unwrap().status,
+            EventStatus::Pending
+        );
+
+        let response = send(
+            "/api/admin/events/1/reject",
+            "application/json",
+            r#"{"reason":"spam","notes":"link farm"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let (reason, notes): (Option<String>, Option<String>) =
+            sqlx::query_as("SELECT reason, notes FROM moderation_decisions WHERE event_id = 1")
+                .fetch_one(&db)
+                .await
+                .unwrap();
+        assert_eq!(reason.as_deref(), Some("spam"));
+        assert_eq!(notes.as_deref(), Some("link farm"));
+        let (audited,): (Option<String>,) =
+            sqlx::query_as("SELECT reason FROM audit_log WHERE action = 'event.reject'")
+                .fetch_one(&db)
+                .await
+                .unwrap();
+        assert_eq!(audited.as_deref(), Some("spam: link farm"));
+
+        let request = Request::builder()
+            .uri("/api/admin/events")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(listed["events"][0]["latest_decision"]["reason"], "spam");
+
+        // The admin UI approves without a body
+        let response = send("/api/admin/events/1/approve", "application/json", "")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_merging_events_keeps_their_history() {
+        let (app, db) = test_app().await;
+        let owner = admin_token(&db, Role::Owner).await;
+        let send = |method: Method, uri: &str, body: &'static str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", owner))
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+
+        let submission = r#"{"title":"Merge Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        for _ in 0..2 {
+            let response = send(Method::POST, "/api/events", submission).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+        for (method, uri, body) in [
+            (Method::PATCH, "/api/admin/events/1/approve", ""),
+            (
+                Method::PATCH,
+                "/api/admin/events/2/lifecycle",
+                r#"{"state":"cancelled"}"#,
+            ),
+            (
+                Method::PATCH,
+                "/api/admin/events/2/reject",
+                r#"{"reason":"duplicate"}"#,
+            ),
+        ] {
+            let response = send(method, uri, body).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+        }
+        sqlx::query(
+            "INSERT INTO live_sessions (event_id, token_hash, started_by, started_at) VALUES (2, 'hash', 'owner', ?)",
+        )
+        .bind(Utc::now())
+        .execute(&db)
+        .await
+        .unwrap();
+
+        // Not while the duplicate is being tracked
+        let response = send(Method::POST, "/api/admin/events/2/merge", r#"{"into":1}"#)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        sqlx::query("UPDATE live_sessions SET ended_at = ?, end_reason = 'stopped'")
+            .bind(Utc::now())
+            .execute(&db)
+            .await
+            .unwrap();
+        let response = send(Method::POST, "/api/admin/events/2/merge", r#"{"into":1}"#)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(db::fetch_event(&db, 2).await.unwrap().is_none());
+
+        for table in ["moderation_decisions", "event_lifecycle_changes"] {
+            let moved: Vec<(i64, Option<i64>)> = sqlx::query_as(&format!(
+                "SELECT event_id, merged_from FROM {} WHERE merged_from IS NOT NULL",
+                table
+            ))
+            .fetch_all(&db)
+            .await
+            .unwrap();
+            assert_eq!(moved, [(1, Some(2))], "{}", table);
+        }
+        let (sessions,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM live_sessions WHERE event_id = 1")
+                .fetch_one(&db)
+                .await
+                .unwrap();
+        assert_eq!(sessions, 1);
+
+        // The duplicate's rejection doesn't become the survivor's latest decision
+        let response = send(Method::GET, "/api/admin/events", "").await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(listed["events"][0]["id"], 1);
+        assert_eq!(
+            listed["events"][0]["latest_decision"]["decision"],
+            "approved"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submitter_edits_and_withdrawals_need_the_edit_token() {
+        let (app, db) = test_app().await;
+        let moderator = admin_token(&db, Role::Moderator).await;
+        let send = |method: Method, uri: &str, headers: &[(&str, &str)], body: &'static str| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json");
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        let json = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let submission = r#"{"title":"Token Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        let mut tokens = Vec::new();
+        for _ in 0..2 {
+            let response = send(Method::POST, "/api/events", &[], submission)
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            tokens.push(
+                json(response).await["edit_token"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+            );
+        }
+        let (first, second) = (tokens[0].as_str(), tokens[1].as_str());
+
+        // Missing, wrong and another submission's token
+        for headers in [
+            vec![],
+            vec![("x-edit-token", "nope")],
+            vec![("x-edit-token", second)],
+        ] {
+            for method in [Method::GET, Method::PUT, Method::DELETE] {
+                let response = send(
+                    method.clone(),
+                    "/api/submissions/1",
+                    &headers,
+                    r#"{"title":"Mine"}"#,
+                )
+                .await
+                .unwrap();
+                assert_eq!(
+                    response.status(),
+                    StatusCode::UNAUTHORIZED,
+                    "{} {:?}",
+                    method,
+                    headers
+                );
+            }
+        }
+
+        // Moderation stays with moderators
+        let response = send(
+            Method::PUT,
+            "/api/submissions/1",
+            &[("x-edit-token", first)],
+            r#"{"status":"approved"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            json(response).await["fields"]["status"][0]["code"],
+            "admin_only"
+        );
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.status, EventStatus::Pending);
+
+        // Pending submissions change at once
+        let response = send(
+            Method::PUT,
+            "/api/submissions/1",
+            &[("x-edit-token", first)],
+            r#"{"title":"Token Ride (fixed)"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(json(response).await["event"]["title"], "Token Ride (fixed)");
+
+        // Published rides queue the edit for review
+        send(
+            Method::PATCH,
+            "/api/admin/events/1/approve",
+            &[("authorization", &format!("Bearer {}", moderator))],
+            "",
+        )
+        .await
+        .unwrap();
+        let response = send(
+            Method::PUT,
+            "/api/submissions/1",
+            &[("x-edit-token", first)],
+            r#"{"title":"Token Ride (moved)"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let body = json(response).await;
+        assert_eq!(body["event"]["title"], "Token Ride (fixed)");
+        assert_eq!(
+            body["pending_revision"]["changes"]["title"],
+            "Token Ride (moved)"
+        );
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Token Ride (fixed)");
+
+        let revision = body["pending_revision"]["id"].as_i64().unwrap();
+        let response = send(
+            Method::PATCH,
+            &format!("/api/admin/revisions/{}/approve", revision),
+            &[("authorization", &format!("Bearer {}", moderator))],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Token Ride (moved)");
+
+        // Only unpublished submissions can be withdrawn
+        let response = send(
+            Method::DELETE,
+            "/api/submissions/1",
+            &[("x-edit-token", first)],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let response = send(
+            Method::DELETE,
+            "/api/submissions/2",
+            &[("x-edit-token", second)],
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(db::fetch_event(&db, 1).await.unwrap().is_some());
+        assert!(db::fetch_event(&db, 2).await.unwrap().is_none());
+
+        // Rejected rides can no longer be edited
+        send(
+            Method::PATCH,
+            "/api/admin/events/1/reject",
+            &[("authorization", &format!("Bearer {}", moderator))],
+            "",
+        )
+        .await
+        .unwrap();
+        let response = send(
+            Method::PUT,
+            "/api/submissions/1",
+            &[("x-edit-token", first)],
+            r#"{"title":"Token Ride (again)"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.status, EventStatus::Rejected);
+        assert_eq!(event.title, "Token Ride (moved)");
+    }
+
+    #[tokio::test]
+    async fn test_submitter_edits_lose_races_with_moderation() {
+        let (app, db) = test_app().await;
+        let moderator = admin_token(&db, Role::Moderator).await;
+        let moderate = |action: &str, id: i64| {
+            let request = Request::builder()
+                .method(Method::PATCH)
+                .uri(format!("/api/admin/events/{}/{}", id, action))
+                .header(header::AUTHORIZATION, format!("Bearer {}", moderator))
+                .body(Body::empty())
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        let submission = r#"{"title":"Race Ride","organizer":"Crew","location_name":"Rynek",
+            "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z"}"#;
+        for _ in 0..2 {
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/events")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(submission))
+                .unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+        let edit = UpdateEventRequest {
+            title: Some("Race Ride (edited)".to_string()),
+            ..Default::default()
+        };
+
+        // The submitter read a pending event, then a moderator approved it
+        let read = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(read.status, EventStatus::Pending);
+        moderate("approve", 1).await.unwrap();
+        let schedule = Schedule::updated(&read, &edit).unwrap();
+        let updated = db::apply_event_update(&db, 1, &edit, &schedule, Some(EventStatus::Pending))
+            .await
+            .unwrap();
+        assert!(!updated);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.title, "Race Ride");
+        assert_eq!(event.status, EventStatus::Approved);
+
+        // The submitter read a published event, then a moderator rejected it
+        moderate("approve", 2).await.unwrap();
+        moderate("reject", 2).await.unwrap();
+        let error = queue_revision(&db, 2, &edit).await.unwrap_err();
+        assert!(matches!(error, ApiError::Conflict(_)));
+        let (revisions,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM event_revisions")
+            .fetch_one(&db)
+            .await
+            .unwrap();
+        assert_eq!(revisions, 0);
+
+        // Without a race the guard lets the edit through
+        queue_revision(&db, 1, &edit).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_event_links_must_be_web_urls() {
+        let (app, db) = test_app().await;
+        let send = |method: Method, uri: &str, headers: &[(&str, &str)], body: String| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json");
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            app.clone().oneshot(request.body(Body::from(body)).unwrap())
+        };
+        let json = |response: axum::response::Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+        let submission = |link: &str| {
+            format!(
+                r#"{{"title":"Link Ride","organizer":"Crew","location_name":"Rynek",
+                    "latitude":50.06,"longitude":19.94,"event_date":"2030-06-01T18:00:00Z",
+                    "event_link":"{}"}}"#,
+                link
+            )
+        };
+
+        let response = send(
+            Method::POST,
+            "/api/events",
+            &[],
+            submission("javascript:alert(document.cookie)"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            json(response).await["fields"]["event_link"][0]["code"],
+            "url"
+        );
+
+        let response = send(
+            Method::POST,
+            "/api/events",
+            &[],
+            submission("https://ra.co/events/1"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = json(response).await;
+        let token = body["edit_token"].as_str().unwrap().to_string();
+        let headers = [("x-edit-token", token.as_str())];
+
+        for (field, link) in [
+            ("image_url", "not a url"),
+            ("video_url", "javascript:alert(document.cookie)"),
+            ("event_link", "data:text/html,<script>alert(1)</script>"),
+            ("event_link", "ftp://example.com/ride"),
+        ] {
+            let response = send(
+                Method::PUT,
+                "/api/submissions/1",
+                &headers,
+                serde_json::json!({ field: link }).to_string(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", link);
+            assert_eq!(json(response).await["fields"][field][0]["code"], "url");
+        }
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.event_link.as_deref(), Some("https://ra.co/events/1"));
+        assert_eq!(event.image_url, None);
+
+        // Empty strings still clear a link
+        let response = send(
+            Method::PUT,
+            "/api/submissions/1",
+            &headers,
+            r#"{"event_link":""}"#.to_string(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let event = db::fetch_event(&db, 1).await.unwrap().unwrap();
+        assert_eq!(event.event_link, None);
+
+        // Admin edits get the same checks
+        let token = admin_token(&db, Role::Editor).await;
+        let response = send(
+            Method::PUT,
+            "/api/admin/events/1",
+            &[("authorization", &format!("Bearer {}", token))],
+            r#"{"video_url":"javascript:alert(1)"}"#.to_string(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_occurrence_pages_merge_series_into_one_off_events() {
+        let (app, db) = test_app().await;
+        let submit = |body: String| {
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/events")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        for day in ["01", "08", "10", "15", "20", "29"] {
+            submit(format!(
+                r#"{{"title":"One-off {day}","organizer":"Crew","location_name":"Rynek",
+                    "latitude":50.06,"longitude":19.94,"event_date":"2030-06-{day}T18:00:00Z"}}"#
+            ))
+            .await
+            .unwrap();
+        }
+        submit(
+            r#"{"title":"Weekly","organizer":"Crew","location_name":"Rynek","latitude":50.06,
+                "longitude":19.94,"event_date":"2030-06-03T18:00:00Z","rrule":"FREQ=WEEKLY;COUNT=4"}"#
+                .to_string(),
+        )
+        .await
+        .unwrap();
+        sqlx::query("UPDATE events SET status = 'approved'")
+            .execute(&db)
+            .await
+            .unwrap();
+
+        let get = |uri: String| async {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+        let window = "from=2030-01-01T00:00:00Z&to=2031-01-01T00:00:00Z";
+        for sort in ["asc", "desc"] {
+            let all = get(format!("/api/events/upcoming?{window}&sort={sort}")).await;
+            let all: Vec<String> = all["events"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| e["event_date"].as_str().unwrap().to_string())
+                .collect();
+            assert_eq!(all.len(), 10);
+            let mut sorted = all.clone();
+            sorted.sort();
+            if sort == "desc" {
+                sorted.reverse();
+            }
+            assert_eq!(all, sorted);
+
+            let mut paged = Vec::new();
+            let mut uri = format!("/api/events/upcoming?{window}&sort={sort}&limit=3");
+            loop {
+                let page = get(uri.clone()).await;
+                assert_eq!(page["total"], 10);
+                paged.extend(
+                    page["events"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|e| e["event_date"].as_str().unwrap().to_string()),
+                );
+                match page["next_cursor"].as_str() {
+                    Some(cursor) => {
+                        uri = format!(
+                            "/api/events/upcoming?{window}&sort={sort}&limit=3&cursor={cursor}"
+                        )
+                    }
+                    None => break,
+                }
+            }
+            assert_eq!(paged, all, "{}", sort);
+        }
+    }
 }
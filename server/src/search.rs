@@ -0,0 +1,288 @@
+use sqlx::SqlitePool;
+
+use crate::db::EVENT_COLUMNS;
+use crate::dedup;
+use crate::models::{EventSearchHit, OrganizerSearchHit};
+use crate::organizers::ORGANIZER_COLUMNS;
+
+/// Place names that differ by more than diacritics; any spelling in a group
+/// matches the others. Entries are in `dedup::normalize` form.
+const EXONYMS: &[&[&str]] = &[
+    &["warsaw", "warszawa", "warschau"],
+    &["krakow", "cracow", "krakau"],
+    &["wroclaw", "breslau"],
+    &["gdansk", "danzig"],
+    &["poznan", "posen"],
+    &["lodz", "lodsch"],
+    &["poland", "polska", "polen"],
+    &["munich", "munchen"],
+    &["cologne", "koln"],
+    &["nuremberg", "nurnberg"],
+    &["germany", "deutschland"],
+    &["prague", "praha", "prag"],
+    &["czechia", "cesko"],
+    &["vienna", "wien"],
+    &["austria", "osterreich"],
+    &["zurich", "zuerich"],
+    &["geneva", "geneve", "genf"],
+    &["switzerland", "schweiz", "suisse", "svizzera"],
+    &["brussels", "bruxelles", "brussel"],
+    &["antwerp", "antwerpen", "anvers"],
+    &["ghent", "gent", "gand"],
+    &["belgium", "belgie", "belgique"],
+    &["netherlands", "nederland", "holland"],
+    &["copenhagen", "kobenhavn"],
+    &["denmark", "danmark"],
+    &["gothenburg", "goteborg"],
+    &["sweden", "sverige"],
+    &["norway", "norge"],
+    &["finland", "suomi"],
+    &["lisbon", "lisboa"],
+    &["portugal"],
+    &["seville", "sevilla"],
+    &["spain", "espana"],
+    &["rome", "roma"],
+    &["milan", "milano"],
+    &["naples", "napoli"],
+    &["turin", "torino"],
+    &["florence", "firenze"],
+    &["venice", "venezia"],
+    &["genoa", "genova"],
+    &["italy", "italia"],
+    &["athens", "athina"],
+    &["greece", "hellas", "ellada"],
+    &["budapest"],
+    &["hungary", "magyarorszag"],
+    &["bucharest", "bucuresti"],
+    &["romania"],
+    &["belgrade", "beograd"],
+    &["kyiv", "kiev"],
+    &["lviv", "lwow", "lemberg"],
+    &["ukraine", "ukraina"],
+    &["moscow", "moskva"],
+    &["vilnius", "wilno"],
+    &["lithuania", "lietuva"],
+    &["latvia", "latvija"],
+    &["estonia", "eesti"],
+    &["croatia", "hrvatska"],
+    &["slovakia", "slovensko"],
+    &["bratislava", "pressburg"],
+];
+
+/// Words used from a query; the rest are ignored
+const MAX_QUERY_TERMS: usize = 8;
+
+/// Snippet markers from FTS5, swapped for `<mark>` after escaping.
+/// Private-use characters never appear in stored text.
+const MARK_START: &str = "\u{E000}";
+const MARK_END: &str = "\u{E001}";
+
+/// Tokens of context around matches in snippets
+const SNIPPET_TOKENS: i64 = 12;
+
+/// Column weights for bm25, in index column order. The last column is the
+/// folded copy of the names, which only matters when the others miss.
+const EVENT_WEIGHTS: &str = "10.0, 1.0, 5.0, 3.0, 3.0, 3.0";
+const ORGANIZER_WEIGHTS: &str = "10.0, 1.0, 5.0";
+
+/// Build an FTS5 MATCH expression from user input: every word is a prefix
+/// match, spelling variants of place names are OR-ed in, and words are AND-ed.
+/// `None` if the input has no searchable words.
+pub fn match_expression(query: &str) -> Option<String> {
+    let normalized = dedup::normalize(query);
+    let terms: Vec<String> = normalized
+        .split_whitespace()
+        .take(MAX_QUERY_TERMS)
+        .map(|word| {
+            let variants = EXONYMS
+                .iter()
+                .find(|group| group.contains(&word))
+                .map(|group| group.to_vec())
+                .unwrap_or_else(|| vec![word]);
+            let alternatives: Vec<String> =
+                variants.iter().map(|v| format!("\"{}\"*", v)).collect();
+            if alternatives.len() == 1 {
+                alternatives[0].clone()
+            } else {
+                format!("({})", alternatives.join(" OR "))
+            }
+        })
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" AND "))
+}
+
+/// Escape a snippet for HTML and turn the match markers into `<mark>` tags
+pub fn highlight(snippet: &str) -> String {
+    snippet
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+        .replace(MARK_START, "<mark>")
+        .replace(MARK_END, "</mark>")
+}
+
+/// Approved events matching an FTS5 expression, best first
+pub async fn search_events(
+    db: &SqlitePool,
+    expression: &str,
+    limit: i64,
+) -> Result<Vec<EventSearchHit>, sqlx::Error> {
+    let columns: Vec<String> = EVENT_COLUMNS
+        .split(',')
+        .map(|c| format!("e.{}", c.trim()))
+        .collect();
+    let hits: Vec<EventSearchHit> = sqlx::query_as(&format!(
+        "SELECT {}, -bm25(events_fts, {}) AS rank, \
+         snippet(events_fts, -1, ?, ?, '…', {}) AS snippet \
+         FROM events_fts JOIN events e ON e.id = events_fts.rowid \
+         WHERE events_fts MATCH ? AND e.status = 'approved' \
+         ORDER BY rank DESC, e.event_date DESC LIMIT ?",
+        columns.join(", "),
+        EVENT_WEIGHTS,
+        SNIPPET_TOKENS
+    ))
+    .bind(MARK_START)
+    .bind(MARK_END)
+    .bind(expression)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    Ok(hits
+        .into_iter()
+        .map(|hit| EventSearchHit {
+            event: hit.event.with_parsed_status(),
+            snippet: highlight(&hit.snippet),
+            ..hit
+        })
+        .collect())
+}
+
+/// Organizers matching an FTS5 expression, best first
+pub async fn search_organizers(
+    db: &SqlitePool,
+    expression: &str,
+    limit: i64,
+) -> Result<Vec<OrganizerSearchHit>, sqlx::Error> {
+    let columns: Vec<String> = ORGANIZER_COLUMNS
+        .split(',')
+        .map(|c| format!("o.{}", c.trim()))
+        .collect();
+    let hits: Vec<OrganizerSearchHit> = sqlx::query_as(&format!(
+        "SELECT {}, -bm25(organizers_fts, {}) AS rank, \
+         snippet(organizers_fts, -1, ?, ?, '…', {}) AS snippet \
+         FROM organizers_fts JOIN organizers o ON o.id = organizers_fts.rowid \
+         WHERE organizers_fts MATCH ? \
+         ORDER BY rank DESC, o.name LIMIT ?",
+        columns.join(", "),
+        ORGANIZER_WEIGHTS,
+        SNIPPET_TOKENS
+    ))
+    .bind(MARK_START)
+    .bind(MARK_END)
+    .bind(expression)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    Ok(hits
+        .into_iter()
+        .map(|hit| OrganizerSearchHit {
+            snippet: highlight(&hit.snippet),
+            ..hit
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_expression() {
+        assert_eq!(
+            match_expression("Kraków bass").unwrap(),
+            r#"("krakow"* OR "cracow"* OR "krakau"*) AND "bass"*"#
+        );
+        assert_eq!(
+            match_expression("Warszawa").unwrap(),
+            r#"("warsaw"* OR "warszawa"* OR "warschau"*)"#
+        );
+        // FTS5 syntax in the input is treated as plain words
+        assert_eq!(
+            match_expression(r#"dnb" OR title:*"#).unwrap(),
+            r#""dnb"* AND "or"* AND "title"*"#
+        );
+        assert_eq!(match_expression("  ?! "), None);
+    }
+
+    #[test]
+    fn test_every_exonym_is_normalized() {
+        for group in EXONYMS {
+            for name in *group {
+                assert_eq!(dedup::normalize(name), *name);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_folds_letters_without_diacritics() {
+        let (_, db) = crate::routes::tests::test_app().await;
+        sqlx::query(
+            "INSERT INTO events (title, organizer, location_name, latitude, longitude, event_date, status, updated_at) \
+             VALUES ('Wrocław Bass Ride', 'Łódź Crew', 'Rynek', 51.11, 17.03, ?, 'approved', ?)",
+        )
+        .bind(chrono::Utc::now())
+        .bind(chrono::Utc::now())
+        .execute(&db)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO organizers (name, slug) VALUES ('Łódź Crew', 'lodz-crew')")
+            .execute(&db)
+            .await
+            .unwrap();
+
+        for query in [
+            "Wrocław",
+            "Wroclaw",
+            "wroclaw bass",
+            "Łódź",
+            "Lodz",
+            "lodz crew",
+        ] {
+            let expression = match_expression(query).unwrap();
+            let events = search_events(&db, &expression, 10).await.unwrap();
+            assert_eq!(events.len(), 1, "{}", query);
+            assert_eq!(events[0].event.title, "Wrocław Bass Ride");
+        }
+        for query in ["Łódź", "Lodz"] {
+            let expression = match_expression(query).unwrap();
+            let organizers = search_organizers(&db, &expression, 10).await.unwrap();
+            assert_eq!(organizers.len(), 1, "{}", query);
+        }
+
+        // Edits re-index the folded copy
+        sqlx::query("UPDATE events SET title = 'Gdańsk Bass Ride', organizer = 'Crew'")
+            .execute(&db)
+            .await
+            .unwrap();
+        let expression = match_expression("Lodz").unwrap();
+        assert!(search_events(&db, &expression, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        let expression = match_expression("Gdansk").unwrap();
+        assert_eq!(search_events(&db, &expression, 10).await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_highlight_escapes_html() {
+        assert_eq!(
+            highlight("<b>Bass</b> na \u{E000}Kółkach\u{E001} & more"),
+            "&lt;b&gt;Bass&lt;/b&gt; na <mark>Kółkach</mark> &amp; more"
+        );
+    }
+}
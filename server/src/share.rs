@@ -0,0 +1,207 @@
+use crate::jsonld;
+use crate::models::{Event, Organizer};
+
+/// Meta descriptions longer than this are cut at a word boundary
+const MAX_DESCRIPTION_CHARS: usize = 200;
+
+/// Public site the share pages and feed entries point people to (`PUBLIC_SITE_URL`)
+pub fn site_url() -> String {
+    base_url("PUBLIC_SITE_URL", "http://localhost:3000")
+}
+
+/// Public base of this API, for links to its own pages (`PUBLIC_API_URL`)
+pub fn api_url() -> String {
+    base_url("PUBLIC_API_URL", "http://localhost:3001")
+}
+
+fn base_url(var: &str, default: &str) -> String {
+    std::env::var(var)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| default.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// The event on the map
+pub fn event_page_url(event_id: i64) -> String {
+    format!("{}/?event={}", site_url(), event_id)
+}
+
+/// Server-rendered page with link-preview tags, for sharing
+pub fn share_url(event_id: i64) -> String {
+    format!("{}/api/events/{}/share", api_url(), event_id)
+}
+
+/// Video id of a YouTube watch, short or embed link
+pub fn youtube_id(url: &str) -> Option<&str> {
+    let rest = url
+        .split_once("youtube.com/watch")
+        .and_then(|(_, query)| query.split(['?', '&']).find_map(|p| p.strip_prefix("v=")))
+        .or_else(|| url.split_once("youtu.be/").map(|(_, rest)| rest))
+        .or_else(|| url.split_once("youtube.com/embed/").map(|(_, rest)| rest))?;
+    let id = rest.split(['?', '&', '#', '/']).next()?;
+    (id.len() == 11).then_some(id)
+}
+
+pub fn youtube_thumbnail(id: &str) -> String {
+    format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", id)
+}
+
+/// One-line "when and where", in the ride's local time
+pub fn when_and_where(event: &Event) -> String {
+    let start = event.event_date.with_timezone(&event.tz());
+    let location = match event.country {
+        Some(ref country) => format!("{}, {}", event.location_name, country),
+        None => event.location_name.clone(),
+    };
+    format!("{} - {}", start.format("%a %-d %b %Y, %H:%M %Z"), location)
+}
+
+/// Share page for an event: OpenGraph and Twitter card tags plus schema.org
+/// JSON-LD for crawlers; people are sent on to the event on the map
+pub fn page(event: &Event, organizer: Option<&Organizer>) -> String {
+    let page_url = event_page_url(event.id);
+    let description = match event.description.as_deref().map(str::trim) {
+        Some(text) if !text.is_empty() => {
+            format!("{}. {}", when_and_where(event), truncate(text))
+        }
+        _ => format!(
+            "{}. Organized by {}",
+            when_and_where(event),
+            event.organizer
+        ),
+    };
+    let image = event.image_url.clone().or_else(|| {
+        event
+            .video_url
+            .as_deref()
+            .and_then(youtube_id)
+            .map(youtube_thumbnail)
+    });
+    // `</` would end the script element early
+    let json_ld = jsonld::event(event, organizer)
+        .to_string()
+        .replace("</", "<\\/");
+
+    let mut meta = vec![
+        ("og:type", "website".to_string()),
+        ("og:site_name", "DNB RIDE".to_string()),
+        ("og:title", event.title.clone()),
+        ("og:description", description.clone()),
+        ("og:url", share_url(event.id)),
+        ("twitter:title", event.title.clone()),
+        ("twitter:description", description.clone()),
+    ];
+    match image {
+        Some(image) => {
+            meta.push(("og:image", image.clone()));
+            meta.push(("twitter:card", "summary_large_image".to_string()));
+            meta.push(("twitter:image", image));
+        }
+        None => meta.push(("twitter:card", "summary".to_string())),
+    }
+    let meta: String = meta
+        .iter()
+        .map(|(name, content)| {
+            // OpenGraph uses `property`, Twitter cards `name`
+            let attribute = if name.starts_with("og:") {
+                "property"
+            } else {
+                "name"
+            };
+            format!(
+                "    <meta {}=\"{}\" content=\"{}\" />\n",
+                attribute,
+                name,
+                escape(content)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <title>{title} - DNB RIDE</title>
+    <meta name="description" content="{description}" />
+    <link rel="canonical" href="{page_url}" />
+{meta}    <script type="application/ld+json">{json_ld}</script>
+    <script>window.location.replace({page_url_js});</script>
+  </head>
+  <body>
+    <h1>{title}</h1>
+    <p>{description}</p>
+    <p><a href="{page_url}">View this ride on DNB RIDE</a></p>
+  </body>
+</html>
+"#,
+        title = escape(&event.title),
+        description = escape(&description),
+        page_url = escape(&page_url),
+        meta = meta,
+        json_ld = json_ld,
+        page_url_js = serde_json::Value::from(page_url.as_str())
+            .to_string()
+            .replace("</", "<\\/"),
+    )
+}
+
+/// Cut `text` to a preview length, at a word boundary
+fn truncate(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= MAX_DESCRIPTION_CHARS {
+        return text;
+    }
+    let cut: String = text.chars().take(MAX_DESCRIPTION_CHARS).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut.trim_end_matches([',', '.', ';', ':']))
+}
+
+/// Escape text for HTML/XML content and attribute values
+pub fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_youtube_id() {
+        let id = Some("9k2CnY5rCzM");
+        assert_eq!(
+            youtube_id("https://www.youtube.com/watch?v=9k2CnY5rCzM"),
+            id
+        );
+        assert_eq!(
+            youtube_id("https://youtube.com/watch?t=10&v=9k2CnY5rCzM"),
+            id
+        );
+        assert_eq!(youtube_id("https://youtu.be/9k2CnY5rCzM?t=42"), id);
+        assert_eq!(youtube_id("https://www.youtube.com/embed/9k2CnY5rCzM"), id);
+        assert_eq!(youtube_id("https://vimeo.com/123456"), None);
+    }
+
+    #[test]
+    fn test_truncate_at_word_boundary() {
+        let long = "bass ".repeat(60);
+        let cut = truncate(&long);
+        assert!(cut.ends_with("bass…"));
+        assert!(cut.chars().count() <= MAX_DESCRIPTION_CHARS + 1);
+        assert_eq!(truncate("  short\n text "), "short text");
+    }
+}
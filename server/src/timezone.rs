@@ -0,0 +1,220 @@
+use chrono_tz::Tz;
+use std::sync::OnceLock;
+
+use crate::geo;
+
+/// Reference points (zone, lat, lng) embedded at build time
+const ANCHORS_CSV: &str = include_str!("../data/timezone_anchors.csv");
+
+/// Zone outlines (zone, lat, lng per vertex) embedded at build time
+const REGIONS_CSV: &str = include_str!("../data/timezone_regions.csv");
+
+/// Points further than this from every anchor (open sea, polar regions)
+/// get a nautical zone from their longitude instead
+pub const MAX_ANCHOR_DISTANCE_KM: f64 = 1500.0;
+
+struct Anchor {
+    zone: Tz,
+    latitude: f64,
+    longitude: f64,
+}
+
+struct Region {
+    zone: Tz,
+    /// Vertices as (lat, lng); the last connects back to the first
+    outline: Vec<(f64, f64)>,
+}
+
+impl Region {
+    /// Even-odd ray cast; outlines are small enough to treat as planar
+    fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        let mut inside = false;
+        let mut previous = self.outline[self.outline.len() - 1];
+        for &(lat, lng) in &self.outline {
+            let (prev_lat, prev_lng) = previous;
+            if (lat > latitude) != (prev_lat > latitude)
+                && longitude < lng + (latitude - lat) * (prev_lng - lng) / (prev_lat - lat)
+            {
+                inside = !inside;
+            }
+            previous = (lat, lng);
+        }
+        inside
+    }
+}
+
+/// `(zone, lat, lng)` rows of a data file, skipping comments and bad lines
+fn points(csv: &str) -> impl Iterator<Item = Anchor> + '_ {
+    csv.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split(',');
+            let zone = fields.next()?.parse().ok()?;
+            let latitude = fields.next()?.parse().ok()?;
+            let longitude = fields.next()?.parse().ok()?;
+            Some(Anchor {
+                zone,
+                latitude,
+                longitude,
+            })
+        })
+}
+
+fn anchors() -> &'static [Anchor] {
+    static ANCHORS: OnceLock<Vec<Anchor>> = OnceLock::new();
+    ANCHORS.get_or_init(|| points(ANCHORS_CSV).collect())
+}
+
+fn regions() -> &'static [Region] {
+    static REGIONS: OnceLock<Vec<Region>> = OnceLock::new();
+    REGIONS.get_or_init(|| {
+        let mut regions: Vec<Region> = Vec::new();
+        for point in points(REGIONS_CSV) {
+            let vertex = (point.latitude, point.longitude);
+            match regions.last_mut() {
+                Some(region) if region.zone == point.zone => region.outline.push(vertex),
+                _ => regions.push(Region {
+                    zone: point.zone,
+                    outline: vec![vertex],
+                }),
+            }
+        }
+        regions
+    })
+}
+
+/// IANA timezone for a coordinate: the zone of the outline containing it,
+/// else the zone of the nearest anchor, or `Etc/GMT±N` by longitude far
+/// from any anchor
+pub fn resolve(latitude: f64, longitude: f64) -> Tz {
+    if let Some(region) = regions().iter().find(|r| r.contains(latitude, longitude)) {
+        return region.zone;
+    }
+
+    let nearest = anchors()
+        .iter()
+        .map(|a| {
+            let distance = geo::haversine_km(latitude, longitude, a.latitude, a.longitude);
+            (distance, a.zone)
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+
+    match nearest {
+        Some((distance, zone)) if distance <= MAX_ANCHOR_DISTANCE_KM => zone,
+        _ => nautical(longitude),
+    }
+}
+
+/// Parse an IANA timezone name, e.g. `Europe/Warsaw`
+pub fn parse(name: &str) -> Option<Tz> {
+    name.trim().parse().ok()
+}
+
+/// Nautical zone for a longitude; `Etc/GMT-2` is UTC+2 (the sign is inverted)
+fn nautical(longitude: f64) -> Tz {
+    let hours = (longitude / 15.0).round().clamp(-12.0, 12.0) as i32;
+    let name = match hours {
+        0 => "Etc/GMT".to_string(),
+        h if h > 0 => format!("Etc/GMT-{}", h),
+        h => format!("Etc/GMT+{}", -h),
+    };
+    name.parse().unwrap_or(Tz::UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_anchor_parses() {
+        let rows = ANCHORS_CSV
+            .lines()
+            .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+            .count();
+        assert_eq!(anchors().len(), rows);
+    }
+
+    #[test]
+    fn test_outlined_zones_have_no_anchors() {
+        let rows = REGIONS_CSV
+            .lines()
+            .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+            .count();
+        assert_eq!(
+            regions().iter().map(|r| r.outline.len()).sum::<usize>(),
+            rows
+        );
+        for region in regions() {
+            assert!(region.outline.len() >= 3);
+            assert!(anchors().iter().all(|a| a.zone != region.zone));
+            // Each zone is one contiguous block of rows
+            assert_eq!(
+                regions().iter().filter(|r| r.zone == region.zone).count(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_border_towns() {
+        // Narva and Ivangorod face each other across the Narva river
+        assert_eq!(resolve(59.377, 28.190), Tz::Europe__Tallinn);
+        assert_eq!(resolve(59.370, 28.220), Tz::Europe__Moscow);
+        assert_eq!(resolve(57.960, 27.630), Tz::Europe__Tallinn); // Varska
+        assert_eq!(resolve(57.810, 27.610), Tz::Europe__Moscow); // Pechory
+        assert_eq!(resolve(57.777, 26.045), Tz::Europe__Tallinn); // Valga
+        assert_eq!(resolve(57.770, 26.000), Tz::Europe__Riga); // Valka
+                                                               // Elvas/Badajoz and Valenca/Tui across the Caia and the Minho
+        assert_eq!(resolve(38.880, -7.163), Tz::Europe__Lisbon);
+        assert_eq!(resolve(38.879, -6.970), Tz::Europe__Madrid);
+        assert_eq!(resolve(42.027, -8.645), Tz::Europe__Lisbon);
+        assert_eq!(resolve(42.047, -8.645), Tz::Europe__Madrid);
+        assert_eq!(resolve(41.907, -8.820), Tz::Europe__Madrid); // A Guarda
+                                                                 // Tornio/Haparanda across the Torne
+        assert_eq!(resolve(65.848, 24.147), Tz::Europe__Helsinki);
+        assert_eq!(resolve(65.836, 24.137), Tz::Europe__Stockholm);
+        assert_eq!(resolve(68.380, 23.630), Tz::Europe__Helsinki); // Hetta
+        assert_eq!(resolve(69.010, 23.040), Tz::Europe__Oslo); // Kautokeino
+        assert_eq!(resolve(61.190, 28.770), Tz::Europe__Helsinki); // Imatra
+        assert_eq!(resolve(61.110, 28.860), Tz::Europe__Moscow); // Svetogorsk
+        assert_eq!(resolve(60.710, 28.750), Tz::Europe__Moscow); // Vyborg
+                                                                 // Transnistria keeps Moldovan time
+        assert_eq!(resolve(46.840, 29.630), Tz::Europe__Chisinau); // Tiraspol
+        assert_eq!(resolve(47.770, 29.000), Tz::Europe__Chisinau); // Ribnita
+        assert_eq!(resolve(46.482, 30.723), Tz::Europe__Kyiv); // Odesa
+        assert_eq!(resolve(48.425, 27.790), Tz::Europe__Chisinau); // Otaci
+        assert_eq!(resolve(48.445, 27.798), Tz::Europe__Kyiv); // Mohyliv-Podilskyi
+        assert_eq!(resolve(47.159, 27.601), Tz::Europe__Bucharest); // Iasi
+    }
+
+    #[test]
+    fn test_resolve_known_cities() {
+        assert_eq!(resolve(52.2297, 21.0122), Tz::Europe__Warsaw);
+        assert_eq!(resolve(50.0647, 19.945), Tz::Europe__Warsaw);
+        assert_eq!(resolve(52.52, 13.405), Tz::Europe__Berlin);
+        assert_eq!(resolve(51.5074, -0.1278), Tz::Europe__London);
+        assert_eq!(resolve(53.4808, -2.2426), Tz::Europe__London);
+        assert_eq!(resolve(53.3498, -6.2603), Tz::Europe__Dublin);
+        assert_eq!(resolve(-34.9285, 138.6007), Tz::Australia__Adelaide);
+        assert_eq!(resolve(40.7128, -74.006), Tz::America__New_York);
+        assert_eq!(resolve(38.722, -9.139), Tz::Europe__Lisbon);
+        assert_eq!(resolve(41.150, -8.611), Tz::Europe__Lisbon);
+        assert_eq!(resolve(37.019, -7.930), Tz::Europe__Lisbon);
+        assert_eq!(resolve(60.170, 24.938), Tz::Europe__Helsinki);
+        assert_eq!(resolve(65.012, 25.465), Tz::Europe__Helsinki);
+        assert_eq!(resolve(69.070, 20.790), Tz::Europe__Helsinki); // Kilpisjarvi
+        assert_eq!(resolve(60.097, 19.935), Tz::Europe__Mariehamn);
+        assert_eq!(resolve(59.437, 24.754), Tz::Europe__Tallinn);
+        assert_eq!(resolve(58.250, 22.480), Tz::Europe__Tallinn); // Kuressaare
+        assert_eq!(resolve(47.011, 28.864), Tz::Europe__Chisinau);
+        assert_eq!(resolve(56.949, 24.105), Tz::Europe__Riga);
+    }
+
+    #[test]
+    fn test_open_sea_gets_nautical_zone() {
+        assert_eq!(resolve(-45.0, -130.0), Tz::Etc__GMTPlus9);
+        assert_eq!(nautical(0.0), Tz::Etc__GMT);
+        assert_eq!(nautical(29.0), Tz::Etc__GMTMinus2);
+    }
+}
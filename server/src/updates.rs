@@ -0,0 +1,344 @@
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::models::{Event, EventLifecycle, EventStatus};
+
+/// Changes kept in memory so reconnecting clients can resume with `Last-Event-ID`
+pub const HISTORY_LEN: usize = 1000;
+
+/// Per-subscriber backlog; a client further behind is sent `reset`
+const CHANNEL_CAPACITY: usize = 256;
+
+/// What happened to an event, as the SSE `event:` name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Approved,
+    Updated,
+    Cancelled,
+    Deleted,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Created => "created",
+            ChangeKind::Approved => "approved",
+            ChangeKind::Updated => "updated",
+            ChangeKind::Cancelled => "cancelled",
+            ChangeKind::Deleted => "deleted",
+        }
+    }
+}
+
+/// Who a stream is for: the public map sees approved events only
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Audience {
+    Public,
+    Admin,
+}
+
+/// One change to an event
+#[derive(Debug)]
+pub struct Change {
+    pub seq: u64,
+    pub event_id: i64,
+    /// The change as moderators see it
+    pub kind: ChangeKind,
+    /// The change as the public sees it, if at all. An event that stops
+    /// being public is `deleted` there.
+    pub public_kind: Option<ChangeKind>,
+    /// The event after the change; `None` once deleted
+    pub event: Option<Event>,
+}
+
+/// Classify the change between two snapshots of an event into what
+/// moderators and the public see. `None` before means it was just created,
+/// `None` after that it was deleted.
+pub fn classify(
+    before: Option<&Event>,
+    after: Option<&Event>,
+) -> Option<(ChangeKind, Option<ChangeKind>)> {
+    let kind = match (before, after) {
+        (None, None) => return None,
+        (None, Some(_)) => ChangeKind::Created,
+        (Some(_), None) => ChangeKind::Deleted,
+        (Some(before), Some(after)) => {
+            if before.status != EventStatus::Approved && after.status == EventStatus::Approved {
+                ChangeKind::Approved
+            } else if before.lifecycle != EventLifecycle::Cancelled
+                && after.lifecycle == EventLifecycle::Cancelled
+            {
+                ChangeKind::Cancelled
+            } else {
+                ChangeKind::Updated
+            }
+        }
+    };
+    let public = |event: Option<&Event>| event.is_some_and(|e| e.status == EventStatus::Approved);
+    let public_kind = match (public(before), public(after)) {
+        (false, false) => None,
+        (true, false) => Some(ChangeKind::Deleted),
+        (false, true) if kind == ChangeKind::Created => Some(ChangeKind::Created),
+        (false, true) => Some(ChangeKind::Approved),
+        (true, true) => Some(kind),
+    };
+    Some((kind, public_kind))
+}
+
+struct History {
+    last_seq: u64,
+    changes: VecDeque<Arc<Change>>,
+}
+
+/// In-process hub behind the event streams: every mutating handler publishes
+/// here, every open stream subscribes
+pub struct Updates {
+    /// Tells ids of this process apart from those of an earlier run, whose
+    /// history is gone
+    epoch: i64,
+    sender: broadcast::Sender<Arc<Change>>,
+    history: Mutex<History>,
+}
+
+/// Body of a change message
+#[derive(Serialize)]
+struct ChangeData<'a> {
+    id: i64,
+    event: Option<&'a Event>,
+}
+
+enum Item {
+    Change(Arc<Change>),
+    /// Changes were missed; the client should reload
+    Reset,
+}
+
+impl Default for Updates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Updates {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Updates {
+            epoch: Utc::now().timestamp_millis(),
+            sender,
+            history: Mutex::new(History {
+                last_seq: 0,
+                changes: VecDeque::with_capacity(HISTORY_LEN),
+            }),
+        }
+    }
+
+    /// Publish how an event changed; see [`classify`]
+    pub fn publish(&self, before: Option<&Event>, after: Option<Event>) {
+        let Some((kind, public_kind)) = classify(before, after.as_ref()) else {
+            return;
+        };
+        let Some(event_id) = after.as_ref().or(before).map(|e| e.id) else {
+            return;
+        };
+
+        // Numbering, history and broadcast under one lock keep subscribers in order
+        let mut history = self.history.lock().unwrap();
+        history.last_seq += 1;
+        let change = Arc::new(Change {
+            seq: history.last_seq,
+            event_id,
+            kind,
+            public_kind,
+            event: after,
+        });
+        if history.changes.len() == HISTORY_LEN {
+            history.changes.pop_front();
+        }
+        history.changes.push_back(change.clone());
+        // Fails only when nobody is listening
+        let _ = self.sender.send(change);
+    }
+
+    /// SSE stream of changes for `audience`, resuming after `last_event_id`
+    /// when given. A client whose id can't be resumed from is sent `reset`.
+    pub fn stream(
+        &self,
+        audience: Audience,
+        last_event_id: Option<&str>,
+    ) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+        let (missed, replay, receiver) = self.subscribe(last_event_id);
+        let epoch = self.epoch;
+
+        let backlog = missed
+            .then_some(Item::Reset)
+            .into_iter()
+            .chain(replay.into_iter().map(Item::Change))
+            .collect::<Vec<_>>();
+        let live = BroadcastStream::new(receiver).map(|change| match change {
+            Ok(change) => Item::Change(change),
+            Err(_lagged) => Item::Reset,
+        });
+        let events = tokio_stream::iter(backlog)
+            .chain(live)
+            .filter_map(move |item| message(item, audience, epoch))
+            .map(Ok);
+        Sse::new(events).keep_alive(KeepAlive::default())
+    }
+
+    /// Subscribe, and collect the changes after `last_event_id` atomically so
+    /// none is lost or repeated between replay and live updates
+    fn subscribe(
+        &self,
+        last_event_id: Option<&str>,
+    ) -> (bool, Vec<Arc<Change>>, broadcast::Receiver<Arc<Change>>) {
+        let history = self.history.lock().unwrap();
+        let receiver = self.sender.subscribe();
+        let Some(last_event_id) = last_event_id else {
+            return (false, Vec::new(), receiver);
+        };
+
+        let resumable = last_event_id
+            .split_once('-')
+            .filter(|(epoch, _)| epoch.parse() == Ok(self.epoch))
+            .and_then(|(_, seq)| seq.parse::<u64>().ok())
+            .filter(|&seq| seq <= history.last_seq);
+        let Some(seq) = resumable else {
+            return (true, Vec::new(), receiver);
+        };
+        // Everything after `seq` must still be in the history
+        let oldest = history.changes.front().map_or(seq + 1, |c| c.seq);
+        let replay = history
+            .changes
+            .iter()
+            .filter(|c| c.seq > seq)
+            .cloned()
+            .collect();
+        (oldest > seq + 1, replay, receiver)
+    }
+}
+
+fn message(item: Item, audience: Audience, epoch: i64) -> Option<SseEvent> {
+    let change = match item {
+        Item::Change(change) => change,
+        Item::Reset => return Some(SseEvent::default().event("reset").data("{}")),
+    };
+    let (kind, event) = match audience {
+        Audience::Admin => (change.kind, change.event.as_ref()),
+        // An event leaving the map may still exist, but is no longer public
+        Audience::Public => match change.public_kind? {
+            ChangeKind::Deleted => (ChangeKind::Deleted, None),
+            kind => (kind, change.event.as_ref()),
+        },
+    };
+    let data = ChangeData {
+        id: change.event_id,
+        event,
+    };
+    Some(
+        SseEvent::default()
+            .event(kind.as_str())
+            .id(format!("{}-{}", epoch, change.seq))
+            .data(serde_json::to_string(&data).ok()?),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sample_event;
+
+    fn event(status: EventStatus, lifecycle: EventLifecycle) -> Event {
+        Event {
+            status,
+            lifecycle,
+            ..sample_event()
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        use ChangeKind::*;
+        let pending = event(EventStatus::Pending, EventLifecycle::Scheduled);
+        let approved = event(EventStatus::Approved, EventLifecycle::Scheduled);
+        let rejected = event(EventStatus::Rejected, EventLifecycle::Scheduled);
+        let cancelled = event(EventStatus::Approved, EventLifecycle::Cancelled);
+
+        assert_eq!(classify(None, Some(&pending)), Some((Created, None)));
+        assert_eq!(
+            classify(None, Some(&approved)),
+            Some((Created, Some(Created)))
+        );
+        assert_eq!(
+            classify(Some(&pending), Some(&approved)),
+            Some((Approved, Some(Approved)))
+        );
+        assert_eq!(
+            classify(Some(&pending), Some(&pending)),
+            Some((Updated, None))
+        );
+        assert_eq!(
+            classify(Some(&approved), Some(&cancelled)),
+            Some((Cancelled, Some(Cancelled)))
+        );
+        assert_eq!(
+            classify(Some(&cancelled), Some(&cancelled)),
+            Some((Updated, Some(Updated)))
+        );
+        // Unpublishing takes the event off the map
+        assert_eq!(
+            classify(Some(&approved), Some(&rejected)),
+            Some((Updated, Some(Deleted)))
+        );
+        assert_eq!(
+            classify(Some(&approved), None),
+            Some((Deleted, Some(Deleted)))
+        );
+        assert_eq!(classify(Some(&pending), None), Some((Deleted, None)));
+        assert_eq!(classify(None, None), None);
+    }
+
+    #[test]
+    fn test_resume_from_last_event_id() {
+        let updates = Updates::new();
+        let approved = event(EventStatus::Approved, EventLifecycle::Scheduled);
+        for _ in 0..3 {
+            updates.publish(Some(&approved), Some(approved.clone()));
+        }
+        let id = |seq: u64| format!("{}-{}", updates.epoch, seq);
+
+        let (missed, replay, _) = updates.subscribe(None);
+        assert!(!missed && replay.is_empty());
+        let (missed, replay, _) = updates.subscribe(Some(&id(1)));
+        assert!(!missed);
+        assert_eq!(replay.iter().map(|c| c.seq).collect::<Vec<_>>(), [2, 3]);
+        let (missed, replay, _) = updates.subscribe(Some(&id(3)));
+        assert!(!missed && replay.is_empty());
+
+        // Ids from an earlier run, from the future or garbage can't be resumed
+        for stale in [format!("1-{}", 1), id(9), "nonsense".to_string()] {
+            let (missed, replay, _) = updates.subscribe(Some(&stale));
+            assert!(missed && replay.is_empty(), "{}", stale);
+        }
+    }
+
+    #[test]
+    fn test_resume_past_history_is_a_reset() {
+        let updates = Updates::new();
+        let approved = event(EventStatus::Approved, EventLifecycle::Scheduled);
+        for _ in 0..HISTORY_LEN + 5 {
+            updates.publish(Some(&approved), Some(approved.clone()));
+        }
+        let (missed, replay, _) = updates.subscribe(Some(&format!("{}-2", updates.epoch)));
+        assert!(missed);
+        assert_eq!(replay.len(), HISTORY_LEN);
+    }
+}
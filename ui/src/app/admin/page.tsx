@@ -2,25 +2,38 @@
 
 import { useState, useEffect, useCallback, useMemo } from 'react';
 import Link from 'next/link';
-import { Event, VideoSuggestion } from '@/types';
+import { AdminIdentity, Event, VideoSuggestion } from '@/types';
 import {
+    login,
+    logout,
+    fetchMe,
+    SessionExpiredError,
     fetchAllEventsAdmin,
     approveEvent,
     rejectEvent,
     deleteEvent,
     fetchSuggestions,
     approveSuggestion,
-    rejectSuggestion
+    rejectSuggestion,
+    streamAdminEvents
 } from '@/lib/admin-api';
 import { AdminEventCard, EditEventModal, SuggestionCard } from '@/components/admin';
 import { AuroraBackground } from '@/components';
-import { ArrowLeft, Loader2 } from 'lucide-react';
+import { ArrowLeft, Loader2, LogOut } from 'lucide-react';
 
 type Tab = 'all' | 'pending' | 'approved' | 'rejected' | 'suggestions';
 
+// The session token lives until the tab is closed; it expires server-side after 12 hours
+const TOKEN_STORAGE_KEY = 'admin-session-token';
+
 export default function AdminPage() {
-    const [adminKey, setAdminKey] = useState('');
-    const [isAuthenticated, setIsAuthenticated] = useState(false);
+    const [token, setToken] = useState('');
+    const [admin, setAdmin] = useState<AdminIdentity | null>(null);
+    const isAuthenticated = admin !== null;
+
+    const [username, setUsername] = useState('');
+    const [password, setPassword] = useState('');
+    const [isSigningIn, setIsSigningIn] = useState(false);
 
     const [events, setEvents] = useState<Event[]>([]);
     const [suggestions, setSuggestions] = useState<VideoSuggestion[]>([]);
@@ -36,42 +49,97 @@ export default function AdminPage() {
         setTimeout(() => setToast(null), 3000);
     }, []);
 
+    const endSession = useCallback(() => {
+        sessionStorage.removeItem(TOKEN_STORAGE_KEY);
+        setToken('');
+        setAdmin(null);
+        setEvents([]);
+        setSuggestions([]);
+    }, []);
+
     const loadEvents = useCallback(async () => {
-        if (!adminKey) {
-            showToast('Please enter admin key');
-            return;
-        }
+        if (!token) return;
 
         setIsLoading(true);
         setError(null);
 
         try {
-            const data = await fetchAllEventsAdmin(adminKey);
+            const data = await fetchAllEventsAdmin(token);
             setEvents(data);
-            setIsAuthenticated(true);
         } catch (err) {
             setError(err instanceof Error ? err.message : 'Failed to load events');
-            if (err instanceof Error && err.message.includes('Invalid')) {
-                setIsAuthenticated(false);
+            if (err instanceof SessionExpiredError) {
+                endSession();
             }
         } finally {
             setIsLoading(false);
         }
-    }, [adminKey, showToast]);
+    }, [token, endSession]);
+
+    // Resume a session from earlier in this tab
+    useEffect(() => {
+        const stored = sessionStorage.getItem(TOKEN_STORAGE_KEY);
+        if (!stored) return;
+        fetchMe(stored)
+            .then((identity) => {
+                setToken(stored);
+                setAdmin(identity);
+            })
+            .catch(() => sessionStorage.removeItem(TOKEN_STORAGE_KEY));
+    }, []);
+
+    useEffect(() => {
+        if (isAuthenticated) loadEvents();
+    }, [isAuthenticated, loadEvents]);
+
+    const handleSignIn = useCallback(async (e: React.FormEvent) => {
+        e.preventDefault();
+        setIsSigningIn(true);
+        setError(null);
+
+        try {
+            const session = await login(username, password);
+            sessionStorage.setItem(TOKEN_STORAGE_KEY, session.token);
+            setToken(session.token);
+            setAdmin(session.admin);
+            setPassword('');
+        } catch (err) {
+            setError(err instanceof Error ? err.message : 'Failed to sign in');
+        } finally {
+            setIsSigningIn(false);
+        }
+    }, [username, password]);
+
+    const handleSignOut = useCallback(async () => {
+        await logout(token).catch(() => {});
+        endSession();
+    }, [token, endSession]);
+
+    // Keep the queue current while signed in: new submissions and other moderators' changes
+    useEffect(() => {
+        if (!isAuthenticated) return;
+        const controller = new AbortController();
+        streamAdminEvents(
+            token,
+            () => { fetchAllEventsAdmin(token).then(setEvents).catch(() => {}); },
+            controller.signal
+        );
+        return () => controller.abort();
+    }, [isAuthenticated, token]);
 
     const loadSuggestionsData = useCallback(async () => {
-        if (!adminKey) return;
+        if (!token) return;
 
         setIsLoading(true);
         try {
-            const data = await fetchSuggestions(adminKey);
+            const data = await fetchSuggestions(token);
             setSuggestions(data);
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to load suggestions');
         } finally {
             setIsLoading(false);
         }
-    }, [adminKey, showToast]);
+    }, [token, showToast]);
 
     const handleTabSwitch = useCallback((tab: Tab) => {
         setCurrentTab(tab);
@@ -90,55 +158,55 @@ export default function AdminPage() {
 
     const handleApproveEvent = useCallback(async (eventId: number) => {
         try {
-            await approveEvent(adminKey, eventId);
+            await approveEvent(token, eventId);
             showToast('Event approved');
             loadEvents();
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to approve event');
         }
-    }, [adminKey, loadEvents, showToast]);
+    }, [token, loadEvents, showToast]);
 
     const handleRejectEvent = useCallback(async (eventId: number) => {
         try {
-            await rejectEvent(adminKey, eventId);
+            await rejectEvent(token, eventId);
             showToast('Event rejected');
             loadEvents();
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to reject event');
         }
-    }, [adminKey, loadEvents, showToast]);
+    }, [token, loadEvents, showToast]);
 
     const handleDeleteEvent = useCallback(async (eventId: number) => {
         if (!confirm('Are you sure you want to delete this event?')) return;
 
         try {
-            await deleteEvent(adminKey, eventId);
+            await deleteEvent(token, eventId);
             showToast('Event deleted');
             loadEvents();
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to delete event');
         }
-    }, [adminKey, loadEvents, showToast]);
+    }, [token, loadEvents, showToast]);
 
     const handleApproveSuggestion = useCallback(async (suggestionId: number) => {
         try {
-            await approveSuggestion(adminKey, suggestionId);
+            await approveSuggestion(token, suggestionId);
             showToast('Suggestion approved and event updated');
             loadSuggestionsData();
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to approve suggestion');
         }
-    }, [adminKey, loadSuggestionsData, showToast]);
+    }, [token, loadSuggestionsData, showToast]);
 
     const handleRejectSuggestion = useCallback(async (suggestionId: number) => {
         try {
-            await rejectSuggestion(adminKey, suggestionId);
+            await rejectSuggestion(token, suggestionId);
             showToast('Suggestion rejected');
             loadSuggestionsData();
         } catch (err) {
             showToast(err instanceof Error ? err.message : 'Failed to reject suggestion');
         }
-    }, [adminKey, loadSuggestionsData, showToast]);
+    }, [token, loadSuggestionsData, showToast]);
 
     const tabs: { id: Tab; label: string }[] = [
         { id: 'all', label: 'All Events' },
@@ -163,24 +231,28 @@ export default function AdminPage() {
                         </h1>
                     </div>
 
-                    <div className="flex gap-3 items-center">
-                        <input
-                            type="password"
-                            value={adminKey}
-                            onChange={(e) => setAdminKey(e.target.value)}
-                            placeholder="Enter Admin Key"
-                            className="px-4 py-3 rounded-2xl input-aurora"
-                            onKeyDown={(e) => e.key === 'Enter' && loadEvents()}
-                        />
-                        <button
-                            onClick={loadEvents}
-                            disabled={isLoading}
-                            className="px-6 py-3 btn-coral disabled:opacity-50 flex items-center gap-2"
-                        >
-                            {isLoading && <Loader2 className="w-4 h-4 animate-spin" />}
-                            {isLoading ? 'Loading...' : 'Load Events'}
-                        </button>
-                    </div>
+                    {admin && (
+                        <div className="flex gap-3 items-center">
+                            <span className="text-sm text-[var(--color-text-muted)]">
+                                {admin.username} ({admin.role})
+                            </span>
+                            <button
+                                onClick={loadEvents}
+                                disabled={isLoading}
+                                className="px-6 py-3 btn-coral disabled:opacity-50 flex items-center gap-2"
+                            >
+                                {isLoading && <Loader2 className="w-4 h-4 animate-spin" />}
+                                {isLoading ? 'Loading...' : 'Refresh'}
+                            </button>
+                            <button
+                                onClick={handleSignOut}
+                                className="px-5 py-3 rounded-2xl bg-white/5 border border-white/10 text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-white/10 flex items-center gap-2"
+                            >
+                                <LogOut className="w-4 h-4" />
+                                Sign out
+                            </button>
+                        </div>
+                    )}
                 </header>
 
                 {isAuthenticated && (
@@ -207,9 +279,37 @@ export default function AdminPage() {
                 )}
 
                 {!isAuthenticated ? (
-                    <div className="text-center py-16 text-[var(--color-text-muted)]">
-                        <p>Enter admin key and click "Load Events" to manage events.</p>
-                    </div>
+                    <form onSubmit={handleSignIn} className="max-w-sm mx-auto py-16 space-y-4">
+                        <p className="text-center text-[var(--color-text-muted)]">
+                            Sign in with your admin account to manage events.
+                        </p>
+                        <input
+                            type="text"
+                            value={username}
+                            onChange={(e) => setUsername(e.target.value)}
+                            placeholder="Username"
+                            autoComplete="username"
+                            required
+                            className="w-full px-4 py-3 rounded-2xl input-aurora"
+                        />
+                        <input
+                            type="password"
+                            value={password}
+                            onChange={(e) => setPassword(e.target.value)}
+                            placeholder="Password"
+                            autoComplete="current-password"
+                            required
+                            className="w-full px-4 py-3 rounded-2xl input-aurora"
+                        />
+                        <button
+                            type="submit"
+                            disabled={isSigningIn}
+                            className="w-full px-6 py-3 btn-coral disabled:opacity-50 flex items-center justify-center gap-2"
+                        >
+                            {isSigningIn && <Loader2 className="w-4 h-4 animate-spin" />}
+                            {isSigningIn ? 'Signing in...' : 'Sign in'}
+                        </button>
+                    </form>
                 ) : currentTab === 'suggestions' ? (
                     <div className="space-y-4">
                         {suggestions.length === 0 ? (
@@ -254,7 +354,7 @@ export default function AdminPage() {
             {editingEvent && (
                 <EditEventModal
                     event={editingEvent}
-                    adminKey={adminKey}
+                    token={token}
                     onClose={() => setEditingEvent(null)}
                     onSuccess={() => {
                         showToast('Event updated successfully');
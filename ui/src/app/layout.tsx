@@ -9,6 +9,8 @@ const outfit = Outfit({
   weight: ["400", "500", "600", "700"],
 });
 
+const API_URL = process.env.NEXT_PUBLIC_API_URL || "http://localhost:3001/api";
+
 export const metadata: Metadata = {
   title: "DNBRIDE - Drum & Bass Cycling Events Worldwide",
   description: "Discover and join Drum & Bass cycling events happening around the world. Find rides near you, suggest videos, and connect with the global DNB community.",
@@ -19,6 +21,12 @@ export const metadata: Metadata = {
     description: "Drum & Bass Cycling Events Worldwide",
     type: "website",
   },
+  alternates: {
+    types: {
+      "application/rss+xml": `${API_URL}/events/feed.rss`,
+      "application/atom+xml": `${API_URL}/events/feed.atom`,
+    },
+  },
   manifest: "/manifest.json",
   appleWebApp: {
     capable: true,
@@ -3,7 +3,7 @@
 import { useEffect, useState, useMemo, useCallback } from 'react';
 import dynamic from 'next/dynamic';
 import { Event, Filters } from '@/types';
-import { fetchEvents, calculateDistance } from '@/lib/api';
+import { fetchEvents, calculateDistance, subscribeToEvents, applyEventChange } from '@/lib/api';
 import { Header, Sidebar, EventDetailPanel, SuggestVideoModal, Footer, AddEventModal, AuroraBackground } from '@/components';
 import { Loader2, AlertCircle, RefreshCw, Plus } from 'lucide-react';
 import Image from 'next/image';
@@ -43,6 +43,11 @@ export default function HomePage() {
       try {
         const eventsData = await fetchEvents();
         setEvents(eventsData);
+        // Feed entries link to /?event=<id>
+        const linked = Number(new URLSearchParams(window.location.search).get('event'));
+        if (linked && eventsData.some(e => e.id === linked)) {
+          setSelectedEventId(linked);
+        }
       } catch (err) {
         setError('Failed to load events. Please try again.');
         console.error(err);
@@ -53,6 +58,14 @@ export default function HomePage() {
     loadData();
   }, []);
 
+  // New, approved, edited and removed rides show up without a reload
+  useEffect(() => {
+    return subscribeToEvents(
+      change => setEvents(prev => applyEventChange(prev, change)),
+      () => fetchEvents().then(setEvents).catch(console.error)
+    );
+  }, []);
+
   const filteredEvents = useMemo(() => {
     let result = [...events];
     const now = new Date();
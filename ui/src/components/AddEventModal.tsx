@@ -5,6 +5,7 @@ import mapboxgl from 'mapbox-gl';
 import 'mapbox-gl/dist/mapbox-gl.css';
 import { X, Calendar, MapPin, Loader2, Search } from 'lucide-react';
 import { AURORA_MAP_STYLE } from '@/lib/mapbox-aurora-style';
+import { ApiRequestError, readApiError } from '@/lib/api';
 
 interface AddEventModalProps {
     onClose: () => void;
@@ -14,6 +15,7 @@ interface AddEventModalProps {
 export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps) {
     const [isSubmitting, setIsSubmitting] = useState(false);
     const [error, setError] = useState<string | null>(null);
+    const [fieldErrors, setFieldErrors] = useState<Record<string, string>>({});
     const [successMessage, setSuccessMessage] = useState<string | null>(null);
 
     const [searchQuery, setSearchQuery] = useState('');
@@ -154,6 +156,7 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
         e.preventDefault();
         setIsSubmitting(true);
         setError(null);
+        setFieldErrors({});
 
         try {
             if (!formData.latitude || !formData.longitude) {
@@ -185,7 +188,7 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
             });
 
             if (!response.ok) {
-                throw new Error('Failed to create event. Please check your inputs.');
+                throw await readApiError(response, 'Failed to create event. Please check your inputs.');
             }
 
             setSuccessMessage('Ride submitted successfully! Waiting for admin approval.');
@@ -195,8 +198,13 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
             }, 2000);
 
         } catch (err: unknown) {
-            const errorMessage = err instanceof Error ? err.message : 'Something went wrong';
-            setError(errorMessage);
+            if (err instanceof ApiRequestError && Object.keys(err.fields).length > 0) {
+                setFieldErrors(err.fields);
+                setError('Please fix the highlighted fields.');
+            } else {
+                const errorMessage = err instanceof Error ? err.message : 'Something went wrong';
+                setError(errorMessage);
+            }
             setIsSubmitting(false);
         }
     };
@@ -254,6 +262,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="e.g. London DnB Ride"
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.title && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.title}</p>
+                            )}
                         </div>
 
                         <div>
@@ -269,6 +280,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="e.g. Dom Whiting"
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.organizer && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.organizer}</p>
+                            )}
                         </div>
 
                         <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
@@ -284,6 +298,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                     onChange={handleChange}
                                     className="w-full px-4 py-3 input-aurora"
                                 />
+                                {fieldErrors.event_date && (
+                                    <p className="text-xs text-red-400 mt-1">{fieldErrors.event_date}</p>
+                                )}
                             </div>
                             <div>
                                 <label className="block text-xs font-medium text-[var(--color-text-muted)] uppercase mb-2">
@@ -313,6 +330,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="e.g. Hyde Park"
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.location_name && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.location_name}</p>
+                            )}
                         </div>
 
                         <div>
@@ -327,6 +347,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="e.g. UK"
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.country && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.country}</p>
+                            )}
                         </div>
 
                         <div className="grid grid-cols-2 gap-4">
@@ -359,6 +382,11 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 />
                             </div>
                         </div>
+                        {(fieldErrors.latitude || fieldErrors.longitude) && (
+                            <p className="text-xs text-red-400 -mt-2">
+                                Location {fieldErrors.latitude || fieldErrors.longitude}
+                            </p>
+                        )}
 
                         <div>
                             <label className="block text-xs font-medium text-[var(--color-text-muted)] uppercase mb-2">
@@ -372,6 +400,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="https://facebook.com/events/..."
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.event_link && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.event_link}</p>
+                            )}
                         </div>
 
                         <div>
@@ -386,6 +417,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="https://..."
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.image_url && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.image_url}</p>
+                            )}
                         </div>
 
                         <div>
@@ -400,6 +434,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 placeholder="https://youtube.com/watch?v=..."
                                 className="w-full px-4 py-3 input-aurora"
                             />
+                            {fieldErrors.video_url && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.video_url}</p>
+                            )}
                         </div>
 
                         <div>
@@ -413,6 +450,9 @@ export default function AddEventModal({ onClose, onSuccess }: AddEventModalProps
                                 onChange={handleChange}
                                 className="w-full px-4 py-3 input-aurora resize-none"
                             />
+                            {fieldErrors.description && (
+                                <p className="text-xs text-red-400 mt-1">{fieldErrors.description}</p>
+                            )}
                         </div>
 
                         <div className="pt-2 flex gap-3">
@@ -1,8 +1,12 @@
 'use client';
 
-import { Event } from '@/types';
+import { useEffect, useState } from 'react';
+import { Event, EventRoute, LiveSnapshot } from '@/types';
+import { eventRouteGpxUrl, fetchEventRoute, followLiveRide } from '@/lib/api';
 import Image from 'next/image';
-import { X, Calendar, Clock, MapPin, User, Navigation, ExternalLink, Video } from 'lucide-react';
+import { X, Calendar, Clock, MapPin, User, Navigation, ExternalLink, Video, Radio, Route, Download } from 'lucide-react';
+
+const COMPASS_POINTS = ['N', 'NE', 'E', 'SE', 'S', 'SW', 'W', 'NW'];
 
 interface EventDetailPanelProps {
     event: Event;
@@ -32,6 +36,20 @@ export default function EventDetailPanel({ event, onClose, onSuggestVideo }: Eve
 
     const embedUrl = event.video_url ? getYouTubeEmbedUrl(event.video_url) : null;
 
+    // Where the sound bike is right now, while the ride is tracked
+    const [live, setLive] = useState<LiveSnapshot | null>(null);
+    useEffect(() => {
+        setLive(null);
+        return followLiveRide(event.id, setLive);
+    }, [event.id]);
+    const livePosition = live && !live.session.ended_at ? live.position : null;
+
+    const [route, setRoute] = useState<EventRoute | null>(null);
+    useEffect(() => {
+        setRoute(null);
+        fetchEventRoute(event.id).then(setRoute).catch(() => setRoute(null));
+    }, [event.id]);
+
     return (
         <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/70 backdrop-blur-sm animate-fade-in">
             <div
@@ -105,6 +123,33 @@ export default function EventDetailPanel({ event, onClose, onSuggestVideo }: Eve
                                 <span>{event.organizer}</span>
                             </div>
                         )}
+                        {live && !live.session.ended_at && (
+                            <div className="flex items-center gap-3 text-[var(--color-primary)]">
+                                <Radio className="w-5 h-5 animate-pulse" />
+                                {livePosition ? (
+                                    <a
+                                        href={`https://www.google.com/maps/search/?api=1&query=${livePosition.latitude},${livePosition.longitude}`}
+                                        target="_blank"
+                                        rel="noopener noreferrer"
+                                        className="hover:underline"
+                                    >
+                                        Live now
+                                        {livePosition.speed !== null && ` · ${(livePosition.speed * 3.6).toFixed(0)} km/h`}
+                                        {livePosition.heading !== null &&
+                                            ` heading ${COMPASS_POINTS[Math.round(livePosition.heading / 45) % 8]}`}
+                                        {` · updated ${new Date(livePosition.recorded_at).toLocaleTimeString('en-US', { hour: '2-digit', minute: '2-digit' })}`}
+                                    </a>
+                                ) : (
+                                    <span>Live now · waiting for the first position</span>
+                                )}
+                            </div>
+                        )}
+                        {route && (
+                            <div className="flex items-center gap-3 text-[var(--color-text-muted)]">
+                                <Route className="w-5 h-5 text-[var(--aurora-emerald)]" />
+                                <span>{route.distance_km.toFixed(1)} km planned route</span>
+                            </div>
+                        )}
                         {event.distance !== undefined && (
                             <div className="flex items-center gap-3 text-[var(--aurora-emerald)]">
                                 <Navigation className="w-5 h-5" />
@@ -168,6 +213,17 @@ export default function EventDetailPanel({ event, onClose, onSuggestVideo }: Eve
                             </button>
                         )}
 
+                        {route && (
+                            <a
+                                href={eventRouteGpxUrl(event.id)}
+                                download
+                                className="inline-flex items-center gap-2 px-5 py-3 rounded-full border border-white/20 hover:bg-white/5 text-[var(--color-text)] font-medium transition-colors"
+                            >
+                                <Download className="w-4 h-4" />
+                                <span>Download GPX</span>
+                            </a>
+                        )}
+
                         {event.event_link && (
                             <a
                                 href={event.event_link}
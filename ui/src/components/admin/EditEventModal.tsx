@@ -7,12 +7,12 @@ import { X, Loader2 } from 'lucide-react';
 
 interface EditEventModalProps {
     event: Event;
-    adminKey: string;
+    token: string;
     onClose: () => void;
     onSuccess: () => void;
 }
 
-export default function EditEventModal({ event, adminKey, onClose, onSuccess }: EditEventModalProps) {
+export default function EditEventModal({ event, token, onClose, onSuccess }: EditEventModalProps) {
     const [isSubmitting, setIsSubmitting] = useState(false);
     const [error, setError] = useState<string | null>(null);
 
@@ -38,7 +38,7 @@ export default function EditEventModal({ event, adminKey, onClose, onSuccess }:
         setIsSubmitting(true);
 
         try {
-            await updateEvent(adminKey, event.id, {
+            await updateEvent(token, event.id, {
                 title,
                 organizer,
                 location_name: locationName,
@@ -59,7 +59,7 @@ export default function EditEventModal({ event, adminKey, onClose, onSuccess }:
         } finally {
             setIsSubmitting(false);
         }
-    }, [adminKey, event.id, title, organizer, locationName, country, latitude, longitude, description, imageUrl, videoUrl, eventLink, date, time, status, onSuccess, onClose]);
+    }, [token, event.id, title, organizer, locationName, country, latitude, longitude, description, imageUrl, videoUrl, eventLink, date, time, status, onSuccess, onClose]);
 
     return (
         <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/70 backdrop-blur-sm animate-fade-in">
@@ -1,20 +1,56 @@
-import { Event, VideoSuggestion } from '@/types';
+import { AdminIdentity, AdminSession, Event, VideoSuggestion } from '@/types';
 
 const API_BASE = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:3000/api';
 
-// Admin API functions - all require X-Admin-Key header
-export async function fetchAllEventsAdmin(adminKey: string): Promise<Event[]> {
+export class SessionExpiredError extends Error {
+    constructor() {
+        super('Session expired, please sign in again');
+    }
+}
+
+// Sign in with an admin account; the returned token authorizes the calls below
+export async function login(username: string, password: string): Promise<AdminSession> {
+    const res = await fetch(`${API_BASE}/admin/login`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ username, password }),
+    });
+    if (res.status === 401) throw new Error('Invalid username or password');
+    if (!res.ok) throw new Error('Failed to sign in');
+    return res.json();
+}
+
+export async function logout(token: string): Promise<void> {
+    await fetch(`${API_BASE}/admin/logout`, {
+        method: 'POST',
+        headers: { Authorization: `Bearer ${token}` },
+    });
+}
+
+// The admin behind a stored token, to check it is still valid
+export async function fetchMe(token: string): Promise<AdminIdentity> {
+    const res = await fetch(`${API_BASE}/admin/me`, {
+        headers: { Authorization: `Bearer ${token}` },
+    });
+    if (res.status === 401) throw new SessionExpiredError();
+    if (!res.ok) throw new Error('Failed to check session');
+    return res.json();
+}
+
+// Admin API functions - all require a session token
+export async function fetchAllEventsAdmin(token: string): Promise<Event[]> {
     const res = await fetch(`${API_BASE}/admin/events`, {
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to fetch events');
     const data = await res.json();
     return data.events;
 }
 
 export async function updateEvent(
-    adminKey: string,
+    token: string,
     eventId: number,
     eventData: Partial<Event>
 ): Promise<Event> {
@@ -22,66 +58,114 @@ export async function updateEvent(
         method: 'PUT',
         headers: {
             'Content-Type': 'application/json',
-            'X-Admin-Key': adminKey,
+            Authorization: `Bearer ${token}`,
         },
         body: JSON.stringify(eventData),
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to update event');
     return res.json();
 }
 
-export async function approveEvent(adminKey: string, eventId: number): Promise<void> {
+export async function approveEvent(token: string, eventId: number): Promise<void> {
     const res = await fetch(`${API_BASE}/admin/events/${eventId}/approve`, {
         method: 'PATCH',
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to approve event');
 }
 
-export async function rejectEvent(adminKey: string, eventId: number): Promise<void> {
+export async function rejectEvent(token: string, eventId: number): Promise<void> {
     const res = await fetch(`${API_BASE}/admin/events/${eventId}/reject`, {
         method: 'PATCH',
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to reject event');
 }
 
-export async function deleteEvent(adminKey: string, eventId: number): Promise<void> {
+export async function deleteEvent(token: string, eventId: number): Promise<void> {
     const res = await fetch(`${API_BASE}/admin/events/${eventId}`, {
         method: 'DELETE',
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to delete event');
 }
 
-export async function fetchSuggestions(adminKey: string): Promise<VideoSuggestion[]> {
+export async function fetchSuggestions(token: string): Promise<VideoSuggestion[]> {
     const res = await fetch(`${API_BASE}/admin/suggestions`, {
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to fetch suggestions');
     const data = await res.json();
     return data.suggestions;
 }
 
-export async function approveSuggestion(adminKey: string, suggestionId: number): Promise<void> {
+export async function approveSuggestion(token: string, suggestionId: number): Promise<void> {
     const res = await fetch(`${API_BASE}/admin/suggestions/${suggestionId}/approve`, {
         method: 'PATCH',
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to approve suggestion');
 }
 
-export async function rejectSuggestion(adminKey: string, suggestionId: number): Promise<void> {
+export async function rejectSuggestion(token: string, suggestionId: number): Promise<void> {
     const res = await fetch(`${API_BASE}/admin/suggestions/${suggestionId}/reject`, {
         method: 'PATCH',
-        headers: { 'X-Admin-Key': adminKey },
+        headers: { Authorization: `Bearer ${token}` },
     });
-    if (res.status === 401) throw new Error('Invalid admin key');
+    if (res.status === 401) throw new SessionExpiredError();
+    if (res.status === 403) throw new Error('Your role does not allow this');
     if (!res.ok) throw new Error('Failed to reject suggestion');
 }
+
+// Follow changes to all events, including new submissions. EventSource can't
+// send the session token, so the stream is read with fetch; reconnects resume
+// with Last-Event-ID. Calls `onChange` for every change (and for `reset`).
+export async function streamAdminEvents(
+    token: string,
+    onChange: () => void,
+    signal: AbortSignal
+): Promise<void> {
+    let lastEventId: string | null = null;
+    while (!signal.aborted) {
+        try {
+            const headers: Record<string, string> = { Authorization: `Bearer ${token}` };
+            if (lastEventId) headers['Last-Event-ID'] = lastEventId;
+            const res = await fetch(`${API_BASE}/admin/events/stream`, { headers, signal });
+            if (res.status === 401 || res.status === 403) return;
+            if (!res.ok || !res.body) throw new Error('Failed to open event stream');
+
+            const reader = res.body.pipeThrough(new TextDecoderStream()).getReader();
+            let buffer = '';
+            for (;;) {
+                const { value, done } = await reader.read();
+                if (done) break;
+                buffer += value;
+                const messages = buffer.split('\n\n');
+                buffer = messages.pop() ?? '';
+                for (const message of messages) {
+                    const lines = message.split('\n');
+                    // Lines starting with ':' are keep-alive comments
+                    if (!lines.some(line => line.startsWith('event:'))) continue;
+                    const id = lines.find(line => line.startsWith('id:'));
+                    if (id) lastEventId = id.slice(3).trim();
+                    onChange();
+                }
+            }
+        } catch {
+            if (signal.aborted) return;
+        }
+        await new Promise(resolve => setTimeout(resolve, 3000));
+    }
+}
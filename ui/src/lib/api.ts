@@ -1,7 +1,31 @@
-import { Event, Organizer, EventsResponse, OrganizersResponse, VideoSuggestion } from '@/types';
+import { ApiErrorBody, Event, EventChange, EventChangeKind, EventRoute, LivePosition, LiveSnapshot, Organizer, EventsResponse, OrganizersResponse, VideoSuggestion } from '@/types';
 
 const API_BASE = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:3000/api';
 
+// Error carrying the API's JSON error body, including per-field messages
+export class ApiRequestError extends Error {
+    code: string;
+    fields: Record<string, string>;
+
+    constructor(body: ApiErrorBody) {
+        super(body.message);
+        this.code = body.code;
+        this.fields = Object.fromEntries(
+            Object.entries(body.fields ?? {}).map(([field, errors]) => [field, errors[0]?.message ?? ''])
+        );
+    }
+}
+
+// Turn a failed response into an ApiRequestError, falling back to a generic message
+export async function readApiError(res: Response, fallback: string): Promise<Error> {
+    try {
+        const body: ApiErrorBody = await res.json();
+        return body.code ? new ApiRequestError(body) : new Error(fallback);
+    } catch {
+        return new Error(fallback);
+    }
+}
+
 // Fetch all approved events
 export async function fetchEvents(): Promise<Event[]> {
     const res = await fetch(`${API_BASE}/events`);
@@ -10,6 +34,19 @@ export async function fetchEvents(): Promise<Event[]> {
     return data.events;
 }
 
+// Fetch the planned route of an event, if it has one
+export async function fetchEventRoute(eventId: number): Promise<EventRoute | null> {
+    const res = await fetch(`${API_BASE}/events/${eventId}`);
+    if (!res.ok) throw new Error('Failed to fetch event');
+    const data: Event & { route: EventRoute | null } = await res.json();
+    return data.route;
+}
+
+// GPX download of an event's planned route
+export function eventRouteGpxUrl(eventId: number): string {
+    return `${API_BASE}/events/${eventId}/route.gpx`;
+}
+
 // Fetch all organizers
 export async function fetchOrganizers(): Promise<Organizer[]> {
     const res = await fetch(`${API_BASE}/organizers`);
@@ -19,8 +56,8 @@ export async function fetchOrganizers(): Promise<Organizer[]> {
 }
 
 // Fetch events by organizer
-export async function fetchEventsByOrganizer(organizerId: number): Promise<Event[]> {
-    const res = await fetch(`${API_BASE}/events/organizer/${organizerId}`);
+export async function fetchEventsByOrganizer(slug: string): Promise<Event[]> {
+    const res = await fetch(`${API_BASE}/events/by-organizer/${encodeURIComponent(slug)}`);
     if (!res.ok) throw new Error('Failed to fetch organizer events');
     const data: EventsResponse = await res.json();
     return data.events;
@@ -33,7 +70,7 @@ export async function createEvent(eventData: Partial<Event>): Promise<Event> {
         headers: { 'Content-Type': 'application/json' },
         body: JSON.stringify(eventData),
     });
-    if (!res.ok) throw new Error('Failed to create event');
+    if (!res.ok) throw await readApiError(res, 'Failed to create event');
     return res.json();
 }
 
@@ -50,6 +87,65 @@ export async function submitVideoSuggestion(
     if (!res.ok) throw new Error('Failed to submit video suggestion');
 }
 
+const EVENT_CHANGE_KINDS: EventChangeKind[] = ['created', 'approved', 'updated', 'cancelled', 'deleted'];
+
+// Follow live changes to approved events. EventSource reconnects by itself and
+// resumes with Last-Event-ID; `onReset` means updates were missed, so reload.
+export function subscribeToEvents(
+    onChange: (change: EventChange) => void,
+    onReset: () => void
+): () => void {
+    const source = new EventSource(`${API_BASE}/events/stream`);
+    for (const kind of EVENT_CHANGE_KINDS) {
+        source.addEventListener(kind, (message) => {
+            const data = JSON.parse((message as MessageEvent<string>).data);
+            onChange({ kind, id: data.id, event: data.event });
+        });
+    }
+    source.addEventListener('reset', onReset);
+    return () => source.close();
+}
+
+// Apply a live change to a list of events
+export function applyEventChange(events: Event[], change: EventChange): Event[] {
+    const rest = events.filter(e => e.id !== change.id);
+    return change.event ? [...rest, change.event] : rest;
+}
+
+// Positions kept on the trail, as many as the server sends
+const LIVE_TRAIL_LENGTH = 100;
+
+// Follow the live position of a ride; onSnapshot gets null until a session starts.
+// Returns a function that stops following.
+export function followLiveRide(
+    eventId: number,
+    onSnapshot: (snapshot: LiveSnapshot | null) => void
+): () => void {
+    const source = new EventSource(`${API_BASE}/events/${eventId}/live/stream`);
+    let current: LiveSnapshot | null = null;
+    const update = (snapshot: LiveSnapshot | null) => {
+        current = snapshot;
+        onSnapshot(snapshot);
+    };
+    const data = (message: globalThis.Event) => JSON.parse((message as MessageEvent<string>).data);
+
+    source.addEventListener('snapshot', (message) => update(data(message)));
+    source.addEventListener('started', (message) => update({ session: data(message), position: null, trail: [] }));
+    source.addEventListener('position', (message) => {
+        const position: LivePosition = data(message);
+        if (!current || current.session.id !== position.session_id) return;
+        update({
+            ...current,
+            position,
+            trail: [...current.trail, position].slice(-LIVE_TRAIL_LENGTH),
+        });
+    });
+    source.addEventListener('ended', (message) => {
+        if (current) update({ ...current, session: data(message) });
+    });
+    return () => source.close();
+}
+
 // Calculate distance between two coordinates (Haversine formula)
 export function calculateDistance(
     lat1: number,
@@ -15,17 +15,42 @@ export interface Event {
     event_link: string | null;
     status: 'pending' | 'approved' | 'rejected';
     created_at: string;
+    approved_at: string | null;
+    updated_at: string;
     distance?: number;
 }
 
 export interface Organizer {
     id: number;
     name: string;
+    slug: string;
     description: string | null;
+    website: string | null;
     logo_url: string | null;
+    city: string | null;
+    country: string | null;
+    instagram_url: string | null;
+    facebook_url: string | null;
+    youtube_url: string | null;
+    soundcloud_url: string | null;
+    contact_email: string | null;
     created_at: string;
 }
 
+export interface OrganizerStats {
+    total_rides: number;
+    upcoming_rides: number;
+    countries_visited: string[];
+    first_ride: string | null;
+    last_ride: string | null;
+    rides_with_video: number;
+}
+
+// GET /api/organizers/:slug
+export interface OrganizerProfile extends Organizer {
+    stats: OrganizerStats;
+}
+
 // API Response types
 export interface EventsResponse {
     events: Event[];
@@ -35,6 +60,60 @@ export interface OrganizersResponse {
     organizers: Organizer[];
 }
 
+// Body of every API error response
+export interface ApiErrorBody {
+    code: string;
+    message: string;
+    // Validator errors keyed by request field (code: validation_failed)
+    fields?: Record<string, { code: string; message: string }[]>;
+}
+
+// Live update from the event streams; `event` is null once deleted
+export type EventChangeKind = 'created' | 'approved' | 'updated' | 'cancelled' | 'deleted';
+
+export interface EventChange {
+    kind: EventChangeKind;
+    id: number;
+    event: Event | null;
+}
+
+// Planned route of a ride, simplified from the organizer's GPX or GeoJSON
+export interface EventRoute {
+    event_id: number;
+    coordinates: [number, number][]; // [longitude, latitude]
+    distance_km: number;
+    bbox: [number, number, number, number]; // [min_lng, min_lat, max_lng, max_lat]
+    source_points: number;
+    updated_at: string;
+}
+
+// Live tracking of the sound bike during a ride
+export interface LiveSession {
+    id: number;
+    event_id: number;
+    started_at: string;
+    last_fix_at: string | null;
+    ended_at: string | null;
+    end_reason: 'stopped' | 'inactive' | null;
+}
+
+export interface LivePosition {
+    session_id: number;
+    latitude: number;
+    longitude: number;
+    heading: number | null; // degrees from north
+    speed: number | null; // m/s
+    accuracy: number | null; // metres
+    recorded_at: string;
+}
+
+// The latest position and the trail leading up to it (oldest first)
+export interface LiveSnapshot {
+    session: LiveSession;
+    position: LivePosition | null;
+    trail: LivePosition[];
+}
+
 // Filter state
 export interface Filters {
     country: string;
@@ -53,3 +132,18 @@ export interface VideoSuggestion {
     created_at: string;
     event_title: string | null;
 }
+
+// Admin accounts and sessions
+export type AdminRole = 'viewer' | 'moderator' | 'editor' | 'owner';
+
+export interface AdminIdentity {
+    id: number | null;
+    username: string;
+    role: AdminRole;
+}
+
+export interface AdminSession {
+    token: string;
+    expires_at: string;
+    admin: AdminIdentity;
+}